@@ -0,0 +1,144 @@
+//! Rubik's Clock, a puzzle with 9 dials on each face that turn in groups depending on which pins are up.
+//!
+//! Each corner dial is on the same axle as the one behind it, so there are 14 dials that can be set
+//! independently (see [`Clock::dials`]). Moves use WCA notation (see [`ClockMove`]):
+//!
+//! ```rust
+//! use barbarosa::clock::{Clock, ClockMove, OptimalSolver};
+//! use barbarosa::generic::{search::Solver, Alg, Cube, Movable, Parsable};
+//!
+//! let scramble = Alg::<ClockMove>::parse("UR3+ DL2- ALL1+ y2 U4-").unwrap();
+//! let clock = Clock::SOLVED.moved(&scramble);
+//! assert_eq!(clock.front(), &[4, 8, 7, 8, 8, 8, 11, 0, 1]);
+//!
+//! let solution = OptimalSolver.solve(&clock).unwrap();
+//! assert_eq!(solution.to_string(), "U4+ y2 UR3- DL2+ ALL1-");
+//! assert!(clock.moved(&solution).is_solved());
+//! ```
+//!
+//! See [`random_state_scramble`] for WCA scrambles.
+
+mod moves;
+mod parser;
+mod solver;
+mod test;
+
+use crate::generic::{self, moves::AsMove, Movable};
+
+pub use moves::{ClockMove, Pin, Pins};
+pub use solver::{random_state_scramble, OptimalSolver};
+
+/// The corner dials, which are on the same axle as the ones behind them.
+const CORNERS: [usize; 4] = [0, 2, 6, 8];
+
+/// The dials of the back that aren't corners, in the order of [`Clock::dials`].
+const BACK_DIALS: [usize; 5] = [1, 3, 4, 5, 7];
+
+/// The dial behind `dial`, as seen from the other side. Left and right are swapped.
+const fn behind(dial: usize) -> usize {
+    dial - dial % 3 + 2 - dial % 3
+}
+
+/// The Clock, as the hours of the dials on both faces.
+///
+/// Pins aren't part of the state: every [`ClockMove`] sets the ones it needs, so only the dials matter.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Clock {
+    front: [u8; 9],
+    back: [u8; 9],
+}
+
+impl Clock {
+    /// The hour each dial of the front shows, from 0 (12 o'clock) to 11.
+    ///
+    /// The dials go row by row from the top left, so the center is at index 4.
+    pub fn front(&self) -> &[u8; 9] {
+        &self.front
+    }
+
+    /// The hour each dial of the back shows, looking at the back. See [`Clock::front`] for the order.
+    ///
+    /// A corner shows 12 minus the hour of the corner in front of it, since they turn together in opposite
+    /// directions when looked at from each side.
+    pub fn back(&self) -> &[u8; 9] {
+        &self.back
+    }
+
+    /// The 14 dials that can be set independently: the 9 of the front, followed by the 5 of the back that aren't
+    /// corners.
+    pub fn dials(&self) -> [u8; 14] {
+        let mut dials = [0; 14];
+        dials[..9].copy_from_slice(&self.front);
+
+        for (dial, back) in dials[9..].iter_mut().zip(BACK_DIALS) {
+            *dial = self.back[back];
+        }
+
+        dials
+    }
+
+    /// Creates a Clock from the hours of the [dials](Clock::dials), which are taken modulo 12.
+    pub fn from_dials(dials: [u8; 14]) -> Self {
+        let mut clock = Self {
+            front: [0; 9],
+            back: [0; 9],
+        };
+
+        for (dial, hour) in dials[..9].iter().enumerate() {
+            clock.front[dial] = hour % 12;
+        }
+
+        for (dial, hour) in BACK_DIALS.into_iter().zip(&dials[9..]) {
+            clock.back[dial] = hour % 12;
+        }
+
+        for corner in CORNERS {
+            clock.back[behind(corner)] = (12 - clock.front[corner]) % 12;
+        }
+
+        clock
+    }
+
+    /// Turns the dials around the `pins` on the front `amount` hours clockwise, along with the corners behind
+    /// them.
+    pub fn turn(&mut self, pins: Pins, amount: i8) {
+        let amount = amount.rem_euclid(12) as u8;
+
+        for (dial, turned) in pins.turned_dials().into_iter().enumerate() {
+            if !turned {
+                continue;
+            }
+
+            self.front[dial] = (self.front[dial] + amount) % 12;
+
+            if CORNERS.contains(&dial) {
+                self.back[behind(dial)] = (self.back[behind(dial)] + 12 - amount) % 12;
+            }
+        }
+    }
+
+    /// Turns the Clock around, so that the back becomes the front.
+    pub fn flip(&mut self) {
+        std::mem::swap(&mut self.front, &mut self.back);
+    }
+}
+
+impl generic::Cube for Clock {
+    const SOLVED: Self = Self {
+        front: [0; 9],
+        back: [0; 9],
+    };
+}
+
+impl AsMove for Clock {
+    type Move = ClockMove;
+}
+
+impl Movable<ClockMove> for Clock {
+    fn apply(&mut self, m: &ClockMove) {
+        match *m {
+            ClockMove::Turn { pins, amount } => self.turn(pins, amount),
+            ClockMove::Flip => self.flip(),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Moves of the Clock.
+
+use strum::EnumIter;
+
+use crate::generic::{self, moves::Mergeable};
+
+/// One of the 4 pins, named as seen from the front. Looking at the back, left and right are swapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+#[allow(missing_docs)]
+pub enum Pin {
+    UL,
+    UR,
+    DL,
+    DR,
+}
+
+impl Pin {
+    /// The 4 dials around the pin, which turn together when it's up (see [`Clock::front`](super::Clock::front)).
+    pub const fn dials(self) -> [usize; 4] {
+        match self {
+            Pin::UL => [0, 1, 3, 4],
+            Pin::UR => [1, 2, 4, 5],
+            Pin::DL => [3, 4, 6, 7],
+            Pin::DR => [4, 5, 7, 8],
+        }
+    }
+}
+
+/// The pins that are up during a move, as written in WCA notation.
+///
+/// The variants are in the order of WCA scrambles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, EnumIter)]
+#[allow(missing_docs)]
+pub enum Pins {
+    UR,
+    DR,
+    DL,
+    UL,
+    U,
+    R,
+    D,
+    L,
+    ALL,
+}
+
+impl Pins {
+    /// The pins that are up.
+    pub const fn up(self) -> &'static [Pin] {
+        match self {
+            Pins::UR => &[Pin::UR],
+            Pins::DR => &[Pin::DR],
+            Pins::DL => &[Pin::DL],
+            Pins::UL => &[Pin::UL],
+            Pins::U => &[Pin::UL, Pin::UR],
+            Pins::R => &[Pin::UR, Pin::DR],
+            Pins::D => &[Pin::DL, Pin::DR],
+            Pins::L => &[Pin::UL, Pin::DL],
+            Pins::ALL => &[Pin::UL, Pin::UR, Pin::DL, Pin::DR],
+        }
+    }
+
+    /// Whether each dial of the front turns when turning a dial next to the pins that are up.
+    pub fn turned_dials(self) -> [bool; 9] {
+        let mut turned = [false; 9];
+
+        for dial in self.up().iter().flat_map(|pin| pin.dials()) {
+            turned[dial] = true;
+        }
+
+        turned
+    }
+}
+
+impl std::fmt::Display for Pins {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// A move of the Clock in WCA notation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClockMove {
+    /// Pushes up `pins` (and down the rest), and turns a dial next to them `amount` hours clockwise. This turns
+    /// every dial around the pins on the front, and the corners behind them on the back.
+    ///
+    /// Written as `UR3+` or `ALL2-`, where the amount goes from `5-` to `6+`. [`ClockMove::turn`] brings the
+    /// amount into that range, but any other amount works too and is written as its equivalent in the range.
+    Turn {
+        /// The pins that are up
+        pins: Pins,
+        /// The hours the dials turn clockwise, looking at the front
+        amount: i8,
+    },
+
+    /// Turns the whole puzzle around so that the back becomes the front, written as `y2`.
+    Flip,
+}
+
+/// Brings an amount of hours into the range used by WCA notation, from -5 to 6.
+const fn normalize(amount: i8) -> i8 {
+    match amount.rem_euclid(12) {
+        amount if amount > 6 => amount - 12,
+        amount => amount,
+    }
+}
+
+impl ClockMove {
+    /// Creates a new turn, with the amount brought into the range from -5 to 6.
+    pub const fn turn(pins: Pins, amount: i8) -> Self {
+        Self::Turn {
+            pins,
+            amount: normalize(amount),
+        }
+    }
+}
+
+impl generic::Move for ClockMove {
+    fn inverse(&self) -> Self {
+        match *self {
+            Self::Turn { pins, amount } => Self::turn(pins, -normalize(amount)),
+            Self::Flip => Self::Flip,
+        }
+    }
+}
+
+impl Mergeable for ClockMove {
+    /// Turns commute with each other, but not with flips.
+    type Axis = std::mem::Discriminant<Self>;
+
+    fn axis(&self) -> Self::Axis {
+        std::mem::discriminant(self)
+    }
+
+    fn merge(&self, other: &Self) -> Option<Option<Self>> {
+        match (*self, *other) {
+            (Self::Turn { pins: a, amount: x }, Self::Turn { pins: b, amount: y }) if a == b => {
+                match Self::turn(a, normalize(x) + normalize(y)) {
+                    Self::Turn { amount: 0, .. } => Some(None),
+                    merged => Some(Some(merged)),
+                }
+            }
+            (Self::Flip, Self::Flip) => Some(None),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ClockMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Turn { pins, amount } => match normalize(amount) {
+                amount if amount < 0 => write!(f, "{pins}{}-", -amount),
+                amount => write!(f, "{pins}{amount}+"),
+            },
+            Self::Flip => write!(f, "y2"),
+        }
+    }
+}
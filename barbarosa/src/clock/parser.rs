@@ -0,0 +1,51 @@
+//! Parsing of WCA Clock notation.
+
+use pest::iterators::Pair;
+use pest_derive::Parser;
+use strum::IntoEnumIterator;
+
+use crate::generic::parse::{FromPest, IntoParseErr, ParseError};
+
+use super::{ClockMove, Pins};
+
+type Result<T> = std::result::Result<T, ParseError<Rule>>;
+
+#[derive(Parser)]
+#[grammar = "grammar/clock.pest"]
+pub struct ClockParser;
+
+fn parse_turn(pair: Pair<Rule>) -> Result<ClockMove> {
+    let mut inner = pair.into_inner();
+
+    let pins = inner.next().into_err()?.as_str();
+    let pins = Pins::iter()
+        .find(|other| other.to_string() == pins)
+        .ok_or_else(|| ParseError::Unreachable(pins.to_string()))?;
+
+    let amount: i8 = inner.next().into_err()?.as_str().parse()?;
+
+    match inner.next().into_err()?.as_str() {
+        "+" => Ok(ClockMove::turn(pins, amount)),
+        "-" => Ok(ClockMove::turn(pins, -amount)),
+        other => Err(ParseError::Unreachable(other.to_string())),
+    }
+}
+
+impl FromPest for ClockMove {
+    type Rule = Rule;
+    type Parser = ClockParser;
+
+    fn rule() -> Self::Rule {
+        Rule::clock_move
+    }
+
+    fn from_pest(pair: Pair<Self::Rule>) -> Result<Self> {
+        let inner = pair.into_inner().next().into_err()?;
+
+        match inner.as_rule() {
+            Rule::turn => parse_turn(inner),
+            Rule::flip => Ok(ClockMove::Flip),
+            _ => Err(ParseError::Unreachable(inner.as_str().to_string())),
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! Optimal solutions and random state scrambles for the Clock.
+//!
+//! Turns only add hours to the dials, so they commute (as long as there's no flip in between) and the order
+//! doesn't matter. A Clock is solved by a set of turns if the hours they add cancel out the ones of the dials,
+//! which is a system of linear equations modulo 12 with one unknown for each of the 18 turns (9 on each side).
+//!
+//! The 14 turns of WCA scrambles are independent, so they have a single solution for every Clock. The optimal
+//! solution is found by trying every amount for the other 4 turns and solving for the rest, which is just
+//! 12<sup>4</sup> combinations.
+
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+
+use crate::generic::{search::Solver, Alg, Cube, Movable};
+
+use super::{Clock, ClockMove, Pins};
+
+/// A matrix of 14 by 14 numbers, indexed by row first.
+type Matrix = [[u32; 14]; 14];
+
+/// A turn of the front, or of the back by doing `y2` first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Front,
+    Back,
+}
+
+/// The turns of WCA scrambles: every pin configuration on the front, and then `U`, `R`, `D`, `L` and `ALL` on
+/// the back.
+const SCRAMBLE_TURNS: [(Side, Pins); 14] = {
+    use Pins::*;
+    use Side::*;
+
+    [
+        (Front, UR),
+        (Front, DR),
+        (Front, DL),
+        (Front, UL),
+        (Front, U),
+        (Front, R),
+        (Front, D),
+        (Front, L),
+        (Front, ALL),
+        (Back, U),
+        (Back, R),
+        (Back, D),
+        (Back, L),
+        (Back, ALL),
+    ]
+};
+
+/// The turns that aren't in [`SCRAMBLE_TURNS`], which can still make the solutions shorter.
+const OTHER_TURNS: [(Side, Pins); 4] = [
+    (Side::Back, Pins::UR),
+    (Side::Back, Pins::DR),
+    (Side::Back, Pins::DL),
+    (Side::Back, Pins::UL),
+];
+
+/// The hours that a turn of one hour adds to each of the [dials](Clock::dials).
+fn turn_dials((side, pins): (Side, Pins)) -> [u32; 14] {
+    let mut clock = Clock::SOLVED;
+
+    match side {
+        Side::Front => clock.turn(pins, 1),
+        Side::Back => {
+            clock.flip();
+            clock.turn(pins, 1);
+            clock.flip();
+        }
+    }
+
+    clock.dials().map(u32::from)
+}
+
+/// Inverts a matrix modulo a prime power, or returns `None` if it isn't invertible.
+///
+/// Gaussian elimination works because numbers that aren't invertible are the multiples of the prime, so if no
+/// number of a column can be used as a pivot, neither can any combination of them.
+fn inverse_modulo(matrix: &Matrix, modulus: u32) -> Option<Matrix> {
+    let inverse_of = |x: u32| (1..modulus).find(|y| x * y % modulus == 1);
+
+    let mut left = matrix.map(|row| row.map(|x| x % modulus));
+    let mut right = [[0; 14]; 14];
+    for (i, row) in right.iter_mut().enumerate() {
+        row[i] = 1;
+    }
+
+    for column in 0..14 {
+        let (pivot, pivot_inverse) =
+            (column..14).find_map(|row| Some((row, inverse_of(left[row][column])?)))?;
+
+        left.swap(column, pivot);
+        right.swap(column, pivot);
+
+        for x in left[column].iter_mut().chain(right[column].iter_mut()) {
+            *x = *x * pivot_inverse % modulus;
+        }
+
+        for row in (0..14).filter(|&row| row != column) {
+            let factor = left[row][column];
+
+            for j in 0..14 {
+                left[row][j] = (left[row][j] + (modulus - factor) * left[column][j]) % modulus;
+                right[row][j] = (right[row][j] + (modulus - factor) * right[column][j]) % modulus;
+            }
+        }
+    }
+
+    Some(right)
+}
+
+/// The inverse of the matrix with the dials that each of the [`SCRAMBLE_TURNS`] turns as columns, modulo 12.
+static SCRAMBLE_INVERSE: Lazy<Matrix> = Lazy::new(|| {
+    let columns = SCRAMBLE_TURNS.map(turn_dials);
+    let matrix: Matrix = std::array::from_fn(|row| columns.map(|column| column[row]));
+
+    // 12 is 4 times 3, so the inverse is the number that matches the inverses modulo 4 and 3
+    let modulo_4 = inverse_modulo(&matrix, 4).expect("Scramble turns are independent");
+    let modulo_3 = inverse_modulo(&matrix, 3).expect("Scramble turns are independent");
+
+    std::array::from_fn(|row| {
+        std::array::from_fn(|column| (9 * modulo_4[row][column] + 4 * modulo_3[row][column]) % 12)
+    })
+});
+
+/// The amount of hours of each of the [`SCRAMBLE_TURNS`] that add `dials` to the hours of a Clock.
+fn scramble_amounts(dials: [u32; 14]) -> [u32; 14] {
+    SCRAMBLE_INVERSE.map(|row| row.iter().zip(dials).map(|(x, dial)| x * dial).sum::<u32>() % 12)
+}
+
+/// Writes the turns as an alg, the front ones first and each side in the order of [`Pins`]. Turns of 0 hours are
+/// skipped unless `keep_empty_turns` is set.
+fn to_alg(
+    turns: impl IntoIterator<Item = ((Side, Pins), u32)>,
+    keep_empty_turns: bool,
+) -> Alg<ClockMove> {
+    let (front, back): (Vec<_>, Vec<_>) = turns
+        .into_iter()
+        .filter(|&(_, amount)| keep_empty_turns || amount != 0)
+        .sorted_by_key(|((_, pins), _)| *pins)
+        .partition(|((side, _), _)| *side == Side::Front);
+
+    let turn = |((_, pins), amount): ((Side, Pins), u32)| ClockMove::turn(pins, amount as i8);
+
+    let mut moves: Vec<_> = front.into_iter().map(turn).collect();
+    if !back.is_empty() {
+        moves.push(ClockMove::Flip);
+        moves.extend(back.into_iter().map(turn));
+    }
+
+    Alg::new(moves)
+}
+
+/// Finds solutions with the least amount of turns of WCA notation, without counting `y2`.
+///
+/// Front turns come first, followed by `y2` and the back turns if there are any. No Clock needs more than 14
+/// turns, since that's enough for the turns of a scramble.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OptimalSolver;
+
+impl Solver<Clock, ClockMove> for OptimalSolver {
+    fn solve(&self, clock: &Clock) -> Option<Alg<ClockMove>> {
+        let other_dials = OTHER_TURNS.map(turn_dials);
+        let target = clock.dials().map(|hour| (12 - u32::from(hour)) % 12);
+
+        // The amounts of the scramble turns that solve the Clock after doing the other ones
+        let scramble_amounts_after = |other_amounts: [u32; 4]| {
+            let remaining = std::array::from_fn(|dial| {
+                let turned: u32 = other_dials
+                    .iter()
+                    .zip(other_amounts)
+                    .map(|(dials, amount)| dials[dial] * amount)
+                    .sum();
+
+                (target[dial] + 12 - turned % 12) % 12
+            });
+
+            scramble_amounts(remaining)
+        };
+
+        let other_amounts = (0..12_u32.pow(4))
+            .map(|combination| std::array::from_fn(|i| combination / 12_u32.pow(i as u32) % 12))
+            .min_by_key(|other_amounts: &[u32; 4]| {
+                scramble_amounts_after(*other_amounts)
+                    .iter()
+                    .chain(other_amounts)
+                    .filter(|&&amount| amount != 0)
+                    .count()
+            })?;
+
+        let turns = SCRAMBLE_TURNS
+            .into_iter()
+            .zip(scramble_amounts_after(other_amounts))
+            .chain(OTHER_TURNS.into_iter().zip(other_amounts));
+
+        Some(to_alg(turns, false))
+    }
+}
+
+impl Distribution<Clock> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Clock {
+        Clock::from_dials(std::array::from_fn(|_| rng.gen_range(0..12)))
+    }
+}
+
+/// Generates a random state scramble in the format of the WCA: the 9 pin configurations on the front, `y2`, and
+/// `U`, `R`, `D`, `L` and `ALL` on the back, as in `UR3+ DR2- ... ALL2- y2 U0+ ... ALL5+`.
+///
+/// Every random Clock has a single scramble of this form that leads to it.
+pub fn random_state_scramble(rng: &mut (impl Rng + ?Sized)) -> Alg<ClockMove> {
+    let clock: Clock = rng.gen();
+
+    // The scramble ends with the back in front, so the turns are found for the Clock turned around
+    let mut flipped = clock.clone();
+    flipped.flip();
+    let amounts = scramble_amounts(flipped.dials().map(u32::from));
+
+    let scramble = to_alg(SCRAMBLE_TURNS.into_iter().zip(amounts), true);
+    debug_assert_eq!(Clock::SOLVED.moved(&scramble), clock);
+
+    scramble
+}
@@ -0,0 +1,155 @@
+#![cfg(test)]
+
+use pretty_assertions::assert_eq;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use strum::IntoEnumIterator;
+
+use crate::generic::{search::Solver, Alg, Cube, Movable, Move, Parsable};
+
+use super::*;
+
+#[test]
+fn wca_scrambles_print_back_the_same() {
+    let scramble = "UR3+ DR2- DL1+ UL0+ U3- R4+ D3+ L1+ ALL2- y2 U0+ R3- D2- L4- ALL5+";
+    let parsed = Alg::<ClockMove>::parse(scramble).unwrap();
+
+    assert_eq!(parsed.to_string(), scramble);
+}
+
+#[test]
+fn amounts_wrap_around_the_dial() {
+    assert_eq!(
+        Alg::<ClockMove>::parse("UR7+ ALL6- y2").unwrap().moves,
+        vec![
+            ClockMove::turn(Pins::UR, -5),
+            ClockMove::turn(Pins::ALL, 6),
+            ClockMove::Flip
+        ]
+    );
+}
+
+#[test]
+fn turns_built_by_hand_are_written_in_wca_range() {
+    let turn = ClockMove::Turn {
+        pins: Pins::UR,
+        amount: 7,
+    };
+    assert_eq!(turn.to_string(), "UR5-");
+    assert_eq!(turn.inverse().to_string(), "UR5+");
+
+    let turn = ClockMove::Turn {
+        pins: Pins::ALL,
+        amount: i8::MIN,
+    };
+    assert_eq!(turn.to_string(), "ALL4+");
+    assert_eq!(turn.inverse(), ClockMove::turn(Pins::ALL, -4));
+    assert_eq!(
+        Alg::<ClockMove>::new(vec![turn, turn])
+            .simplified()
+            .to_string(),
+        "ALL4-"
+    );
+}
+
+#[test]
+fn rejects_turns_without_amount_or_direction() {
+    for invalid in ["UR3", "UR+", "ur3+", "UF1+", "y", "x2", "UR3+-", "UR-3"] {
+        assert!(
+            Alg::<ClockMove>::parse(invalid).is_err(),
+            "{invalid} should error"
+        );
+    }
+}
+
+#[test]
+fn corners_turn_the_dial_behind_them_backwards() {
+    let clock = Clock::SOLVED.moved(&Alg::<ClockMove>::parse("UR1+").unwrap());
+    assert_eq!(clock.front(), &[0, 1, 1, 0, 1, 1, 0, 0, 0]);
+    assert_eq!(clock.back(), &[11, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    let clock = Clock::SOLVED.moved(&Alg::<ClockMove>::parse("ALL2- y2").unwrap());
+    assert_eq!(clock.front(), &[2, 0, 2, 0, 0, 0, 2, 0, 2]);
+    assert_eq!(clock.back(), &[10; 9]);
+}
+
+#[test]
+fn the_fourteen_dials_describe_the_whole_clock() {
+    for pins in Pins::iter() {
+        let m = ClockMove::turn(pins, 5);
+        let clock = Clock::SOLVED.moved(&m);
+
+        assert!(!clock.is_solved());
+        assert_eq!(clock.clone().moved(&m.inverse()), Clock::SOLVED);
+        assert_eq!(Clock::from_dials(clock.dials()), clock);
+    }
+
+    let clock = Clock::from_dials([13; 14]);
+    assert_eq!(clock.front(), &[1; 9]);
+    assert_eq!(clock.back(), &[11, 1, 11, 1, 1, 1, 11, 1, 11]);
+}
+
+#[test]
+fn simplifies_turns_with_the_same_pins() {
+    let simplified = Alg::<ClockMove>::parse("UR2+ U1+ UR1+ UR3- y2 y2 ALL6+ ALL6+")
+        .unwrap()
+        .simplified();
+    assert_eq!(simplified.to_string(), "U1+");
+
+    let simplified = Alg::<ClockMove>::parse("UR2+ y2 UR1+")
+        .unwrap()
+        .simplified();
+    assert_eq!(simplified.to_string(), "UR2+ y2 UR1+");
+}
+
+#[test]
+fn solutions_do_the_front_turns_first() {
+    assert_eq!(OptimalSolver.solve(&Clock::SOLVED), Some(Alg::new(vec![])));
+
+    let clock = Clock::SOLVED.moved(&Alg::<ClockMove>::parse("UR3+ y2 DL2- ALL1+").unwrap());
+    let solution = OptimalSolver.solve(&clock).unwrap();
+    assert_eq!(solution.to_string(), "DL2+ ALL1- y2 UR3-");
+    assert!(clock.moved(&solution).is_solved());
+
+    // Which side is in front doesn't matter once the dials are solved
+    let clock = Clock::SOLVED.moved(&Alg::<ClockMove>::parse("y2 UR3+").unwrap());
+    assert_eq!(OptimalSolver.solve(&clock).unwrap().to_string(), "UR3-");
+}
+
+#[test]
+fn random_states_need_at_most_fourteen_turns() {
+    let mut rng = StdRng::seed_from_u64(50);
+
+    for _ in 0..5 {
+        let clock: Clock = rng.gen();
+        let solution = OptimalSolver.solve(&clock).unwrap();
+        let turns = solution.moves.iter().filter(|m| **m != ClockMove::Flip);
+
+        assert!(clock.clone().moved(&solution).is_solved());
+        assert!(turns.count() <= 14);
+    }
+}
+
+#[test]
+fn scrambles_list_every_pin_configuration_in_wca_order() {
+    let mut rng = StdRng::seed_from_u64(50);
+    let back = [Pins::U, Pins::R, Pins::D, Pins::L, Pins::ALL];
+
+    for _ in 0..5 {
+        let scramble = random_state_scramble(&mut rng);
+        let pins: Vec<_> = scramble
+            .moves
+            .iter()
+            .filter_map(|m| match *m {
+                ClockMove::Turn { pins, .. } => Some(pins),
+                ClockMove::Flip => None,
+            })
+            .collect();
+
+        assert_eq!(scramble.moves[9], ClockMove::Flip);
+        assert_eq!(pins, Pins::iter().chain(back).collect::<Vec<_>>());
+        assert_eq!(
+            Alg::<ClockMove>::parse(&scramble.to_string()).unwrap(),
+            scramble
+        );
+    }
+}
@@ -1,8 +1,11 @@
 use rand::{distributions::Standard, prelude::Distribution};
 
-use crate::generic::{self, moves::AsMove, Cube};
+use crate::generic::{self, algebra::impl_state_algebra, moves::AsMove, Cube, StateAlgebra};
 
-use super::{invariants::fix_corner_multiplicity, pieces::corner::CornerSet, AxisMove, CubeN};
+use super::{
+    invariants::fix_corner_multiplicity, order::PieceOrders, pieces::corner::CornerSet, AxisMove,
+    CubeN, WideAxisMove,
+};
 
 mod test;
 
@@ -27,6 +30,16 @@ impl AsMove for Cube2 {
 
 impl CubeN for Cube2 {
     const N: u32 = 2;
+    type WideMove = WideAxisMove<0>;
+
+    fn piece_orders(&self) -> PieceOrders {
+        PieceOrders {
+            corners: self.corners.order(),
+            edges: None,
+            wings: None,
+            centers: None,
+        }
+    }
 }
 
 impl generic::Movable<AxisMove> for Cube2 {
@@ -35,6 +48,8 @@ impl generic::Movable<AxisMove> for Cube2 {
     }
 }
 
+impl_state_algebra!(Cube2, [corners]);
+
 impl Distribution<Cube2> for Standard {
     fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Cube2 {
         let mut cube = Cube2::SOLVED;
@@ -11,6 +11,7 @@ use crate::{
 
 use super::Cube3;
 
+#[allow(dead_code)]
 struct CfopSolver {}
 
 impl Solver<Cube3, AxisMove> for CfopSolver {
@@ -1,7 +1,7 @@
 //! Collection of heuristics for the A* search algorithm.
 
 pub use manhattan::manhattan;
-pub use mus::{mus, mus_with_fallback};
+pub use mus::{mus, mus_in, mus_with_fallback};
 
 use crate::generic::Cube;
 
@@ -1,6 +1,9 @@
-use crate::cube3::{mus, Cube3};
+use crate::{
+    cube3::{mus, Cube3},
+    cube_n::moves::metric::TurnMetric,
+};
 
-/// Same as [mus()], but with a fallback function that is called if the cache has not been initialized.
+/// Same as [`mus()`], but with a fallback function that is called if the cache has not been initialized.
 pub fn mus_with_fallback(fallback: impl Fn(&Cube3) -> f32) -> impl Fn(&Cube3) -> f32 {
     move |cube| {
         mus::cache::get(cube)
@@ -16,3 +19,11 @@ pub fn mus_with_fallback(fallback: impl Fn(&Cube3) -> f32) -> impl Fn(&Cube3) ->
 pub fn mus(cube: &Cube3) -> f32 {
     mus::cache::get_or_init(cube) as f32
 }
+
+/// Same as [`mus()`], but the number of moves is measured in the given metric. For example, use
+/// [`TurnMetric::Qtm`] to search with quarter turns.
+///
+/// See also [`crate::cube3::mus::cache::get_or_init_in`].
+pub fn mus_in(metric: TurnMetric) -> impl Fn(&Cube3) -> f32 {
+    move |cube| mus::cache::get_or_init_in(cube, metric) as f32
+}
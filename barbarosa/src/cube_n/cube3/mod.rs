@@ -6,22 +6,31 @@ pub mod cfop;
 pub mod heuristics;
 pub mod mus;
 
+pub(crate) mod supercube;
 mod test;
 
-use rand::{distributions::Standard, prelude::Distribution};
+use std::fmt;
+
+use itertools::Itertools;
+use rand::{distributions::Standard, prelude::Distribution, Rng};
 
 use crate::{
     cube_n::space::Axis,
-    generic::{self, moves::AsMove, Cube, Movable},
+    generic::{
+        self, algebra::impl_state_algebra, moves::AsMove, piece::Cycle, Cube, Movable, StateAlgebra,
+    },
 };
 
 use super::{
     invariants::{fix_corner_multiplicity, fix_edge_flip_parity, fix_swap_parity},
+    moves::slice::impl_movable_slice_move,
+    order::PieceOrders,
     pieces::{corner::CornerSet, edge::EdgeSet},
-    CubeN,
+    supercube::{random_middle_centers, turn_middle_center},
+    CubeN, SuperCube,
 };
 
-use super::{AxisMove, Corner, Edge};
+use super::{moves::QuarterAxisMove, AxisMove, Corner, Edge, WideAxisMove};
 
 /// A 3x3x3 Rubik's cube.
 ///
@@ -55,6 +64,16 @@ impl generic::Movable<AxisMove> for Cube3 {
 
 impl CubeN for Cube3 {
     const N: u32 = 3;
+    type WideMove = WideAxisMove<0>;
+
+    fn piece_orders(&self) -> PieceOrders {
+        PieceOrders {
+            corners: self.corners.order(),
+            edges: Some(self.edges.order()),
+            wings: None,
+            centers: None,
+        }
+    }
 }
 
 impl Cube3 {
@@ -62,6 +81,59 @@ impl Cube3 {
     pub fn successors(&self) -> impl IntoIterator<Item = (Self, AxisMove)> {
         AxisMove::all().map(|mov| (self.clone().moved(&mov), mov))
     }
+
+    /// Returns every possible state after doing a quarter turn on the current state.
+    ///
+    /// Searching with these successors finds solutions that are optimal in the quarter turn metric.
+    pub fn quarter_successors(&self) -> impl IntoIterator<Item = (Self, QuarterAxisMove)> {
+        let cube = self.clone();
+        QuarterAxisMove::all().map(move |mov| (cube.clone().moved(&mov), mov))
+    }
+}
+
+impl_state_algebra!(Cube3, [edges, corners]);
+impl_movable_slice_move!(Cube3, 0, [1]);
+
+impl Cube3 {
+    /// Returns the cycle decomposition of the cube, for both corners and edges.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use barbarosa::prelude::*;
+    /// use barbarosa::cube_n::moves::perms::pll;
+    ///
+    /// let cube = Cube3::SOLVED.moved(&pll::T);
+    ///
+    /// assert_eq!(cube.cycles().to_string(), "(UFR UBR) (UR UL)");
+    /// ```
+    pub fn cycles(&self) -> Cube3Cycles {
+        Cube3Cycles {
+            corners: self.corners.cycles(),
+            edges: self.edges.cycles(),
+        }
+    }
+}
+
+/// The cycle decomposition of a [`Cube3`]. See [`Cube3::cycles`].
+///
+/// It gets displayed in the usual cycle notation, corners first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cube3Cycles {
+    /// The non-trivial cycles of the corners
+    pub corners: Vec<Cycle<Corner>>,
+
+    /// The non-trivial cycles of the edges
+    pub edges: Vec<Cycle<Edge>>,
+}
+
+impl fmt::Display for Cube3Cycles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let corners = self.corners.iter().map(|cycle| cycle.to_string());
+        let edges = self.edges.iter().map(|cycle| cycle.to_string());
+
+        write!(f, "{}", corners.chain(edges).join(" "))
+    }
 }
 
 impl Distribution<Cube3> for Standard {
@@ -88,3 +160,23 @@ impl Distribution<Cube3> for Standard {
         cube
     }
 }
+
+/// The centers of the 3x3x3 can only turn in place. They are tracked as the number of clockwise quarter turns of
+/// each one, indexed by [`Face::index`](super::space::Face::index).
+impl SuperCube for Cube3 {
+    type Centers = [u8; 6];
+
+    const SOLVED_CENTERS: Self::Centers = [0; 6];
+
+    fn move_centers(centers: &mut Self::Centers, m: &AxisMove) {
+        turn_middle_center(centers, m);
+    }
+
+    fn are_centers_solved(&self, centers: &Self::Centers) -> bool {
+        *centers == Self::SOLVED_CENTERS
+    }
+
+    fn random_centers<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::Centers {
+        random_middle_centers(&self.corners, rng)
+    }
+}
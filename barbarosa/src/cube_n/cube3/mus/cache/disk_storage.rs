@@ -8,18 +8,18 @@ use crate::{
     generic::Movable,
 };
 
-use super::{generation::build_partial, Cache};
+use super::{generation::build_partial, Cache, Turns};
 
-pub fn load() -> io::Result<Cache> {
-    let edges = load_partial::<HalfEdgesMUS>()?;
-    let corners = load_partial::<CornersMUS>()?;
+pub fn load(turns: Turns) -> io::Result<Cache> {
+    let edges = load_partial::<HalfEdgesMUS>(turns)?;
+    let corners = load_partial::<CornersMUS>(turns)?;
 
     Ok(Cache { edges, corners })
 }
 
-pub fn load_or_build() -> io::Result<Cache> {
-    let edges = load_or_build_partial::<HalfEdgesMUS>()?;
-    let corners = load_or_build_partial::<CornersMUS>()?;
+pub fn load_or_build(turns: Turns) -> io::Result<Cache> {
+    let edges = load_or_build_partial::<HalfEdgesMUS>(turns)?;
+    let corners = load_or_build_partial::<CornersMUS>(turns)?;
 
     Ok(Cache { edges, corners })
 }
@@ -31,62 +31,69 @@ pub fn load_or_build() -> io::Result<Cache> {
 // }
 
 fn load_or_build_partial<T: DiskCacheable + Deindexable + Movable<AxisMove> + Clone>(
+    turns: Turns,
 ) -> io::Result<Vec<u8>> {
-    match load_partial::<T>() {
+    match load_partial::<T>(turns) {
         Ok(cache) => Ok(cache),
-        Err(err) if err.kind() == io::ErrorKind::NotFound => build_partial::<T>(),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => build_partial::<T>(turns),
         Err(err) => Err(err),
     }
 }
 
 pub trait DiskCacheable: Indexable {
-    const PATH: &'static str;
+    const NAME: &'static str;
+
+    fn path(turns: Turns) -> String {
+        format!("mus-cache/{}{}.barbarosa", Self::NAME, turns.file_suffix())
+    }
 }
 
 impl DiskCacheable for CornersMUS {
-    const PATH: &'static str = "mus-cache/corners.barbarosa";
+    const NAME: &'static str = "corners";
 }
 
 impl DiskCacheable for HalfEdgesMUS {
-    const PATH: &'static str = "mus-cache/edges.barbarosa";
+    const NAME: &'static str = "edges";
 }
 
-pub fn load_partial<T: DiskCacheable>() -> io::Result<Vec<u8>> {
-    print_with_timestamp::<T>("Attempting to load cache from disk");
+pub fn load_partial<T: DiskCacheable>(turns: Turns) -> io::Result<Vec<u8>> {
+    print_with_timestamp::<T>(turns, "Attempting to load cache from disk");
 
-    let bytes = fs::read(T::PATH)?;
+    let bytes = fs::read(T::path(turns))?;
 
     assert_correct_cache_size::<T>(bytes.len());
-    print_with_timestamp::<T>("Cache has been loaded");
+    print_with_timestamp::<T>(turns, "Cache has been loaded");
 
     Ok(bytes)
 }
 
 // TODO: Would be nice if there was some metadata stored along the actual cache. Mainly the cube for which this
 // cache was built, since the reference cube changes the indices.
-pub fn write_partial<T: DiskCacheable>(bytes: &[u8]) -> io::Result<()> {
-    print_with_timestamp::<T>("Writing cache to disk");
+pub fn write_partial<T: DiskCacheable>(turns: Turns, bytes: &[u8]) -> io::Result<()> {
+    print_with_timestamp::<T>(turns, "Writing cache to disk");
     assert_correct_cache_size::<T>(bytes.len());
 
-    match fs::write(T::PATH, bytes) {
+    let path = T::path(turns);
+
+    match fs::write(&path, bytes) {
         Ok(data) => data,
         Err(err) if err.kind() == io::ErrorKind::NotFound => {
-            let Some(parent) = Path::new(T::PATH).parent() else {
+            let Some(parent) = Path::new(&path).parent() else {
                 return Err(err);
             };
 
             fs::create_dir_all(parent)?;
-            fs::write(T::PATH, bytes)?
+            fs::write(&path, bytes)?
         }
         Err(err) => return Err(err),
     };
 
-    print_with_timestamp::<T>("Cache has been written");
+    print_with_timestamp::<T>(turns, "Cache has been written");
 
     Ok(())
 }
 
-pub fn print_with_timestamp<T: DiskCacheable>(msg: &str) {
+pub fn print_with_timestamp<T: DiskCacheable>(turns: Turns, msg: &str) {
     if !cfg!(debug_assertions) {
         return;
     }
@@ -95,7 +102,7 @@ pub fn print_with_timestamp<T: DiskCacheable>(msg: &str) {
         "{}: {} ({})",
         Local::now().format("%H:%M:%S.%3f"),
         msg,
-        T::PATH
+        T::path(turns)
     );
 }
 
@@ -12,7 +12,7 @@ use crate::{
     generic::Movable,
 };
 
-use super::disk_storage::DiskCacheable;
+use super::{disk_storage::DiskCacheable, Turns};
 
 pub use super::disk_storage::load_or_build;
 
@@ -21,6 +21,7 @@ fn cache_neighbours_at_depth<
 >(
     cache: &mut [PartialEntry],
     move_depth: u8,
+    moves: &[AxisMove],
 ) {
     // TODO: Make paralell
     for index in 0..T::TOTAL_SET_SIZE {
@@ -31,8 +32,8 @@ fn cache_neighbours_at_depth<
         }
 
         let state = T::from_index(index);
-        for mov in AxisMove::all() {
-            let successor = state.clone().moved(&mov);
+        for mov in moves {
+            let successor = state.clone().moved(mov);
             let successor_entry = &mut cache[successor.index()];
 
             if successor_entry.get().is_none() {
@@ -43,9 +44,17 @@ fn cache_neighbours_at_depth<
 }
 
 /// Builds and stores a partial cache
+///
+/// The distances are measured in the given kind of turns, so only those moves are used to reach new states.
 pub fn build_partial<T: Indexable + Deindexable + Movable<AxisMove> + DiskCacheable + Clone>(
+    turns: Turns,
 ) -> io::Result<Vec<u8>> {
-    print_with_timestamp::<T>(&format!("Building partial cache for {}.", T::PATH));
+    print_with_timestamp::<T>(
+        turns,
+        &format!("Building partial cache for {}.", T::path(turns)),
+    );
+
+    let moves: Vec<_> = turns.moves().collect();
 
     let mut cache = vec![PartialEntry::none(); T::TOTAL_SET_SIZE];
 
@@ -60,22 +69,22 @@ pub fn build_partial<T: Indexable + Deindexable + Movable<AxisMove> + DiskCachea
         let stats = Stats::new(&cache, start_time);
 
         if stats.amount_cached == T::TOTAL_SET_SIZE {
-            print_with_timestamp::<T>(&format!("Finished caching! {stats}"));
+            print_with_timestamp::<T>(turns, &format!("Finished caching! {stats}"));
             break;
         }
 
-        print_with_timestamp::<T>(&format!("Caching at depth {move_depth}; {stats}"));
+        print_with_timestamp::<T>(turns, &format!("Caching at depth {move_depth}; {stats}"));
 
-        cache_neighbours_at_depth::<T>(&mut cache, move_depth);
+        cache_neighbours_at_depth::<T>(&mut cache, move_depth, &moves);
     }
 
-    print_with_timestamp::<T>("Caching done! Attempting to save to disk.");
+    print_with_timestamp::<T>(turns, "Caching done! Attempting to save to disk.");
 
     let cache = unsafe { mem::transmute::<Vec<PartialEntry>, Vec<u8>>(cache) };
 
-    write_partial::<T>(&cache)?;
+    write_partial::<T>(turns, &cache)?;
 
-    print_with_timestamp::<T>("Cache saved to disk.");
+    print_with_timestamp::<T>(turns, "Cache saved to disk.");
 
     Ok(cache)
 }
@@ -100,7 +109,7 @@ fn amount_cached(cache: &[PartialEntry]) -> usize {
     cache.iter().filter(|entry| entry.get().is_some()).count()
 }
 
-fn percent_cached(cache: &Vec<PartialEntry>, amount_cached: usize) -> f64 {
+fn percent_cached(cache: &[PartialEntry], amount_cached: usize) -> f64 {
     amount_cached as f64 / cache.len() as f64
 }
 
@@ -113,7 +122,7 @@ struct Stats {
 }
 
 impl Stats {
-    fn new(cache: &Vec<PartialEntry>, started: DateTime<Local>) -> Self {
+    fn new(cache: &[PartialEntry], started: DateTime<Local>) -> Self {
         let amount_cached = amount_cached(cache);
         let percent_cached = percent_cached(cache, amount_cached);
         let time_elapsed = Local::now().signed_duration_since(started);
@@ -2,6 +2,8 @@
 //!
 //! Currently we can only cache MUS if we have filesystem access. This should be changed
 //! to somehow work on the web with WASM and such.
+//!
+//! There is a cache for half turns and another one for quarter turns, see [`get_or_init_in`].
 
 mod disk_storage;
 mod generation;
@@ -9,7 +11,11 @@ mod test;
 
 use std::{io, sync::OnceLock};
 
-use crate::cube3::Cube3;
+use crate::{
+    cube3::Cube3,
+    cube_n::{moves::metric::TurnMetric, AxisMove},
+    generic::moves::Metric,
+};
 
 /// The MUS cache. Any instance of this type is guaranteed to have a complete cache
 pub struct Cache {
@@ -18,17 +24,83 @@ pub struct Cache {
 }
 
 static CACHE_LOCK: OnceLock<Cache> = OnceLock::new();
+static QUARTER_CACHE_LOCK: OnceLock<Cache> = OnceLock::new();
+
+/// The moves that the distances of a cache are measured in.
+///
+/// Without slices, [STM](TurnMetric::Stm) and [ETM](TurnMetric::Etm) are the same as [HTM](TurnMetric::Htm),
+/// and [QSTM](TurnMetric::Qstm) is the same as [QTM](TurnMetric::Qtm). So only two caches are needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Turns {
+    Half,
+    Quarter,
+}
+
+impl From<TurnMetric> for Turns {
+    fn from(metric: TurnMetric) -> Self {
+        match metric {
+            TurnMetric::Htm | TurnMetric::Stm | TurnMetric::Etm => Turns::Half,
+            TurnMetric::Qtm | TurnMetric::Qstm => Turns::Quarter,
+        }
+    }
+}
+
+impl Turns {
+    fn lock(self) -> &'static OnceLock<Cache> {
+        match self {
+            Turns::Half => &CACHE_LOCK,
+            Turns::Quarter => &QUARTER_CACHE_LOCK,
+        }
+    }
+
+    /// The moves that count as a single move
+    fn moves(self) -> impl Iterator<Item = AxisMove> {
+        let metric = match self {
+            Turns::Half => TurnMetric::Htm,
+            Turns::Quarter => TurnMetric::Qtm,
+        };
+
+        AxisMove::all()
+            .into_iter()
+            .filter(move |mov| metric.cost(mov) == 1)
+    }
+
+    /// Suffix of the cache files, so that caches for different metrics don't overwrite each other
+    fn file_suffix(self) -> &'static str {
+        match self {
+            Turns::Half => "",
+            Turns::Quarter => "-qtm",
+        }
+    }
+}
 
 /// Gets the heuristic value of the cube from the cache, or initializes the cache if it hasn't been
 /// initialized yet
 pub fn get_or_init(cube: &Cube3) -> u8 {
-    CACHE_LOCK.get_or_init(Cache::init).get(cube)
+    get_or_init_in(cube, TurnMetric::Htm)
 }
 
 /// Gets the heuristic value of the cube from the cache, or returns `None` if the cache hasn't been
 /// initialized yet
 pub fn get(cube: &Cube3) -> Option<u8> {
-    CACHE_LOCK.get().map(|cache| cache.get(cube))
+    get_in(cube, TurnMetric::Htm)
+}
+
+/// Same as [`get_or_init`], but the value is measured in the given metric. Every metric has its own
+/// cache, which gets initialized independently.
+pub fn get_or_init_in(cube: &Cube3, metric: TurnMetric) -> u8 {
+    Turns::from(metric)
+        .lock()
+        .get_or_init(|| Cache::init_in(metric))
+        .get(cube)
+}
+
+/// Same as [`get`], but the value is measured in the given metric.
+pub fn get_in(cube: &Cube3, metric: TurnMetric) -> Option<u8> {
+    Turns::from(metric)
+        .lock()
+        .get()
+        .map(|cache| cache.get(cube))
 }
 
 impl Cache {
@@ -58,7 +130,16 @@ impl Cache {
     /// If there's a folder that needs to be created in the path it does, and is in general somewhat robust
     /// already by itself.
     pub fn init() -> Self {
-        generation::load_or_build().expect(
+        Self::init_in(TurnMetric::Htm)
+    }
+
+    /// Same as [`Cache::init`], but for the cache in the given metric.
+    ///
+    /// # Panic
+    ///
+    /// Same as [`Cache::init`].
+    pub fn init_in(metric: TurnMetric) -> Self {
+        generation::load_or_build(metric.into()).expect(
             "User should have permission to write to cache directory. \
             If you have permissions and this still failed, it should probably \
             be reported as a bug.",
@@ -67,6 +148,11 @@ impl Cache {
 
     /// Loads the cache from disk (doesn't build it if it doesn't exist)
     pub fn load() -> io::Result<Self> {
-        disk_storage::load()
+        Self::load_in(TurnMetric::Htm)
+    }
+
+    /// Loads the cache in the given metric from disk (doesn't build it if it doesn't exist)
+    pub fn load_in(metric: TurnMetric) -> io::Result<Self> {
+        disk_storage::load(metric.into())
     }
 }
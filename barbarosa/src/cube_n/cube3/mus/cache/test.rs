@@ -3,7 +3,11 @@
 use rand::{rngs::StdRng, SeedableRng};
 
 use crate::{
-    cube3::{heuristics::mus, Cube3},
+    cube3::{
+        heuristics::{mus, mus_in},
+        Cube3,
+    },
+    cube_n::moves::{metric::TurnMetric, QuarterAxisMove},
     generic::{Alg, Cube, Movable},
 };
 
@@ -21,3 +25,20 @@ fn correct_cache() {
         }
     }
 }
+
+#[test]
+#[ignore = "MUS takes too long to build"]
+fn correct_quarter_turn_cache() {
+    let mut rng = StdRng::seed_from_u64(69420);
+    let quarter_mus = mus_in(TurnMetric::Qtm);
+
+    for move_amount in 0..=20 {
+        for _ in 0..100 {
+            let alg = Alg::<QuarterAxisMove>::from(Alg::random_with_rng(move_amount, &mut rng));
+            let cube = Cube3::SOLVED.moved(&alg);
+
+            assert!(quarter_mus(&cube) <= alg.moves.len() as f32);
+            assert!(quarter_mus(&cube) >= mus(&cube));
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Solving the 3x3x3 supercube. See [`crate::cube_n::Super`] for more info.
+
+use once_cell::sync::Lazy;
+
+use crate::{
+    cube_n::{
+        moves::rotation::Rotatable, reduction::Stages, space::Face, supercube::turn_middle_center,
+        AxisMove, Orientation, Super,
+    },
+    generic::{alg_tree::AlgTree, search::Solver, Alg, Movable},
+};
+
+use super::Cube3;
+
+/// `(U R L U2 R' L')2`, which turns the U center 180 degrees and doesn't move anything else.
+static HALF_TURN: Lazy<Alg<AxisMove>> =
+    Lazy::new(|| "U R L U2 R' L' U R L U2 R' L'".try_into().unwrap());
+
+/// `(R2 U2 R' U2 R2 U')3`, which turns the R and the U centers 90 degrees clockwise and doesn't move anything
+/// else.
+static QUARTER_TURNS: Lazy<Alg<AxisMove>> = Lazy::new(|| {
+    "R2 U2 R' U2 R2 U' R2 U2 R' U2 R2 U' R2 U2 R' U2 R2 U'"
+        .try_into()
+        .unwrap()
+});
+
+/// The faces in an order such that each one is next to the following one.
+const FACES: [Face; 6] = [Face::U, Face::F, Face::R, Face::B, Face::L, Face::D];
+
+impl Super<Cube3> {
+    /// Solves the cube, using `solver` for the pieces and then turning the centers in place.
+    ///
+    /// The solution has a comment after each stage: 3x3x3 and centers. Returns `None` if the solver can't solve
+    /// the cube.
+    pub fn solve_with(&self, solver: &impl Solver<Cube3, AxisMove>) -> Option<AlgTree<AxisMove>> {
+        let mut stages = Stages::new();
+        let mut cube = self.clone();
+
+        let solution = solver.solve(&cube.base_cube)?;
+        cube.apply(&solution);
+        stages.push("3x3x3", solution);
+
+        let centers = center_turns(&cube.centers);
+        stages.push("centers", centers);
+
+        Some(stages.into())
+    }
+}
+
+/// An alg that turns the middle centers back in place when they're turned by `centers`, without moving anything
+/// else on the 3x3x3.
+///
+/// Bigger odd cubes can use it too, although it moves the rest of the centers of each face along with the middle
+/// one.
+pub(crate) fn center_turns(centers: &[u8; 6]) -> Alg<AxisMove> {
+    let mut centers = *centers;
+    let mut alg = Alg::empty();
+
+    let mut turn = |centers: &mut [u8; 6], turns: Alg<AxisMove>| {
+        turns
+            .moves
+            .iter()
+            .for_each(|m| turn_middle_center(centers, m));
+        alg.moves.extend(turns.moves);
+    };
+
+    // Odd turns get passed on to the next face, the last one can't have them since the cube is solvable
+    for pair in FACES.windows(2) {
+        if centers[pair[0].index()] % 2 == 1 {
+            turn(
+                &mut centers,
+                reoriented(&QUARTER_TURNS, Some(&pair[0]), &pair[1]).reversed(),
+            );
+        }
+    }
+
+    for face in FACES {
+        if centers[face.index()] == 2 {
+            turn(&mut centers, reoriented(&HALF_TURN, None, &face));
+        }
+    }
+
+    alg
+}
+
+/// Translates `alg` such that it does on `r` (if given) and `u` what it did on the R and U faces.
+fn reoriented(alg: &Alg<AxisMove>, r: Option<&Face>, u: &Face) -> Alg<AxisMove> {
+    let orientation = Orientation::all()
+        .find(|orientation| {
+            r.into_iter().all(|r| Face::R.reoriented(orientation) == *r)
+                && Face::U.reoriented(orientation) == *u
+        })
+        .expect("The faces should be next to each other");
+
+    alg.clone().reoriented(&orientation)
+}
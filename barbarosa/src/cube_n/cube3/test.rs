@@ -4,10 +4,14 @@ use std::collections::HashSet;
 
 use itertools::iproduct;
 use nalgebra::{vector, Vector3};
+use rand::{rngs::StdRng, SeedableRng};
 
 use crate::{
-    cube_n::space::Direction,
-    generic::{piece::Coordinates, Cube, Movable, Parsable},
+    cube_n::{
+        moves::perms::{self, pll},
+        space::Direction,
+    },
+    generic::{piece::Coordinates, Alg, Cube, Movable, Parsable, StateAlgebra},
 };
 
 use super::*;
@@ -77,3 +81,80 @@ fn test_f() {
         vector![Direction::Negative, Direction::Positive]
     );
 }
+
+#[test]
+fn composing_states_is_applying_algs() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..20 {
+        let a = Alg::<AxisMove>::random_with_rng(15, &mut rng);
+        let b = Alg::<AxisMove>::random_with_rng(15, &mut rng);
+
+        let state_a = Cube3::SOLVED.moved(&a);
+        let state_b = Cube3::SOLVED.moved(&b);
+
+        assert_eq!(state_a.compose(&state_b), state_a.clone().moved(&b));
+        assert_eq!(state_a.inverse(), Cube3::SOLVED.moved(&a.reversed()));
+        assert!(state_a.compose(&state_a.inverse()).is_solved());
+    }
+}
+
+#[test]
+fn orders() {
+    let order = |alg: &Alg<AxisMove>| Cube3::SOLVED.moved(alg).order();
+
+    assert_eq!(order(&Alg::empty()), 1);
+    assert_eq!(order(&Alg::parse("R").unwrap()), 4);
+    assert_eq!(order(&Alg::parse("R2").unwrap()), 2);
+    assert_eq!(order(&perms::SEXY_MOVE), 6);
+    assert_eq!(order(&pll::T), 2);
+    assert_eq!(order(&pll::U), 3);
+    assert_eq!(order(&Alg::parse("R U").unwrap()), 105);
+}
+
+#[test]
+fn order_brings_back_to_solved() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..10 {
+        let state = Cube3::SOLVED.moved(&Alg::<AxisMove>::random_with_rng(6, &mut rng));
+        let order = state.order();
+
+        let mut cube = Cube3::SOLVED;
+        for i in 1..=order {
+            cube = cube.compose(&state);
+            assert_eq!(cube.is_solved(), i == order);
+        }
+    }
+}
+
+#[test]
+fn cycles() {
+    let cycles = |alg: &str| {
+        Cube3::SOLVED
+            .moved(&Alg::<AxisMove>::parse(alg).unwrap())
+            .cycles()
+            .to_string()
+    };
+
+    assert_eq!(cycles(""), "");
+    assert_eq!(cycles("R2"), "(UFR DBR) (DFR UBR) (FR BR) (UR DR)");
+    assert_eq!(cycles("R U R' U'").matches('(').count(), 3);
+
+    // A single edge flipped in place shows up as a 1-cycle with a twist
+    let mut cube = Cube3::SOLVED;
+    cube.edges.iter_mut_unchecked().next().unwrap().flip();
+    assert_eq!(cube.cycles().edges.len(), 1);
+    assert_eq!(cube.cycles().edges[0].to_string(), "(UF)+");
+
+    // A corner twisted once and one twisted twice
+    let mut cube = Cube3::SOLVED;
+    for (i, corner) in cube.corners.iter_mut_unchecked().take(2).enumerate() {
+        for _ in 0..=i {
+            corner.twist();
+        }
+    }
+
+    assert_eq!(cube.cycles().corners.len(), 2);
+    assert_eq!(cube.corners.order(), 3);
+}
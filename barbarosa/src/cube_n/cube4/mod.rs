@@ -1,12 +1,17 @@
+mod reduction;
 mod test;
 
-use crate::generic::{self, moves::AsMove};
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+
+use crate::generic::{self, algebra::impl_state_algebra, moves::AsMove, Cube, StateAlgebra};
 
 use super::{
     center::corner::CenterCornerSet,
-    moves::wide::impl_movable_wide_move_inductively,
+    invariants::{randomize_center_corners, randomize_corners, randomize_wings},
+    moves::{slice::impl_movable_slice_move, wide::impl_movable_wide_move_inductively},
+    order::PieceOrders,
     pieces::{corner::CornerSet, wing::WingSet},
-    CubeN, WideAxisMove,
+    CubeN, SuperCube, WideAxisMove,
 };
 
 /// The 4x4x4 cube.
@@ -48,6 +53,46 @@ impl generic::Movable<WideAxisMove<1>> for Cube4 {
 
 impl CubeN for Cube4 {
     const N: u32 = 4;
+    type WideMove = WideAxisMove<1>;
+
+    fn piece_orders(&self) -> PieceOrders {
+        PieceOrders {
+            corners: self.corners.order(),
+            edges: None,
+            wings: Some(self.wings.order()),
+            centers: Some(self.centers.period()),
+        }
+    }
 }
 
 impl_movable_wide_move_inductively!(Cube4, 1, [0]);
+impl_state_algebra!(Cube4, [corners, wings, centers]);
+impl_movable_slice_move!(Cube4, 1, [1, 2]);
+
+impl Distribution<Cube4> for Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Cube4 {
+        let mut cube = Cube4::SOLVED;
+
+        let corner_parity = randomize_corners(&mut cube.corners, rng);
+        randomize_wings(&mut cube.wings, rng);
+        randomize_center_corners(&mut cube.centers, corner_parity, rng);
+
+        cube
+    }
+}
+
+/// The centers are told apart by where they are in the solved cube, so there's nothing else to track.
+impl SuperCube for Cube4 {
+    type Centers = ();
+
+    const SOLVED_CENTERS: Self::Centers = ();
+
+    fn move_centers(_centers: &mut Self::Centers, _m: &WideAxisMove<1>) {}
+
+    fn are_centers_solved(&self, _centers: &Self::Centers) -> bool {
+        self.centers == CenterCornerSet::SOLVED
+    }
+
+    /// The centers of a random cube already have the right permutation parities.
+    fn random_centers<R: Rng + ?Sized>(&self, _rng: &mut R) -> Self::Centers {}
+}
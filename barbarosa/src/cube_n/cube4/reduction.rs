@@ -0,0 +1,144 @@
+//! Solving the 4x4x4 by reduction. See [`crate::cube_n::reduction`] for more info.
+
+mod test;
+
+use once_cell::sync::Lazy;
+
+use crate::{
+    cube_n::{
+        center::corner::CenterCorner,
+        invariants::is_odd_permutation,
+        moves::perms::parity,
+        reduction::{
+            has_parity, identical_piece_targets, paired_edges, paired_wing_targets, parse,
+            NotReducedError, Stages, ThreeCycles,
+        },
+        AxisMove, Cube3, Super, WideAxisMove, Wing,
+    },
+    generic::{alg_tree::AlgTree, piece::PieceSetDescriptor, search::Solver, Alg, Movable},
+};
+
+use super::Cube4;
+
+/// `[R u R', d]`
+static CENTER_CYCLES: Lazy<ThreeCycles<CenterCorner, 1>> = Lazy::new(|| {
+    ThreeCycles::new::<24, 1, 0>(["R Uw U' R' Dw D' R U Uw' R' D Dw'".try_into().unwrap()])
+});
+
+/// `[R U R', u]`
+static WING_CYCLES: Lazy<ThreeCycles<Wing, 1>> =
+    Lazy::new(|| ThreeCycles::new::<24, 1, 0>(["R U R' Uw U' R U' R' U Uw'".try_into().unwrap()]));
+
+/// `[2R 2U 2R', U]`, which doesn't move any other center, unlike the one used for reduction
+static SUPER_CENTER_CYCLES: Lazy<ThreeCycles<CenterCorner, 1>> =
+    Lazy::new(|| ThreeCycles::new::<24, 1, 0>([parse("2R 2U 2R' U 2R 2U' 2R' U'", 4)]));
+
+impl Cube4 {
+    /// Solves the cube by reduction, using `solver` for the 3x3x3 stage.
+    ///
+    /// The solution has a comment after each stage: centers, edge pairing, OLL and PLL parity (if needed) and the
+    /// 3x3x3. Returns `None` if the solver can't solve the virtual 3x3x3.
+    pub fn solve_by_reduction(
+        &self,
+        solver: &impl Solver<Cube3, AxisMove>,
+    ) -> Option<AlgTree<WideAxisMove<1>>> {
+        let mut stages = Stages::new();
+        let virtual_cube = self.clone().reduce(&mut stages);
+
+        let solution: Alg<AxisMove> = solver.solve(&virtual_cube)?;
+        stages.push("3x3x3", solution.into());
+
+        Some(stages.into())
+    }
+
+    /// Reduces the cube, fixing the parities, and returns the virtual 3x3x3.
+    fn reduce(&mut self, stages: &mut Stages<WideAxisMove<1>>) -> Cube3 {
+        let centers = CENTER_CYCLES.solve(
+            &self.centers,
+            &identical_piece_targets(self.centers.pieces(), |_, _| true),
+        );
+        self.apply(&centers);
+        stages.push("centers", centers);
+
+        let edges = WING_CYCLES.solve(&self.wings, &paired_wing_targets(&self.wings));
+        self.apply(&edges);
+        stages.push("edge pairing", edges);
+
+        let flipped_edges = self
+            .virtual_cube3()
+            .edges
+            .into_iter()
+            .filter(|edge| !edge.oriented);
+        if flipped_edges.count() % 2 == 1 {
+            self.apply(&*parity::OLL);
+            stages.push("OLL parity", parity::OLL.clone());
+        }
+
+        let virtual_cube = self.virtual_cube3();
+        if is_odd_permutation(&virtual_cube.edges, |_| true)
+            != is_odd_permutation(&virtual_cube.corners, |_| true)
+        {
+            self.apply(&*parity::PLL);
+            stages.push("PLL parity", parity::PLL.clone());
+        }
+
+        self.virtual_cube3()
+    }
+
+    /// The 3x3x3 that the cube behaves like once it's reduced, which might have parity.
+    ///
+    /// # Panics
+    ///
+    /// If the cube is not reduced.
+    fn virtual_cube3(&self) -> Cube3 {
+        self.paired_cube3().expect("The cube should be reduced")
+    }
+
+    /// The virtual 3x3x3 of the cube, without checking for parity.
+    fn paired_cube3(&self) -> Result<Cube3, NotReducedError> {
+        if !self.centers.is_solved() {
+            return Err(NotReducedError::UnsolvedCenters);
+        }
+
+        Ok(Cube3 {
+            corners: self.corners.clone(),
+            edges: paired_edges(&self.wings).ok_or(NotReducedError::UnpairedEdges)?,
+        })
+    }
+}
+
+impl Super<Cube4> {
+    /// Solves the cube like [`Cube4::solve_by_reduction`], and then puts every center back where it was in the
+    /// solved cube. This last stage has the comment "center identity".
+    pub fn solve_by_reduction(
+        &self,
+        solver: &impl Solver<Cube3, AxisMove>,
+    ) -> Option<AlgTree<WideAxisMove<1>>> {
+        let mut cube = self.base_cube.clone();
+        let mut stages = Stages::new();
+        let virtual_cube = cube.reduce(&mut stages);
+
+        let solution: Alg<WideAxisMove<1>> = solver.solve(&virtual_cube)?.into();
+        cube.apply(&solution);
+        stages.push("3x3x3", solution);
+
+        let centers = SUPER_CENTER_CYCLES.solve(&cube.centers, &CenterCorner::REFERENCE_POSITIONS);
+        stages.push("center identity", centers);
+
+        Some(stages.into())
+    }
+}
+
+/// A reduced 4x4x4 behaves like a 3x3x3 with outer layer moves.
+impl TryFrom<&Cube4> for Cube3 {
+    type Error = NotReducedError;
+
+    fn try_from(cube: &Cube4) -> Result<Self, Self::Error> {
+        let virtual_cube = cube.paired_cube3()?;
+
+        match has_parity(&virtual_cube) {
+            true => Err(NotReducedError::Parity),
+            false => Ok(virtual_cube),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+#![cfg(test)]
+
+use pretty_assertions::assert_eq;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    cube3::heuristics,
+    cube_n::{
+        invariants::{fix_edge_flip_parity, fix_swap_parity},
+        space::faces::{F, U},
+        Edge, IntoSuper,
+    },
+    generic::{piece::PieceSetDescriptor, search::ida::IDASearcher, Cube, Parsable, Piece},
+};
+
+use super::*;
+
+#[test]
+fn three_cycles_are_pure() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..20 {
+        let mut positions = Wing::REFERENCE_POSITIONS.to_vec();
+        let cycle: [Wing; 3] =
+            std::array::from_fn(|_| positions.remove(rng.gen_range(0..positions.len())));
+
+        let cube = Cube4::SOLVED.moved(WING_CYCLES.cycle(cycle.clone()).unwrap());
+
+        assert!(cube.corners.is_solved());
+        assert!(cube
+            .centers
+            .set
+            .iter_with_pos()
+            .all(|(original, piece)| original == *piece));
+        for i in 0..3 {
+            assert_eq!(
+                cube.wings.piece_originally_at(&cycle[i]),
+                &cycle[(i + 1) % 3]
+            );
+        }
+        assert_eq!(
+            cube.wings
+                .iter_with_pos()
+                .filter(|(original, wing)| original != *wing)
+                .count(),
+            3
+        );
+    }
+}
+
+#[test]
+fn parity_algs_are_pure() {
+    let flipped = Cube4::SOLVED.moved(&*parity::OLL);
+    assert!(flipped.corners.is_solved() && flipped.centers.is_solved());
+
+    let virtual_cube = flipped.virtual_cube3();
+    let unsolved: Vec<_> = virtual_cube
+        .edges
+        .iter_with_pos()
+        .filter(|(original, edge)| !edge.is_solved(original))
+        .collect();
+
+    assert_eq!(unsolved.len(), 1);
+    assert_eq!(unsolved[0].0, Edge::position_from_faces([U, F]).unwrap());
+    assert_eq!(unsolved[0].1.position(), unsolved[0].0);
+
+    let swapped = Cube4::SOLVED.moved(&*parity::PLL);
+    assert!(swapped.corners.is_solved() && swapped.centers.is_solved());
+    assert_eq!(swapped.virtual_cube3().cycles().to_string(), "(UF UB)");
+}
+
+#[test]
+fn reduces_random_cubes() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..10 {
+        let scrambled: Cube4 = rng.gen();
+
+        let mut cube = scrambled.clone();
+        let mut stages = Stages::new();
+        let virtual_cube = cube.reduce(&mut stages);
+
+        assert!(cube.centers.is_solved());
+        assert_eq!(scrambled.moved(&AlgTree::from(stages).flatten()), cube);
+
+        let mut solvable = virtual_cube.clone();
+        fix_swap_parity(&mut solvable);
+        fix_edge_flip_parity(&mut solvable.edges);
+        assert_eq!(solvable, virtual_cube);
+    }
+}
+
+#[test]
+fn solves_with_parities() {
+    let solver = IDASearcher::new(heuristics::zero, Cube3::successors, 5);
+
+    for (setup, stages) in [
+        (Alg::empty(), vec!["3x3x3"]),
+        (parity::OLL.clone(), vec!["OLL parity", "3x3x3"]),
+        (parity::PLL.clone(), vec!["PLL parity", "3x3x3"]),
+    ] {
+        let scramble = Alg::<AxisMove>::parse("R U F'").unwrap();
+        let cube = Cube4::SOLVED.moved(&scramble).moved(&setup);
+
+        let solution = cube.solve_by_reduction(&solver).unwrap();
+
+        let comments: Vec<_> = solution
+            .comments()
+            .into_iter()
+            .map(|(_, comment)| comment)
+            .collect();
+        assert_eq!(comments, stages);
+        assert!(cube.moved(&solution.flatten()).is_solved());
+    }
+}
+
+#[test]
+#[ignore = "MUS takes too long to build"]
+fn solves_random_cubes() {
+    let solver = IDASearcher::new(heuristics::mus, Cube3::successors, 20);
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..5 {
+        let cube: Cube4 = rng.gen();
+        let solution = cube.solve_by_reduction(&solver).unwrap();
+
+        assert!(cube.moved(&solution.flatten()).is_solved());
+    }
+}
+
+#[test]
+fn solves_supercubes() {
+    let solver = IDASearcher::new(heuristics::zero, Cube3::successors, 5);
+
+    // Cycling centers of the same face keeps the cube solved, but not the supercube
+    let reference = &CenterCorner::REFERENCE_POSITIONS[0];
+    let same_face: Vec<_> = CenterCorner::REFERENCE_POSITIONS
+        .into_iter()
+        .filter(|center| center.is_solved(reference))
+        .take(3)
+        .collect();
+    let cycle = SUPER_CENTER_CYCLES
+        .cycle(same_face.try_into().unwrap())
+        .unwrap();
+
+    for (setup, stages) in [
+        (Alg::empty(), vec!["3x3x3"]),
+        (cycle.clone(), vec!["3x3x3", "center identity"]),
+    ] {
+        let scramble = Alg::<AxisMove>::parse("R U F'").unwrap();
+        let cube = Cube4::SOLVED.moved(&setup).moved(&scramble).into_super();
+
+        let solution = cube.solve_by_reduction(&solver).unwrap();
+
+        let comments: Vec<_> = solution
+            .comments()
+            .into_iter()
+            .map(|(_, comment)| comment)
+            .collect();
+        assert_eq!(comments, stages);
+
+        let solved = cube.moved(&solution.flatten());
+        assert!(solved.is_solved());
+        assert_eq!(solved.base_cube, Cube4::SOLVED);
+    }
+}
@@ -1,10 +1,12 @@
 #![cfg(test)]
 
 use pretty_assertions::assert_eq;
-use rand::{rngs::StdRng, SeedableRng};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 
 use crate::{
     cube_n::{
+        invariants::{fix_corner_multiplicity, is_odd_permutation},
+        moves::wide::Parsable,
         moves::{
             perms::{self, pll},
             Amount,
@@ -16,7 +18,7 @@ use crate::{
         },
         AxisMove, Wing,
     },
-    generic::{Alg, Cube, Movable},
+    generic::{Alg, Cube, Movable, StateAlgebra},
 };
 
 use super::*;
@@ -259,3 +261,93 @@ fn moves_by_all_widenesses() {
     Cube4::SOLVED.clone().apply(&w0);
     Cube4::SOLVED.clone().apply(&w1);
 }
+
+#[test]
+fn composes_states() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..10 {
+        let a = Alg::<WideAxisMove<1>>::random_unnormalized_with_rng(20, &mut rng);
+        let b = Alg::<WideAxisMove<1>>::random_unnormalized_with_rng(20, &mut rng);
+
+        let state_a = Cube4::SOLVED.moved(&a);
+        let state_b = Cube4::SOLVED.moved(&b);
+
+        assert_eq!(state_a.compose(&state_b), state_a.clone().moved(&b));
+        assert!(state_a.compose(&state_a.inverse()).is_solved());
+    }
+}
+
+#[test]
+fn orders() {
+    let wide_sexy = perms::SEXY_MOVE.clone().widen::<1>(1).unwrap();
+
+    assert_eq!(perms::SEXY_MOVE.order::<Cube4>(), 6);
+    assert_eq!(wide_sexy.order::<Cube4>(), 6);
+    assert_eq!(pll::T.clone().widen::<1>(1).unwrap().order::<Cube4>(), 2);
+
+    let r = Alg::<WideAxisMove<1>>::parse("Rw").unwrap();
+    let orders = r.piece_orders::<Cube4>();
+
+    assert_eq!(r.order::<Cube4>(), 4);
+    assert_eq!(orders.corners, 4);
+    assert_eq!(orders.edges, None);
+    assert_eq!(orders.wings, Some(4));
+    assert_eq!(orders.centers, Some(4));
+}
+
+#[test]
+fn order_brings_back_to_solved() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..10 {
+        let alg = Alg::<WideAxisMove<1>>::random_unnormalized_with_rng(4, &mut rng);
+        let order = alg.order::<Cube4>();
+
+        let mut cube = Cube4::SOLVED;
+        for i in 1..=order {
+            cube.apply(&alg);
+            assert_eq!(cube.is_solved(), i == order, "{alg} has order {order}");
+        }
+    }
+}
+
+#[test]
+fn period_ignores_identical_centers() {
+    let alg = Alg::<WideAxisMove<1>>::parse("Rw U Rw' U'").unwrap();
+    let state = Cube4::SOLVED.moved(&alg);
+
+    // Every center is back on its face long before they all get to their original positions
+    assert_eq!(state.order(), 90);
+    assert_eq!(state.period(), 18);
+    assert_eq!(alg.order::<Cube4>(), 18);
+}
+
+fn assert_invariants(cube: &Cube4) {
+    let mut corners = cube.corners.clone();
+    fix_corner_multiplicity(&mut corners);
+    assert_eq!(corners, cube.corners, "Corners are twisted");
+
+    let c = is_odd_permutation(&cube.corners, |_| true);
+    assert_eq!(is_odd_permutation(&cube.centers.set, |_| true), c);
+}
+
+#[test]
+fn scrambles_uphold_invariants() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..50 {
+        let alg = Alg::<WideAxisMove<1>>::random_unnormalized_with_rng(25, &mut rng);
+        assert_invariants(&Cube4::SOLVED.moved(&alg));
+    }
+}
+
+#[test]
+fn random_cubes_uphold_invariants() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..50 {
+        let cube: Cube4 = rng.gen();
+        assert_invariants(&cube);
+    }
+}
@@ -1,15 +1,27 @@
+mod reduction;
 mod test;
 
-use crate::generic::{self, moves::AsMove};
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+
+use crate::generic::{
+    self, algebra::impl_state_algebra, moves::AsMove, utils::lcm, Cube, StateAlgebra,
+};
 
 use super::{
-    moves::wide::impl_movable_wide_move_inductively,
+    invariants::{
+        randomize_center_corners, randomize_center_edges, randomize_corners, randomize_edges,
+        randomize_wings,
+    },
+    moves::{slice::impl_movable_slice_move, wide::impl_movable_wide_move_inductively},
+    order::PieceOrders,
     pieces::{
         center::{corner::CenterCornerSet, edge::CenterEdgeSet},
         corner::CornerSet,
         edge::EdgeSet,
+        wing::WingSet,
     },
-    CubeN, WideAxisMove,
+    supercube::{random_middle_centers, turn_middle_center},
+    CubeN, SuperCube, WideAxisMove,
 };
 
 /// The 5x5x5 cube.
@@ -19,6 +31,7 @@ use super::{
 pub struct Cube5 {
     corners: CornerSet,
     edges: EdgeSet,
+    wings: WingSet<1>,
     corner_centers: CenterCornerSet<1>,
     corner_edges: CenterEdgeSet<1>,
 }
@@ -31,15 +44,28 @@ impl generic::Cube for Cube5 {
     const SOLVED: Self = Self {
         corners: CornerSet::SOLVED,
         edges: EdgeSet::SOLVED,
+        wings: WingSet::SOLVED,
         corner_centers: CenterCornerSet::SOLVED,
         corner_edges: CenterEdgeSet::SOLVED,
     };
+
+    fn is_solved(&self) -> bool
+    where
+        Self: 'static,
+    {
+        self.corners.is_solved()
+            && self.edges.is_solved()
+            && self.wings.is_solved()
+            && self.corner_centers.is_solved()
+            && self.corner_edges.is_solved()
+    }
 }
 
 impl generic::Movable<WideAxisMove<1>> for Cube5 {
     fn apply(&mut self, m: &WideAxisMove<1>) {
         self.corners.apply(&m.axis_move);
         self.edges.apply(&m.axis_move);
+        self.wings.apply(m);
         self.corner_centers.apply(m);
         self.corner_edges.apply(m);
     }
@@ -47,6 +73,60 @@ impl generic::Movable<WideAxisMove<1>> for Cube5 {
 
 impl CubeN for Cube5 {
     const N: u32 = 5;
+    type WideMove = WideAxisMove<1>;
+
+    fn piece_orders(&self) -> PieceOrders {
+        PieceOrders {
+            corners: self.corners.order(),
+            edges: Some(self.edges.order()),
+            wings: Some(self.wings.order()),
+            centers: Some(lcm(
+                self.corner_centers.period(),
+                self.corner_edges.period(),
+            )),
+        }
+    }
 }
 
 impl_movable_wide_move_inductively!(Cube5, 1, [0]);
+impl_state_algebra!(Cube5, [corners, edges, wings, corner_centers, corner_edges]);
+impl_movable_slice_move!(Cube5, 1, [1, 2, 3]);
+
+impl Distribution<Cube5> for Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Cube5 {
+        let mut cube = Cube5::SOLVED;
+
+        let corner_parity = randomize_corners(&mut cube.corners, rng);
+        randomize_edges(&mut cube.edges, corner_parity, rng);
+        let wing_parity = randomize_wings(&mut cube.wings, rng);
+
+        randomize_center_corners(&mut cube.corner_centers, corner_parity, rng);
+        randomize_center_edges(&mut cube.corner_edges, corner_parity ^ wing_parity, rng);
+
+        cube
+    }
+}
+
+/// The centers are told apart by where they are in the solved cube, except the middle ones, which can only turn
+/// in place. Their turns are tracked like on the [`Cube3`](super::Cube3).
+impl SuperCube for Cube5 {
+    type Centers = [u8; 6];
+
+    const SOLVED_CENTERS: Self::Centers = [0; 6];
+
+    /// Wide moves never reach the middle layers, so each one turns the middle center of its face.
+    fn move_centers(centers: &mut Self::Centers, m: &WideAxisMove<1>) {
+        turn_middle_center(centers, &m.axis_move);
+    }
+
+    fn are_centers_solved(&self, centers: &Self::Centers) -> bool {
+        *centers == Self::SOLVED_CENTERS
+            && self.corner_centers == CenterCornerSet::SOLVED
+            && self.corner_edges == CenterEdgeSet::SOLVED
+    }
+
+    /// The other centers of a random cube already have the right permutation parities.
+    fn random_centers<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::Centers {
+        random_middle_centers(&self.corners, rng)
+    }
+}
@@ -0,0 +1,148 @@
+//! Solving the 5x5x5 by reduction. See [`crate::cube_n::reduction`] for more info.
+
+mod test;
+
+use once_cell::sync::Lazy;
+
+use crate::{
+    cube_n::{
+        center::{corner::CenterCorner, edge::CenterEdge},
+        cube3::supercube::center_turns,
+        reduction::{
+            matching_wing_targets, pair_wings, parse, NotReducedError, Stages, ThreeCycles,
+        },
+        AxisMove, Cube3, Super, WideAxisMove, Wing,
+    },
+    generic::{alg_tree::AlgTree, piece::PieceSetDescriptor, search::Solver, Alg, Movable},
+};
+
+use super::Cube5;
+
+/// `[Rw 2U Rw', U]`
+static CENTER_CORNER_CYCLES: Lazy<ThreeCycles<CenterCorner, 1>> =
+    Lazy::new(|| ThreeCycles::new::<24, 1, 0>([parse("Rw 2U Rw' U Rw 2U' Rw' U'", 5)]));
+
+/// `[Rw2 Fw2 Rw2, U2]`
+static CENTER_EDGE_CYCLES: Lazy<ThreeCycles<CenterEdge, 1>> =
+    Lazy::new(|| ThreeCycles::new::<24, 1, 0>([parse("Rw2 Fw2 Rw2 U2 Rw2 Fw2 Rw2 U2", 5)]));
+
+/// `[R U R', 2U]`
+static WING_CYCLES: Lazy<ThreeCycles<Wing, 1>> =
+    Lazy::new(|| ThreeCycles::new::<24, 1, 0>([parse("R U R' 2U R U' R' 2U'", 5)]));
+
+/// `[2R 2U 2R', U]`, which doesn't move any other center, unlike the one used for reduction
+static SUPER_CENTER_CORNER_CYCLES: Lazy<ThreeCycles<CenterCorner, 1>> =
+    Lazy::new(|| ThreeCycles::new::<24, 1, 0>([parse("2R 2U 2R' U 2R 2U' 2R' U'", 5)]));
+
+/// `[3R 2U 3R', U]`, which doesn't move any other center, unlike the one used for reduction
+static SUPER_CENTER_EDGE_CYCLES: Lazy<ThreeCycles<CenterEdge, 1>> =
+    Lazy::new(|| ThreeCycles::new::<24, 1, 0>([parse("3R 2U 3R' U 3R 2U' 3R' U'", 5)]));
+
+impl Cube5 {
+    /// Solves the cube by reduction, using `solver` for the 3x3x3 stage.
+    ///
+    /// The solution has a comment after each stage: centers, edge pairing and the 3x3x3. Returns `None` if the
+    /// solver can't solve the virtual 3x3x3.
+    pub fn solve_by_reduction(
+        &self,
+        solver: &impl Solver<Cube3, AxisMove>,
+    ) -> Option<AlgTree<WideAxisMove<1>>> {
+        let mut stages = Stages::new();
+        let virtual_cube = self.clone().reduce(&mut stages);
+
+        let solution: Alg<AxisMove> = solver.solve(&virtual_cube)?;
+        stages.push("3x3x3", solution.into());
+
+        Some(stages.into())
+    }
+
+    /// Reduces the cube and returns the virtual 3x3x3.
+    fn reduce(&mut self, stages: &mut Stages<WideAxisMove<1>>) -> Cube3 {
+        let mut centers = CENTER_CORNER_CYCLES.solve_identical(&self.corner_centers, |_, _| true);
+        self.apply(&centers);
+
+        let center_edges = CENTER_EDGE_CYCLES.solve_identical(&self.corner_edges, |_, _| true);
+        self.apply(&center_edges);
+        centers.moves.extend(center_edges.moves);
+        stages.push("centers", centers);
+
+        let edges = pair_wings(
+            &WING_CYCLES,
+            &self.wings,
+            &matching_wing_targets(&self.edges),
+            5,
+        );
+        self.apply(&edges);
+        stages.push("edge pairing", edges);
+
+        self.virtual_cube3()
+    }
+
+    /// The 3x3x3 that the cube behaves like once it's reduced.
+    ///
+    /// # Panics
+    ///
+    /// If the cube is not reduced.
+    fn virtual_cube3(&self) -> Cube3 {
+        Cube3::try_from(self).expect("The cube should be reduced")
+    }
+}
+
+impl Super<Cube5> {
+    /// Solves the cube like [`Cube5::solve_by_reduction`], and then turns the middle centers in place and puts
+    /// every other center back where it was in the solved cube. These last stages have the comments "center
+    /// orientation" and "center identity".
+    pub fn solve_by_reduction(
+        &self,
+        solver: &impl Solver<Cube3, AxisMove>,
+    ) -> Option<AlgTree<WideAxisMove<1>>> {
+        let mut stages = Stages::new();
+        let virtual_cube = self.base_cube.clone().reduce(&mut stages);
+        let mut cube = self.clone().moved(&stages.alg());
+
+        let solution: Alg<WideAxisMove<1>> = solver.solve(&virtual_cube)?.into();
+        cube.apply(&solution);
+        stages.push("3x3x3", solution);
+
+        // Turning the middle centers turns the rest of the centers of their face too, which get solved afterwards
+        let turns: Alg<WideAxisMove<1>> = center_turns(&cube.centers).into();
+        cube.apply(&turns);
+        stages.push("center orientation", turns);
+
+        let cube = cube.base_cube;
+
+        // The 3-cycles are pure, so each orbit can be solved independently
+        let centers: Alg<_> = [
+            SUPER_CENTER_CORNER_CYCLES
+                .solve(&cube.corner_centers, &CenterCorner::REFERENCE_POSITIONS),
+            SUPER_CENTER_EDGE_CYCLES.solve(&cube.corner_edges, &CenterEdge::REFERENCE_POSITIONS),
+        ]
+        .into_iter()
+        .flat_map(|alg| alg.moves)
+        .collect();
+        stages.push("center identity", centers);
+
+        Some(stages.into())
+    }
+}
+
+/// A reduced 5x5x5 behaves like a 3x3x3 with outer layer moves.
+impl TryFrom<&Cube5> for Cube3 {
+    type Error = NotReducedError;
+
+    fn try_from(cube: &Cube5) -> Result<Self, Self::Error> {
+        if !cube.corner_centers.is_solved() || !cube.corner_edges.is_solved() {
+            return Err(NotReducedError::UnsolvedCenters);
+        }
+
+        let targets = matching_wing_targets(&cube.edges);
+        if cube.wings.pieces() != &targets {
+            return Err(NotReducedError::UnpairedEdges);
+        }
+
+        Ok(Cube3 {
+            corners: cube.corners.clone(),
+            edges: cube.edges.clone(),
+        })
+    }
+}
@@ -0,0 +1,131 @@
+#![cfg(test)]
+
+use pretty_assertions::assert_eq;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    cube3::heuristics,
+    cube_n::{
+        invariants::{fix_edge_flip_parity, fix_swap_parity},
+        moves::perms::parity,
+        IntoSuper,
+    },
+    generic::{search::ida::IDASearcher, Cube, Parsable, Piece},
+};
+
+use super::*;
+
+#[test]
+fn reduces_random_cubes() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..10 {
+        let scrambled: Cube5 = rng.gen();
+
+        let mut cube = scrambled.clone();
+        let mut stages = Stages::new();
+        let virtual_cube = cube.reduce(&mut stages);
+
+        assert!(cube.corner_centers.is_solved() && cube.corner_edges.is_solved());
+        assert_eq!(cube.wings.pieces(), &matching_wing_targets(&cube.edges));
+        assert_eq!(scrambled.moved(&AlgTree::from(stages).flatten()), cube);
+
+        let mut solvable = virtual_cube.clone();
+        fix_swap_parity(&mut solvable);
+        fix_edge_flip_parity(&mut solvable.edges);
+        assert_eq!(solvable, virtual_cube);
+    }
+}
+
+#[test]
+#[ignore = "MUS takes too long to build"]
+fn solves_random_cubes() {
+    let solver = IDASearcher::new(heuristics::mus, Cube3::successors, 20);
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..5 {
+        let cube: Cube5 = rng.gen();
+        let solution = cube.solve_by_reduction(&solver).unwrap();
+
+        assert!(cube.moved(&solution.flatten()).is_solved());
+    }
+}
+
+#[test]
+fn solves_with_edge_parity() {
+    let solver = IDASearcher::new(heuristics::zero, Cube3::successors, 5);
+
+    for (setup, stages) in [
+        (Alg::empty(), vec!["3x3x3"]),
+        (parity::flip::<1>(5, 1), vec!["edge pairing", "3x3x3"]),
+    ] {
+        let scramble = Alg::<AxisMove>::parse("R U F'").unwrap();
+        let cube = Cube5::SOLVED.moved(&scramble).moved(&setup);
+
+        let solution = cube.solve_by_reduction(&solver).unwrap();
+
+        let comments: Vec<_> = solution
+            .comments()
+            .into_iter()
+            .map(|(_, comment)| comment)
+            .collect();
+        assert_eq!(comments, stages);
+        assert!(cube.moved(&solution.flatten()).is_solved());
+    }
+}
+
+#[test]
+fn solves_supercubes() {
+    let solver = IDASearcher::new(heuristics::zero, Cube3::successors, 5);
+
+    // Cycling centers of the same face keeps the cube solved, but not the supercube
+    let reference = &CenterEdge::REFERENCE_POSITIONS[0];
+    let same_face: Vec<_> = CenterEdge::REFERENCE_POSITIONS
+        .into_iter()
+        .filter(|center| center.is_solved(reference))
+        .take(3)
+        .collect();
+    let cycle = SUPER_CENTER_EDGE_CYCLES
+        .cycle(same_face.try_into().unwrap())
+        .unwrap();
+
+    for (setup, stages) in [
+        (Alg::empty(), vec!["3x3x3"]),
+        (cycle.clone(), vec!["3x3x3", "center identity"]),
+    ] {
+        let scramble = Alg::<WideAxisMove<1>>::parse("R U F'").unwrap();
+        let cube = Cube5::SOLVED.moved(&setup).into_super().moved(&scramble);
+
+        let solution = cube.solve_by_reduction(&solver).unwrap();
+
+        let comments: Vec<_> = solution
+            .comments()
+            .into_iter()
+            .map(|(_, comment)| comment)
+            .collect();
+        assert_eq!(comments, stages);
+
+        let solved = cube.moved(&solution.flatten());
+        assert!(solved.is_solved());
+        assert_eq!(solved.base_cube, Cube5::SOLVED);
+    }
+}
+
+#[test]
+fn solves_turned_middle_centers() {
+    let solver = IDASearcher::new(heuristics::zero, Cube3::successors, 5);
+    let cube = Super {
+        base_cube: Cube5::SOLVED,
+        centers: [2, 1, 1, 0, 0, 0],
+    };
+
+    let solution = cube.solve_by_reduction(&solver).unwrap();
+
+    let comments: Vec<_> = solution
+        .comments()
+        .into_iter()
+        .map(|(_, comment)| comment)
+        .collect();
+    assert_eq!(comments, ["center orientation", "center identity"]);
+    assert!(cube.moved(&solution.flatten()).is_solved());
+}
@@ -1,8 +1,15 @@
 #![cfg(test)]
 
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
 use crate::{
-    cube_n::{moves::perms::pll, space::Face},
-    generic::{Alg, Cube, Movable},
+    cube_n::{
+        invariants::{fix_corner_multiplicity, fix_edge_flip_parity, is_odd_permutation},
+        moves::perms::{self, pll},
+        space::Face,
+        WideAxisMove,
+    },
+    generic::{Alg, Cube, Movable, Parsable},
 };
 
 use super::Cube5;
@@ -44,3 +51,72 @@ fn half_wide_t_perm() {
 
     assert!(cube.is_solved());
 }
+
+#[test]
+fn moves_wings() {
+    let cube = Cube5::SOLVED.moved(&Alg::<WideAxisMove<1>>::parse("Rw U Rw'").unwrap());
+
+    assert!(!cube.wings.is_solved());
+    assert!(!cube.edges.is_solved());
+}
+
+#[test]
+fn orders() {
+    assert_eq!(perms::SEXY_MOVE.order::<Cube5>(), 6);
+    assert_eq!(pll::T.clone().widen::<1>(1).unwrap().order::<Cube5>(), 2);
+
+    let orders = Alg::<WideAxisMove<1>>::parse("Rw U")
+        .unwrap()
+        .piece_orders::<Cube5>();
+
+    assert_eq!(orders.corners, 15);
+    assert_eq!(orders.edges, Some(7));
+    assert!(orders.wings.is_some());
+    assert!(orders.centers.is_some());
+}
+
+#[test]
+fn only_wide_moves_turn_inner_centers() {
+    let cube = Cube5::SOLVED.moved(&Alg::<WideAxisMove<1>>::parse("R Rw'").unwrap());
+
+    assert!(cube.corners.is_solved());
+    assert!(!cube.corner_edges.is_solved());
+    assert!(!cube.corner_centers.is_solved());
+}
+
+fn assert_invariants(cube: &Cube5) {
+    let mut corners = cube.corners.clone();
+    fix_corner_multiplicity(&mut corners);
+    assert_eq!(corners, cube.corners, "Corners are twisted");
+
+    let mut edges = cube.edges.clone();
+    fix_edge_flip_parity(&mut edges);
+    assert_eq!(edges, cube.edges, "Edges are flipped");
+
+    let c = is_odd_permutation(&cube.corners, |_| true);
+    let w = is_odd_permutation(&cube.wings.set, |_| true);
+
+    assert_eq!(is_odd_permutation(&cube.edges, |_| true), c);
+    assert_eq!(is_odd_permutation(&cube.corner_centers.set, |_| true), c);
+    assert_eq!(is_odd_permutation(&cube.corner_edges.set, |_| true), c ^ w);
+}
+
+#[test]
+fn scrambles_uphold_invariants() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..50 {
+        let alg = Alg::<WideAxisMove<1>>::random_unnormalized_with_rng(25, &mut rng);
+        assert_invariants(&Cube5::SOLVED.moved(&alg));
+    }
+}
+
+#[test]
+fn random_cubes_uphold_invariants() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..50 {
+        let cube: Cube5 = rng.gen();
+        assert_invariants(&cube);
+    }
+}
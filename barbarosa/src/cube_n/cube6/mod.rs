@@ -1,12 +1,21 @@
+mod reduction;
 mod test;
 
-use crate::generic::{self, moves::AsMove};
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+
+use crate::generic::{
+    self, algebra::impl_state_algebra, moves::AsMove, utils::lcm, Cube, StateAlgebra,
+};
 
 use super::{
     center::{corner::CenterCornerSet, wing::CenterWingSet},
-    moves::wide::impl_movable_wide_move_inductively,
+    invariants::{
+        randomize_center_corners, randomize_center_wings, randomize_corners, randomize_wings,
+    },
+    moves::{slice::impl_movable_slice_move, wide::impl_movable_wide_move_inductively},
+    order::PieceOrders,
     pieces::{corner::CornerSet, wing::WingSet},
-    CubeN, WideAxisMove,
+    CubeN, SuperCube, WideAxisMove,
 };
 
 /// The 6x6x6 cube.
@@ -66,6 +75,74 @@ impl generic::Movable<WideAxisMove<2>> for Cube6 {
 
 impl CubeN for Cube6 {
     const N: u32 = 6;
+    type WideMove = WideAxisMove<2>;
+
+    fn piece_orders(&self) -> PieceOrders {
+        PieceOrders {
+            corners: self.corners.order(),
+            edges: None,
+            wings: Some(lcm(self.wings_1.order(), self.wings_2.order())),
+            centers: Some(
+                [
+                    self.center_corners_1.period(),
+                    self.center_corners_2.period(),
+                    self.center_wings.period(),
+                ]
+                .into_iter()
+                .fold(1, lcm),
+            ),
+        }
+    }
 }
 
 impl_movable_wide_move_inductively!(Cube6, 2, [0, 1]);
+impl_state_algebra!(
+    Cube6,
+    [
+        corners,
+        wings_1,
+        wings_2,
+        center_corners_1,
+        center_corners_2,
+        center_wings,
+    ]
+);
+impl_movable_slice_move!(Cube6, 2, [1, 2, 3, 4]);
+
+impl Distribution<Cube6> for Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Cube6 {
+        let mut cube = Cube6::SOLVED;
+
+        let corner_parity = randomize_corners(&mut cube.corners, rng);
+        let wing_parity_1 = randomize_wings(&mut cube.wings_1, rng);
+        let wing_parity_2 = randomize_wings(&mut cube.wings_2, rng);
+
+        randomize_center_corners(&mut cube.center_corners_1, corner_parity, rng);
+        randomize_center_corners(&mut cube.center_corners_2, corner_parity, rng);
+        randomize_center_wings(
+            &mut cube.center_wings,
+            corner_parity ^ wing_parity_1 ^ wing_parity_2,
+            rng,
+        );
+
+        cube
+    }
+}
+
+/// The centers are told apart by where they are in the solved cube, so there's nothing else to track.
+impl SuperCube for Cube6 {
+    type Centers = ();
+
+    const SOLVED_CENTERS: Self::Centers = ();
+
+    fn move_centers(_centers: &mut Self::Centers, _m: &WideAxisMove<2>) {}
+
+    fn are_centers_solved(&self, _centers: &Self::Centers) -> bool {
+        self.center_corners_1 == CenterCornerSet::SOLVED
+            && self.center_corners_2 == CenterCornerSet::SOLVED
+            && self.center_wings == CenterWingSet::SOLVED
+    }
+
+    /// The centers of a random cube already have the right permutation parities.
+    fn random_centers<R: Rng + ?Sized>(&self, _rng: &mut R) -> Self::Centers {}
+}
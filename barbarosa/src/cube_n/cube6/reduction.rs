@@ -0,0 +1,221 @@
+//! Solving the 6x6x6 by reduction. See [`crate::cube_n::reduction`] for more info.
+
+mod test;
+
+use once_cell::sync::Lazy;
+
+use crate::{
+    cube_n::{
+        center::{corner::CenterCorner, wing::CenterWing},
+        invariants::is_odd_permutation,
+        moves::perms::parity,
+        reduction::{
+            apply_steps, has_parity, matching_wing_targets, pair_wings, paired_edges,
+            paired_wing_targets, parse, swapped_wing_targets, NotReducedError, Stages, ThreeCycles,
+        },
+        space::faces::{B, F, U},
+        AxisMove, Cube3, Edge, Super, WideAxisMove, Wing,
+    },
+    generic::{alg_tree::AlgTree, piece::PieceSetDescriptor, search::Solver, Alg, Movable},
+};
+
+use super::Cube6;
+
+/// `[Rw 2U Rw', U]`
+static CENTER_CORNER_CYCLES_1: Lazy<ThreeCycles<CenterCorner, 2>> =
+    Lazy::new(|| ThreeCycles::new::<24, 1, 0>([parse("Rw 2U Rw' U Rw 2U' Rw' U'", 6)]));
+
+/// `[3R 3U 3R', Uw]`
+static CENTER_CORNER_CYCLES_2: Lazy<ThreeCycles<CenterCorner, 2>> =
+    Lazy::new(|| ThreeCycles::new::<24, 2, 0>([parse("3R 3U 3R' Uw 3R 3U' 3R' Uw'", 6)]));
+
+/// `[R 3U R', Uw]` and `[R 3D R', Uw]`, one for each chirality
+static CENTER_WING_CYCLES: Lazy<ThreeCycles<CenterWing, 2>> = Lazy::new(|| {
+    ThreeCycles::new::<48, 1, 2>([
+        parse("R 3U R' Uw R 3U' R' Uw'", 6),
+        parse("R 3D R' Uw R 3D' R' Uw'", 6),
+    ])
+});
+
+/// `[R U R', 2U]`
+static WING_CYCLES_1: Lazy<ThreeCycles<Wing, 2>> =
+    Lazy::new(|| ThreeCycles::new::<24, 1, 0>([parse("R U R' 2U R U' R' 2U'", 6)]));
+
+/// `[R U R', 3U]`
+static WING_CYCLES_2: Lazy<ThreeCycles<Wing, 2>> =
+    Lazy::new(|| ThreeCycles::new::<24, 2, 0>([parse("R U R' 3U R U' R' 3U'", 6)]));
+
+/// `[2R 2U 2R', U]`, which doesn't move any other center, unlike the one used for reduction
+static SUPER_CENTER_CORNER_CYCLES_1: Lazy<ThreeCycles<CenterCorner, 2>> =
+    Lazy::new(|| ThreeCycles::new::<24, 1, 0>([parse("2R 2U 2R' U 2R 2U' 2R' U'", 6)]));
+
+/// `[3R 3U 3R', U]`, which doesn't move any other center, unlike the one used for reduction
+static SUPER_CENTER_CORNER_CYCLES_2: Lazy<ThreeCycles<CenterCorner, 2>> =
+    Lazy::new(|| ThreeCycles::new::<24, 2, 0>([parse("3R 3U 3R' U 3R 3U' 3R' U'", 6)]));
+
+/// `[2R 3U 2R', U]` and `[2R 3D 2R', U]`, one for each chirality. Unlike the ones used for reduction, they don't
+/// move any other center
+static SUPER_CENTER_WING_CYCLES: Lazy<ThreeCycles<CenterWing, 2>> = Lazy::new(|| {
+    ThreeCycles::new::<48, 1, 2>([
+        parse("2R 3U 2R' U 2R 3U' 2R' U'", 6),
+        parse("2R 3D 2R' U 2R 3D' 2R' U'", 6),
+    ])
+});
+
+impl Cube6 {
+    /// Solves the cube by reduction, using `solver` for the 3x3x3 stage.
+    ///
+    /// The solution has a comment after each stage: centers, edge pairing, OLL and PLL parity (if needed) and the
+    /// 3x3x3. Returns `None` if the solver can't solve the virtual 3x3x3.
+    pub fn solve_by_reduction(
+        &self,
+        solver: &impl Solver<Cube3, AxisMove>,
+    ) -> Option<AlgTree<WideAxisMove<2>>> {
+        let mut stages = Stages::new();
+        let virtual_cube = self.clone().reduce(&mut stages);
+
+        let solution: Alg<AxisMove> = solver.solve(&virtual_cube)?;
+        stages.push("3x3x3", solution.into());
+
+        Some(stages.into())
+    }
+
+    /// Reduces the cube, fixing the parities, and returns the virtual 3x3x3.
+    fn reduce(&mut self, stages: &mut Stages<WideAxisMove<2>>) -> Cube3 {
+        // Each orbit gets solved after the previous one, since the 3-cycles move the centers of other orbits
+        let centers = apply_steps(
+            self,
+            &[
+                &|cube| CENTER_CORNER_CYCLES_1.solve_identical(&cube.center_corners_1, |_, _| true),
+                &|cube| CENTER_CORNER_CYCLES_2.solve_identical(&cube.center_corners_2, |_, _| true),
+                &|cube| {
+                    CENTER_WING_CYCLES
+                        .solve_identical(&cube.center_wings, |a, b| a.chirality() == b.chirality())
+                },
+            ],
+        );
+        stages.push("centers", centers);
+
+        // The wings of depth 1 get paired with each other, and then the ones of depth 2 get matched to them
+        let mut edges = WING_CYCLES_1.solve(&self.wings_1, &paired_wing_targets(&self.wings_1));
+        self.apply(&edges);
+
+        let targets =
+            matching_wing_targets(&paired_edges(&self.wings_1).expect("Wings should be paired"));
+        let alg = pair_wings(&WING_CYCLES_2, &self.wings_2, &targets, 6);
+        self.apply(&alg);
+        edges.moves.extend(alg.moves);
+        stages.push("edge pairing", edges);
+
+        let flipped_edges = self
+            .virtual_cube3()
+            .edges
+            .into_iter()
+            .filter(|edge| !edge.oriented);
+        if flipped_edges.count() % 2 == 1 {
+            let mut flip = parity::flip(6, 1);
+            flip.moves.extend(parity::flip(6, 2).moves);
+
+            self.apply(&flip);
+            stages.push("OLL parity", flip);
+        }
+
+        // There's no short alg that swaps two edges keeping all the centers solved, but it can be done with the
+        // 3-cycles of each depth
+        let virtual_cube = self.virtual_cube3();
+        if is_odd_permutation(&virtual_cube.edges, |_| true)
+            != is_odd_permutation(&virtual_cube.corners, |_| true)
+        {
+            let slots = [[U, F], [U, B]]
+                .map(|faces| Edge::position_from_faces(faces).expect("The faces are not parallel"));
+
+            let mut swap =
+                WING_CYCLES_1.solve(&self.wings_1, &swapped_wing_targets(&self.wings_1, slots));
+            self.apply(&swap);
+
+            let alg =
+                WING_CYCLES_2.solve(&self.wings_2, &swapped_wing_targets(&self.wings_2, slots));
+            self.apply(&alg);
+            swap.moves.extend(alg.moves);
+
+            stages.push("PLL parity", swap);
+        }
+
+        self.virtual_cube3()
+    }
+
+    /// The 3x3x3 that the cube behaves like once it's reduced, which might have parity.
+    ///
+    /// # Panics
+    ///
+    /// If the cube is not reduced.
+    fn virtual_cube3(&self) -> Cube3 {
+        self.paired_cube3().expect("The cube should be reduced")
+    }
+
+    /// The virtual 3x3x3 of the cube, without checking for parity.
+    fn paired_cube3(&self) -> Result<Cube3, NotReducedError> {
+        if !self.center_corners_1.is_solved()
+            || !self.center_corners_2.is_solved()
+            || !self.center_wings.is_solved()
+        {
+            return Err(NotReducedError::UnsolvedCenters);
+        }
+
+        let edges = paired_edges(&self.wings_1).ok_or(NotReducedError::UnpairedEdges)?;
+        if self.wings_2.pieces() != &matching_wing_targets(&edges) {
+            return Err(NotReducedError::UnpairedEdges);
+        }
+
+        Ok(Cube3 {
+            corners: self.corners.clone(),
+            edges,
+        })
+    }
+}
+
+impl Super<Cube6> {
+    /// Solves the cube like [`Cube6::solve_by_reduction`], and then puts every center back where it was in the
+    /// solved cube. This last stage has the comment "center identity".
+    pub fn solve_by_reduction(
+        &self,
+        solver: &impl Solver<Cube3, AxisMove>,
+    ) -> Option<AlgTree<WideAxisMove<2>>> {
+        let mut cube = self.base_cube.clone();
+        let mut stages = Stages::new();
+        let virtual_cube = cube.reduce(&mut stages);
+
+        let solution: Alg<WideAxisMove<2>> = solver.solve(&virtual_cube)?.into();
+        cube.apply(&solution);
+        stages.push("3x3x3", solution);
+
+        // The 3-cycles are pure, so each orbit can be solved independently
+        let centers: Alg<_> = [
+            SUPER_CENTER_CORNER_CYCLES_1
+                .solve(&cube.center_corners_1, &CenterCorner::REFERENCE_POSITIONS),
+            SUPER_CENTER_CORNER_CYCLES_2
+                .solve(&cube.center_corners_2, &CenterCorner::REFERENCE_POSITIONS),
+            SUPER_CENTER_WING_CYCLES.solve(&cube.center_wings, &CenterWing::REFERENCE_POSITIONS),
+        ]
+        .into_iter()
+        .flat_map(|alg| alg.moves)
+        .collect();
+        stages.push("center identity", centers);
+
+        Some(stages.into())
+    }
+}
+
+/// A reduced 6x6x6 behaves like a 3x3x3 with outer layer moves.
+impl TryFrom<&Cube6> for Cube3 {
+    type Error = NotReducedError;
+
+    fn try_from(cube: &Cube6) -> Result<Self, Self::Error> {
+        let virtual_cube = cube.paired_cube3()?;
+
+        match has_parity(&virtual_cube) {
+            true => Err(NotReducedError::Parity),
+            false => Ok(virtual_cube),
+        }
+    }
+}
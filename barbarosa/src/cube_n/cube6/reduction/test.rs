@@ -0,0 +1,135 @@
+#![cfg(test)]
+
+use pretty_assertions::assert_eq;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    cube3::heuristics,
+    cube_n::{
+        invariants::{fix_edge_flip_parity, fix_swap_parity},
+        moves::perms::parity,
+        IntoSuper,
+    },
+    generic::{search::ida::IDASearcher, Cube, Parsable},
+};
+
+use super::*;
+
+#[test]
+fn reduces_random_cubes() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..10 {
+        let scrambled: Cube6 = rng.gen();
+
+        let mut cube = scrambled.clone();
+        let mut stages = Stages::new();
+        let virtual_cube = cube.reduce(&mut stages);
+
+        assert!(cube.center_corners_1.is_solved());
+        assert!(cube.center_corners_2.is_solved());
+        assert!(cube.center_wings.is_solved());
+        assert_eq!(
+            cube.wings_2.pieces(),
+            &matching_wing_targets(&virtual_cube.edges)
+        );
+        assert_eq!(scrambled.moved(&AlgTree::from(stages).flatten()), cube);
+
+        let mut solvable = virtual_cube.clone();
+        fix_swap_parity(&mut solvable);
+        fix_edge_flip_parity(&mut solvable.edges);
+        assert_eq!(solvable, virtual_cube);
+    }
+}
+
+#[test]
+#[ignore = "MUS takes too long to build"]
+fn solves_random_cubes() {
+    let solver = IDASearcher::new(heuristics::mus, Cube3::successors, 20);
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..5 {
+        let cube: Cube6 = rng.gen();
+        let solution = cube.solve_by_reduction(&solver).unwrap();
+
+        assert!(cube.moved(&solution.flatten()).is_solved());
+    }
+}
+
+#[test]
+fn solves_with_parities() {
+    let solver = IDASearcher::new(heuristics::zero, Cube3::successors, 5);
+
+    let mut oll = parity::flip(6, 1);
+    oll.moves.extend(parity::flip(6, 2).moves);
+
+    let slots = [[U, F], [U, B]].map(|faces| Edge::position_from_faces(faces).unwrap());
+    let mut pll = WING_CYCLES_1.solve(
+        &Cube6::SOLVED.wings_1,
+        &swapped_wing_targets(&Cube6::SOLVED.wings_1, slots),
+    );
+    pll.moves.extend(
+        WING_CYCLES_2
+            .solve(
+                &Cube6::SOLVED.wings_2,
+                &swapped_wing_targets(&Cube6::SOLVED.wings_2, slots),
+            )
+            .moves,
+    );
+
+    for (setup, stages) in [
+        (Alg::empty(), vec!["3x3x3"]),
+        (oll, vec!["OLL parity", "3x3x3"]),
+        (pll, vec!["PLL parity", "3x3x3"]),
+    ] {
+        let scramble = Alg::<AxisMove>::parse("R U F'").unwrap();
+        let cube = Cube6::SOLVED.moved(&scramble).moved(&setup);
+
+        let solution = cube.solve_by_reduction(&solver).unwrap();
+
+        let comments: Vec<_> = solution
+            .comments()
+            .into_iter()
+            .map(|(_, comment)| comment)
+            .collect();
+        assert_eq!(comments, stages);
+        assert!(cube.moved(&solution.flatten()).is_solved());
+    }
+}
+
+#[test]
+fn solves_supercubes() {
+    let solver = IDASearcher::new(heuristics::zero, Cube3::successors, 5);
+
+    // Cycling centers of the same face keeps the cube solved, but not the supercube
+    let reference = &CenterCorner::REFERENCE_POSITIONS[0];
+    let same_face: Vec<_> = CenterCorner::REFERENCE_POSITIONS
+        .into_iter()
+        .filter(|center| center.is_solved(reference))
+        .take(3)
+        .collect();
+    let cycle = SUPER_CENTER_CORNER_CYCLES_2
+        .cycle(same_face.try_into().unwrap())
+        .unwrap();
+
+    for (setup, stages) in [
+        (Alg::empty(), vec!["3x3x3"]),
+        (cycle.clone(), vec!["3x3x3", "center identity"]),
+    ] {
+        let scramble = Alg::<AxisMove>::parse("R U F'").unwrap();
+        let cube = Cube6::SOLVED.moved(&setup).moved(&scramble).into_super();
+
+        let solution = cube.solve_by_reduction(&solver).unwrap();
+
+        let comments: Vec<_> = solution
+            .comments()
+            .into_iter()
+            .map(|(_, comment)| comment)
+            .collect();
+        assert_eq!(comments, stages);
+
+        let solved = cube.moved(&solution.flatten());
+        assert!(solved.is_solved());
+        assert_eq!(solved.base_cube, Cube6::SOLVED);
+    }
+}
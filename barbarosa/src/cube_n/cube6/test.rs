@@ -1,14 +1,17 @@
 #![cfg(test)]
 
 use pretty_assertions::assert_eq;
+use rand::{rngs::StdRng, Rng, SeedableRng};
 
 use crate::{
     cube_n::{
+        invariants::{fix_corner_multiplicity, is_odd_permutation},
         moves::perms::{self, pll},
+        pieces::center::wing::CenterWing,
         space::{Axis, Direction},
         AxisMove,
     },
-    generic::{Cube, Movable},
+    generic::{Alg, Cube, Movable, StateAlgebra},
 };
 
 use super::*;
@@ -69,3 +72,76 @@ fn center_wings_normal_directions() {
     assert_eq!(uf.normal_axis(), Axis::X);
     assert_eq!(uf.normal_direction(), Direction::Positive);
 }
+
+#[test]
+fn orders() {
+    let alg = |depth| perms::SEXY_MOVE.clone().widen::<2>(depth).unwrap();
+
+    for depth in 0..=2 {
+        assert_eq!(alg(depth).order::<Cube6>(), 6);
+        assert_eq!(
+            pll::T.clone().widen::<2>(depth).unwrap().order::<Cube6>(),
+            2
+        );
+    }
+}
+
+#[test]
+fn order_brings_back_to_solved() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..5 {
+        let alg = Alg::<WideAxisMove<2>>::random_unnormalized_with_rng(3, &mut rng);
+        let state = Cube6::SOLVED.moved(&alg);
+        let order = state.period();
+
+        assert!(state.compose(&state.inverse()).is_solved());
+
+        let mut cube = Cube6::SOLVED;
+        for i in 1..=order {
+            cube.apply(&alg);
+            assert_eq!(cube.is_solved(), i == order, "{alg} has order {order}");
+        }
+    }
+}
+
+fn assert_invariants(cube: &Cube6) {
+    let mut corners = cube.corners.clone();
+    fix_corner_multiplicity(&mut corners);
+    assert_eq!(corners, cube.corners, "Corners are twisted");
+
+    let c = is_odd_permutation(&cube.corners, |_| true);
+    let w_1 = is_odd_permutation(&cube.wings_1.set, |_| true);
+    let w_2 = is_odd_permutation(&cube.wings_2.set, |_| true);
+
+    assert_eq!(is_odd_permutation(&cube.center_corners_1.set, |_| true), c);
+    assert_eq!(is_odd_permutation(&cube.center_corners_2.set, |_| true), c);
+
+    for chirality in [Direction::Positive, Direction::Negative] {
+        let orbit = |wing: &CenterWing| wing.chirality() == chirality;
+        assert_eq!(
+            is_odd_permutation(&cube.center_wings.set, orbit),
+            c ^ w_1 ^ w_2
+        );
+    }
+}
+
+#[test]
+fn scrambles_uphold_invariants() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..50 {
+        let alg = Alg::<WideAxisMove<2>>::random_unnormalized_with_rng(25, &mut rng);
+        assert_invariants(&Cube6::SOLVED.moved(&alg));
+    }
+}
+
+#[test]
+fn random_cubes_uphold_invariants() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..50 {
+        let cube: Cube6 = rng.gen();
+        assert_invariants(&cube);
+    }
+}
@@ -1,12 +1,23 @@
+mod reduction;
 mod test;
 
-use crate::generic::{self, moves::AsMove};
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+
+use crate::generic::{
+    self, algebra::impl_state_algebra, moves::AsMove, utils::lcm, Cube, StateAlgebra,
+};
 
 use super::{
     center::{corner::CenterCornerSet, edge::CenterEdgeSet, wing::CenterWingSet},
-    moves::wide::impl_movable_wide_move_inductively,
+    invariants::{
+        randomize_center_corners, randomize_center_edges, randomize_center_wings,
+        randomize_corners, randomize_edges, randomize_wings,
+    },
+    moves::{slice::impl_movable_slice_move, wide::impl_movable_wide_move_inductively},
+    order::PieceOrders,
     pieces::{corner::CornerSet, edge::EdgeSet, wing::WingSet},
-    CubeN, WideAxisMove,
+    supercube::{random_middle_centers, turn_middle_center},
+    CubeN, SuperCube, WideAxisMove,
 };
 
 /// The 7x7x7 cube. The biggest [WCA](https://www.worldcubeassociation.org/) `NxN`.
@@ -80,6 +91,91 @@ impl generic::Movable<WideAxisMove<2>> for Cube7 {
 
 impl CubeN for Cube7 {
     const N: u32 = 7;
+    type WideMove = WideAxisMove<2>;
+
+    fn piece_orders(&self) -> PieceOrders {
+        PieceOrders {
+            corners: self.corners.order(),
+            edges: Some(self.edges.order()),
+            wings: Some(lcm(self.wings_1.order(), self.wings_2.order())),
+            centers: Some(
+                [
+                    self.center_corners_1.period(),
+                    self.center_corners_2.period(),
+                    self.center_wings.period(),
+                    self.center_edges_1.period(),
+                    self.center_edges_2.period(),
+                ]
+                .into_iter()
+                .fold(1, lcm),
+            ),
+        }
+    }
 }
 
 impl_movable_wide_move_inductively!(Cube7, 2, [0, 1]);
+impl_state_algebra!(
+    Cube7,
+    [
+        corners,
+        edges,
+        wings_1,
+        wings_2,
+        center_corners_1,
+        center_corners_2,
+        center_wings,
+        center_edges_1,
+        center_edges_2,
+    ]
+);
+impl_movable_slice_move!(Cube7, 2, [1, 2, 3, 4, 5]);
+
+impl Distribution<Cube7> for Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Cube7 {
+        let mut cube = Cube7::SOLVED;
+
+        let corner_parity = randomize_corners(&mut cube.corners, rng);
+        randomize_edges(&mut cube.edges, corner_parity, rng);
+        let wing_parity_1 = randomize_wings(&mut cube.wings_1, rng);
+        let wing_parity_2 = randomize_wings(&mut cube.wings_2, rng);
+
+        randomize_center_corners(&mut cube.center_corners_1, corner_parity, rng);
+        randomize_center_corners(&mut cube.center_corners_2, corner_parity, rng);
+        randomize_center_edges(&mut cube.center_edges_1, corner_parity ^ wing_parity_1, rng);
+        randomize_center_edges(&mut cube.center_edges_2, corner_parity ^ wing_parity_2, rng);
+        randomize_center_wings(
+            &mut cube.center_wings,
+            corner_parity ^ wing_parity_1 ^ wing_parity_2,
+            rng,
+        );
+
+        cube
+    }
+}
+
+/// The centers are told apart by where they are in the solved cube, except the middle ones, which can only turn
+/// in place. Their turns are tracked like on the [`Cube3`](super::Cube3).
+impl SuperCube for Cube7 {
+    type Centers = [u8; 6];
+
+    const SOLVED_CENTERS: Self::Centers = [0; 6];
+
+    /// Wide moves never reach the middle layers, so each one turns the middle center of its face.
+    fn move_centers(centers: &mut Self::Centers, m: &WideAxisMove<2>) {
+        turn_middle_center(centers, &m.axis_move);
+    }
+
+    fn are_centers_solved(&self, centers: &Self::Centers) -> bool {
+        *centers == Self::SOLVED_CENTERS
+            && self.center_corners_1 == CenterCornerSet::SOLVED
+            && self.center_corners_2 == CenterCornerSet::SOLVED
+            && self.center_edges_1 == CenterEdgeSet::SOLVED
+            && self.center_edges_2 == CenterEdgeSet::SOLVED
+            && self.center_wings == CenterWingSet::SOLVED
+    }
+
+    /// The other centers of a random cube already have the right permutation parities.
+    fn random_centers<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::Centers {
+        random_middle_centers(&self.corners, rng)
+    }
+}
@@ -0,0 +1,207 @@
+//! Solving the 7x7x7 by reduction. See [`crate::cube_n::reduction`] for more info.
+
+mod test;
+
+use once_cell::sync::Lazy;
+
+use crate::{
+    cube_n::{
+        center::{corner::CenterCorner, edge::CenterEdge, wing::CenterWing},
+        cube3::supercube::center_turns,
+        reduction::{
+            apply_steps, matching_wing_targets, pair_wings, parse, NotReducedError, Stages,
+            ThreeCycles,
+        },
+        AxisMove, Cube3, Super, WideAxisMove, Wing,
+    },
+    generic::{alg_tree::AlgTree, piece::PieceSetDescriptor, search::Solver, Alg, Movable},
+};
+
+use super::Cube7;
+
+/// `[Rw 2U Rw', U]`
+static CENTER_CORNER_CYCLES_1: Lazy<ThreeCycles<CenterCorner, 2>> =
+    Lazy::new(|| ThreeCycles::new::<24, 1, 0>([parse("Rw 2U Rw' U Rw 2U' Rw' U'", 7)]));
+
+/// `[3R 3U 3R', Uw]`
+static CENTER_CORNER_CYCLES_2: Lazy<ThreeCycles<CenterCorner, 2>> =
+    Lazy::new(|| ThreeCycles::new::<24, 2, 0>([parse("3R 3U 3R' Uw 3R 3U' 3R' Uw'", 7)]));
+
+/// `[3Rw2 Fw2 3Rw2, U2]`
+static CENTER_EDGE_CYCLES_1: Lazy<ThreeCycles<CenterEdge, 2>> =
+    Lazy::new(|| ThreeCycles::new::<24, 1, 0>([parse("3Rw2 Fw2 3Rw2 U2 3Rw2 Fw2 3Rw2 U2", 7)]));
+
+/// `[3Rw2 3F2 3Rw2, Uw2]`
+static CENTER_EDGE_CYCLES_2: Lazy<ThreeCycles<CenterEdge, 2>> =
+    Lazy::new(|| ThreeCycles::new::<24, 2, 0>([parse("3Rw2 3F2 3Rw2 Uw2 3Rw2 3F2 3Rw2 Uw2", 7)]));
+
+/// `[R 3U R', Uw]` and `[R 3D R', Uw]`, one for each chirality
+static CENTER_WING_CYCLES: Lazy<ThreeCycles<CenterWing, 2>> = Lazy::new(|| {
+    ThreeCycles::new::<48, 1, 2>([
+        parse("R 3U R' Uw R 3U' R' Uw'", 7),
+        parse("R 3D R' Uw R 3D' R' Uw'", 7),
+    ])
+});
+
+/// `[R U R', 2U]`
+static WING_CYCLES_1: Lazy<ThreeCycles<Wing, 2>> =
+    Lazy::new(|| ThreeCycles::new::<24, 1, 0>([parse("R U R' 2U R U' R' 2U'", 7)]));
+
+/// `[R U R', 3U]`
+static WING_CYCLES_2: Lazy<ThreeCycles<Wing, 2>> =
+    Lazy::new(|| ThreeCycles::new::<24, 2, 0>([parse("R U R' 3U R U' R' 3U'", 7)]));
+
+/// `[2R 2U 2R', U]`, which doesn't move any other center, unlike the one used for reduction
+static SUPER_CENTER_CORNER_CYCLES_1: Lazy<ThreeCycles<CenterCorner, 2>> =
+    Lazy::new(|| ThreeCycles::new::<24, 1, 0>([parse("2R 2U 2R' U 2R 2U' 2R' U'", 7)]));
+
+/// `[3R 3U 3R', U]`, which doesn't move any other center, unlike the one used for reduction
+static SUPER_CENTER_CORNER_CYCLES_2: Lazy<ThreeCycles<CenterCorner, 2>> =
+    Lazy::new(|| ThreeCycles::new::<24, 2, 0>([parse("3R 3U 3R' U 3R 3U' 3R' U'", 7)]));
+
+/// `[4R 2U 4R', U]`, which doesn't move any other center, unlike the one used for reduction
+static SUPER_CENTER_EDGE_CYCLES_1: Lazy<ThreeCycles<CenterEdge, 2>> =
+    Lazy::new(|| ThreeCycles::new::<24, 1, 0>([parse("4R 2U 4R' U 4R 2U' 4R' U'", 7)]));
+
+/// `[3Rw2 3F2 3Rw2, U2]`, which doesn't move any other center, unlike the one used for reduction
+static SUPER_CENTER_EDGE_CYCLES_2: Lazy<ThreeCycles<CenterEdge, 2>> =
+    Lazy::new(|| ThreeCycles::new::<24, 2, 0>([parse("3Rw2 3F2 3Rw2 U2 3Rw2 3F2 3Rw2 U2", 7)]));
+
+/// `[2R 3U 2R', U]` and `[2R 3D 2R', U]`, one for each chirality. Unlike the ones used for reduction, they don't
+/// move any other center
+static SUPER_CENTER_WING_CYCLES: Lazy<ThreeCycles<CenterWing, 2>> = Lazy::new(|| {
+    ThreeCycles::new::<48, 1, 2>([
+        parse("2R 3U 2R' U 2R 3U' 2R' U'", 7),
+        parse("2R 3D 2R' U 2R 3D' 2R' U'", 7),
+    ])
+});
+
+impl Cube7 {
+    /// Solves the cube by reduction, using `solver` for the 3x3x3 stage.
+    ///
+    /// The solution has a comment after each stage: centers, edge pairing and the 3x3x3. Returns `None` if the
+    /// solver can't solve the virtual 3x3x3.
+    pub fn solve_by_reduction(
+        &self,
+        solver: &impl Solver<Cube3, AxisMove>,
+    ) -> Option<AlgTree<WideAxisMove<2>>> {
+        let mut stages = Stages::new();
+        let virtual_cube = self.clone().reduce(&mut stages);
+
+        let solution: Alg<AxisMove> = solver.solve(&virtual_cube)?;
+        stages.push("3x3x3", solution.into());
+
+        Some(stages.into())
+    }
+
+    /// Reduces the cube and returns the virtual 3x3x3.
+    fn reduce(&mut self, stages: &mut Stages<WideAxisMove<2>>) -> Cube3 {
+        // Each orbit gets solved after the previous one, since the 3-cycles move the centers of other orbits
+        let centers = apply_steps(
+            self,
+            &[
+                &|cube| CENTER_CORNER_CYCLES_1.solve_identical(&cube.center_corners_1, |_, _| true),
+                &|cube| CENTER_CORNER_CYCLES_2.solve_identical(&cube.center_corners_2, |_, _| true),
+                &|cube| CENTER_EDGE_CYCLES_1.solve_identical(&cube.center_edges_1, |_, _| true),
+                &|cube| CENTER_EDGE_CYCLES_2.solve_identical(&cube.center_edges_2, |_, _| true),
+                &|cube| {
+                    CENTER_WING_CYCLES
+                        .solve_identical(&cube.center_wings, |a, b| a.chirality() == b.chirality())
+                },
+            ],
+        );
+        stages.push("centers", centers);
+
+        // The middle edges don't move while pairing, so the targets stay the same for both depths
+        let targets = matching_wing_targets(&self.edges);
+
+        let mut edges = pair_wings(&WING_CYCLES_1, &self.wings_1, &targets, 7);
+        self.apply(&edges);
+
+        let alg = pair_wings(&WING_CYCLES_2, &self.wings_2, &targets, 7);
+        self.apply(&alg);
+        edges.moves.extend(alg.moves);
+        stages.push("edge pairing", edges);
+
+        self.virtual_cube3()
+    }
+
+    /// The 3x3x3 that the cube behaves like once it's reduced.
+    ///
+    /// # Panics
+    ///
+    /// If the cube is not reduced.
+    fn virtual_cube3(&self) -> Cube3 {
+        Cube3::try_from(self).expect("The cube should be reduced")
+    }
+}
+
+impl Super<Cube7> {
+    /// Solves the cube like [`Cube7::solve_by_reduction`], and then turns the middle centers in place and puts
+    /// every other center back where it was in the solved cube. These last stages have the comments "center
+    /// orientation" and "center identity".
+    pub fn solve_by_reduction(
+        &self,
+        solver: &impl Solver<Cube3, AxisMove>,
+    ) -> Option<AlgTree<WideAxisMove<2>>> {
+        let mut stages = Stages::new();
+        let virtual_cube = self.base_cube.clone().reduce(&mut stages);
+        let mut cube = self.clone().moved(&stages.alg());
+
+        let solution: Alg<WideAxisMove<2>> = solver.solve(&virtual_cube)?.into();
+        cube.apply(&solution);
+        stages.push("3x3x3", solution);
+
+        // Turning the middle centers turns the rest of the centers of their face too, which get solved afterwards
+        let turns: Alg<WideAxisMove<2>> = center_turns(&cube.centers).into();
+        cube.apply(&turns);
+        stages.push("center orientation", turns);
+
+        let cube = cube.base_cube;
+
+        // The 3-cycles are pure, so each orbit can be solved independently
+        let centers: Alg<_> = [
+            SUPER_CENTER_CORNER_CYCLES_1
+                .solve(&cube.center_corners_1, &CenterCorner::REFERENCE_POSITIONS),
+            SUPER_CENTER_CORNER_CYCLES_2
+                .solve(&cube.center_corners_2, &CenterCorner::REFERENCE_POSITIONS),
+            SUPER_CENTER_EDGE_CYCLES_1
+                .solve(&cube.center_edges_1, &CenterEdge::REFERENCE_POSITIONS),
+            SUPER_CENTER_EDGE_CYCLES_2
+                .solve(&cube.center_edges_2, &CenterEdge::REFERENCE_POSITIONS),
+            SUPER_CENTER_WING_CYCLES.solve(&cube.center_wings, &CenterWing::REFERENCE_POSITIONS),
+        ]
+        .into_iter()
+        .flat_map(|alg| alg.moves)
+        .collect();
+        stages.push("center identity", centers);
+
+        Some(stages.into())
+    }
+}
+
+/// A reduced 7x7x7 behaves like a 3x3x3 with outer layer moves.
+impl TryFrom<&Cube7> for Cube3 {
+    type Error = NotReducedError;
+
+    fn try_from(cube: &Cube7) -> Result<Self, Self::Error> {
+        if !cube.center_corners_1.is_solved()
+            || !cube.center_corners_2.is_solved()
+            || !cube.center_edges_1.is_solved()
+            || !cube.center_edges_2.is_solved()
+            || !cube.center_wings.is_solved()
+        {
+            return Err(NotReducedError::UnsolvedCenters);
+        }
+
+        let targets = matching_wing_targets(&cube.edges);
+        if cube.wings_1.pieces() != &targets || cube.wings_2.pieces() != &targets {
+            return Err(NotReducedError::UnpairedEdges);
+        }
+
+        Ok(Cube3 {
+            corners: cube.corners.clone(),
+            edges: cube.edges.clone(),
+        })
+    }
+}
@@ -0,0 +1,204 @@
+#![cfg(test)]
+
+use pretty_assertions::assert_eq;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    cube3::heuristics,
+    cube_n::{
+        invariants::{fix_edge_flip_parity, fix_swap_parity},
+        moves::perms::parity,
+        IntoSuper,
+    },
+    generic::{piece::PieceSetDescriptor, search::ida::IDASearcher, Cube, Parsable, Piece},
+};
+
+use super::*;
+
+/// The number of pieces of each field that are not in their original position
+fn moved_pieces(cube: &Cube7) -> [usize; 9] {
+    macro_rules! moved {
+        ($($set:expr),*) => {
+            [$($set
+                .iter_with_pos()
+                .filter(|(original, piece)| original != &piece.position())
+                .count()),*]
+        };
+    }
+
+    moved!(
+        cube.corners,
+        cube.edges,
+        cube.wings_1,
+        cube.wings_2,
+        cube.center_corners_1,
+        cube.center_corners_2,
+        cube.center_edges_1,
+        cube.center_edges_2,
+        cube.center_wings
+    )
+}
+
+#[test]
+fn three_cycles_are_pure() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    macro_rules! assert_pure {
+        ($cycles:expr, $piece:ty, $index:literal, $ignored:expr) => {
+            for _ in 0..5 {
+                let mut positions = <$piece>::REFERENCE_POSITIONS.to_vec();
+                let cycle: [$piece; 3] =
+                    std::array::from_fn(|_| positions.remove(rng.gen_range(0..positions.len())));
+
+                let Some(alg) = $cycles.cycle(cycle) else {
+                    continue;
+                };
+
+                let moved = moved_pieces(&Cube7::SOLVED.moved(alg));
+                for (i, count) in moved.into_iter().enumerate() {
+                    match i {
+                        $index => assert_eq!(count, 3),
+                        i if $ignored.contains(&i) => (),
+                        _ => assert_eq!(count, 0, "{alg}"),
+                    }
+                }
+            }
+        };
+    }
+
+    // The centers are solved first, so their cycles can move the edges and wings
+    assert_pure!(CENTER_CORNER_CYCLES_1, CenterCorner, 4, [0, 1, 2, 3]);
+    assert_pure!(CENTER_CORNER_CYCLES_2, CenterCorner, 5, [0, 1, 2, 3]);
+    assert_pure!(CENTER_EDGE_CYCLES_1, CenterEdge, 6, [0, 1, 2, 3]);
+    assert_pure!(CENTER_EDGE_CYCLES_2, CenterEdge, 7, [0, 1, 2, 3]);
+    assert_pure!(CENTER_WING_CYCLES, CenterWing, 8, [0, 1, 2, 3]);
+    assert_pure!(WING_CYCLES_1, Wing, 2, []);
+    assert_pure!(WING_CYCLES_2, Wing, 3, []);
+
+    assert_pure!(SUPER_CENTER_CORNER_CYCLES_1, CenterCorner, 4, []);
+    assert_pure!(SUPER_CENTER_CORNER_CYCLES_2, CenterCorner, 5, []);
+    assert_pure!(SUPER_CENTER_EDGE_CYCLES_1, CenterEdge, 6, []);
+    assert_pure!(SUPER_CENTER_EDGE_CYCLES_2, CenterEdge, 7, []);
+    assert_pure!(SUPER_CENTER_WING_CYCLES, CenterWing, 8, []);
+}
+
+#[test]
+fn reduces_random_cubes() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..10 {
+        let scrambled: Cube7 = rng.gen();
+
+        let mut cube = scrambled.clone();
+        let mut stages = Stages::new();
+        let virtual_cube = cube.reduce(&mut stages);
+
+        assert!(cube.center_corners_1.is_solved());
+        assert!(cube.center_corners_2.is_solved());
+        assert!(cube.center_edges_1.is_solved());
+        assert!(cube.center_edges_2.is_solved());
+        assert!(cube.center_wings.is_solved());
+        assert_eq!(cube.wings_1.pieces(), &matching_wing_targets(&cube.edges));
+        assert_eq!(cube.wings_2.pieces(), &matching_wing_targets(&cube.edges));
+        assert_eq!(scrambled.moved(&AlgTree::from(stages).flatten()), cube);
+
+        let mut solvable = virtual_cube.clone();
+        fix_swap_parity(&mut solvable);
+        fix_edge_flip_parity(&mut solvable.edges);
+        assert_eq!(solvable, virtual_cube);
+    }
+}
+
+#[test]
+#[ignore = "MUS takes too long to build"]
+fn solves_random_cubes() {
+    let solver = IDASearcher::new(heuristics::mus, Cube3::successors, 20);
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..5 {
+        let cube: Cube7 = rng.gen();
+        let solution = cube.solve_by_reduction(&solver).unwrap();
+
+        assert!(cube.moved(&solution.flatten()).is_solved());
+    }
+}
+
+#[test]
+fn solves_with_edge_parity() {
+    let solver = IDASearcher::new(heuristics::zero, Cube3::successors, 5);
+
+    for (setup, stages) in [
+        (Alg::empty(), vec!["3x3x3"]),
+        (parity::flip::<2>(7, 1), vec!["edge pairing", "3x3x3"]),
+        (parity::flip::<2>(7, 2), vec!["edge pairing", "3x3x3"]),
+    ] {
+        let scramble = Alg::<AxisMove>::parse("R U F'").unwrap();
+        let cube = Cube7::SOLVED.moved(&scramble).moved(&setup);
+
+        let solution = cube.solve_by_reduction(&solver).unwrap();
+
+        let comments: Vec<_> = solution
+            .comments()
+            .into_iter()
+            .map(|(_, comment)| comment)
+            .collect();
+        assert_eq!(comments, stages);
+        assert!(cube.moved(&solution.flatten()).is_solved());
+    }
+}
+
+#[test]
+fn solves_supercubes() {
+    let solver = IDASearcher::new(heuristics::zero, Cube3::successors, 5);
+
+    // Cycling centers of the same face keeps the cube solved, but not the supercube
+    let reference = &CenterEdge::REFERENCE_POSITIONS[0];
+    let same_face: Vec<_> = CenterEdge::REFERENCE_POSITIONS
+        .into_iter()
+        .filter(|center| center.is_solved(reference))
+        .take(3)
+        .collect();
+    let cycle = SUPER_CENTER_EDGE_CYCLES_1
+        .cycle(same_face.try_into().unwrap())
+        .unwrap();
+
+    for (setup, stages) in [
+        (Alg::empty(), vec!["3x3x3"]),
+        (cycle.clone(), vec!["3x3x3", "center identity"]),
+    ] {
+        let scramble = Alg::<WideAxisMove<2>>::parse("R U F'").unwrap();
+        let cube = Cube7::SOLVED.moved(&setup).into_super().moved(&scramble);
+
+        let solution = cube.solve_by_reduction(&solver).unwrap();
+
+        let comments: Vec<_> = solution
+            .comments()
+            .into_iter()
+            .map(|(_, comment)| comment)
+            .collect();
+        assert_eq!(comments, stages);
+
+        let solved = cube.moved(&solution.flatten());
+        assert!(solved.is_solved());
+        assert_eq!(solved.base_cube, Cube7::SOLVED);
+    }
+}
+
+#[test]
+fn solves_turned_middle_centers() {
+    let solver = IDASearcher::new(heuristics::zero, Cube3::successors, 5);
+    let cube = Super {
+        base_cube: Cube7::SOLVED,
+        centers: [2, 1, 1, 0, 0, 0],
+    };
+
+    let solution = cube.solve_by_reduction(&solver).unwrap();
+
+    let comments: Vec<_> = solution
+        .comments()
+        .into_iter()
+        .map(|(_, comment)| comment)
+        .collect();
+    assert_eq!(comments, ["center orientation", "center identity"]);
+    assert!(cube.moved(&solution.flatten()).is_solved());
+}
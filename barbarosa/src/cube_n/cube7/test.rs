@@ -1,15 +1,19 @@
 #![cfg(test)]
 
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
 use crate::{
     cube_n::{
+        invariants::{fix_corner_multiplicity, fix_edge_flip_parity, is_odd_permutation},
         moves::{
             perms::{self, pll},
             Amount,
         },
-        space::Face,
+        pieces::center::wing::CenterWing,
+        space::{Direction, Face},
         AxisMove,
     },
-    generic::{Cube, Movable},
+    generic::{Alg, Cube, Movable, StateAlgebra},
 };
 
 use super::*;
@@ -52,3 +56,110 @@ fn varying_type_of_wide_move() {
     Cube7::SOLVED.clone().apply(&w1);
     Cube7::SOLVED.clone().apply(&w2);
 }
+
+#[test]
+fn orders() {
+    let alg = |depth| perms::SEXY_MOVE.clone().widen::<2>(depth).unwrap();
+
+    for depth in 0..=2 {
+        assert_eq!(alg(depth).order::<Cube7>(), 6);
+        assert_eq!(
+            pll::T.clone().widen::<2>(depth).unwrap().order::<Cube7>(),
+            2
+        );
+    }
+}
+
+#[test]
+fn order_brings_back_to_solved() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..5 {
+        let alg = Alg::<WideAxisMove<2>>::random_unnormalized_with_rng(3, &mut rng);
+        let state = Cube7::SOLVED.moved(&alg);
+        let order = state.period();
+
+        assert!(state.compose(&state.inverse()).is_solved());
+
+        let mut cube = Cube7::SOLVED;
+        for i in 1..=order {
+            cube.apply(&alg);
+            assert_eq!(cube.is_solved(), i == order, "{alg} has order {order}");
+        }
+    }
+}
+
+fn assert_invariants(cube: &Cube7) {
+    let mut corners = cube.corners.clone();
+    fix_corner_multiplicity(&mut corners);
+    assert_eq!(corners, cube.corners, "Corners are twisted");
+
+    let mut edges = cube.edges.clone();
+    fix_edge_flip_parity(&mut edges);
+    assert_eq!(edges, cube.edges, "Edges are flipped");
+
+    let c = is_odd_permutation(&cube.corners, |_| true);
+    let w_1 = is_odd_permutation(&cube.wings_1.set, |_| true);
+    let w_2 = is_odd_permutation(&cube.wings_2.set, |_| true);
+
+    assert_eq!(is_odd_permutation(&cube.edges, |_| true), c);
+    assert_eq!(is_odd_permutation(&cube.center_corners_1.set, |_| true), c);
+    assert_eq!(is_odd_permutation(&cube.center_corners_2.set, |_| true), c);
+    assert_eq!(
+        is_odd_permutation(&cube.center_edges_1.set, |_| true),
+        c ^ w_1
+    );
+    assert_eq!(
+        is_odd_permutation(&cube.center_edges_2.set, |_| true),
+        c ^ w_2
+    );
+
+    for chirality in [Direction::Positive, Direction::Negative] {
+        let orbit = |wing: &CenterWing| wing.chirality() == chirality;
+        assert_eq!(
+            is_odd_permutation(&cube.center_wings.set, orbit),
+            c ^ w_1 ^ w_2
+        );
+    }
+}
+
+#[test]
+fn quarter_turns_are_odd() {
+    let cube = Cube7::SOLVED.moved(&AxisMove::new(Face::R, Amount::Single));
+
+    assert!(is_odd_permutation(&cube.corners, |_| true));
+    assert!(is_odd_permutation(&cube.edges, |_| true));
+    assert!(is_odd_permutation(&cube.center_corners_1.set, |_| true));
+    assert!(!is_odd_permutation(&cube.wings_1.set, |_| true));
+
+    let cube = Cube7::SOLVED.moved(&AxisMove::new(Face::R, Amount::Double));
+    assert!(!is_odd_permutation(&cube.corners, |_| true));
+}
+
+#[test]
+fn scrambles_uphold_invariants() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..50 {
+        let alg = Alg::<WideAxisMove<2>>::random_unnormalized_with_rng(25, &mut rng);
+        let mut cube = Cube7::SOLVED;
+        cube.apply(&alg);
+
+        assert_invariants(&cube);
+    }
+}
+
+#[test]
+fn random_cubes_uphold_invariants() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..50 {
+        let cube: Cube7 = rng.gen();
+        assert_invariants(&cube);
+    }
+
+    let a: Cube7 = StdRng::seed_from_u64(1).gen();
+    let b: Cube7 = StdRng::seed_from_u64(1).gen();
+    assert_eq!(a, b);
+    assert!(!a.is_solved());
+}
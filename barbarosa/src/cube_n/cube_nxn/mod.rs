@@ -0,0 +1,362 @@
+//! Cubes bigger than the 7x7x7.
+//!
+//! Every big cube is made of the same kinds of pieces, with one orbit of each kind per depth (or pair of depths
+//! for center-wings). So instead of writing each cube by hand, they get generated by a macro which just
+//! needs the list of orbits.
+
+mod test;
+
+/// Defines a big cube given its size, the depth of its widest moves and the orbits of its pieces.
+///
+/// Odd cubes also have to specify a field for their middle `edges`. The field names are given explicitly
+/// since `macro_rules` can't create identifiers. The `narrower_widths` (the widths of the wide moves from 0 up to,
+/// but not including, `width`) and the `slice_depths` (from 1 to `size - 2`) have to be given as literals too.
+macro_rules! cube_nxn {
+    (
+        $(#[$meta:meta])*
+        $cube:ident {
+            size: $n:literal,
+            width: $width:literal,
+            $(edges: $edges:ident,)?
+            wings: [$($wings:ident: $wing_depth:literal),* $(,)?],
+            center_corners: [$($center_corners:ident: $center_corner_depth:literal),* $(,)?],
+            center_edges: [$($center_edges:ident: $center_edge_depth:literal),* $(,)?],
+            center_wings: [$($center_wings:ident: ($normal_depth:literal, $tangent_depth:literal)),* $(,)?],
+            narrower_widths: [$($narrower_width:tt),* $(,)?],
+            slice_depths: [$($slice_depth:literal),* $(,)?] $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, PartialEq, Eq, Clone)]
+        pub struct $cube {
+            corners: CornerSet,
+            $($edges: EdgeSet,)?
+
+            $($wings: WingSet<$wing_depth>,)*
+
+            $($center_corners: CenterCornerSet<$center_corner_depth>,)*
+            $($center_edges: CenterEdgeSet<$center_edge_depth>,)*
+            $($center_wings: CenterWingSet<$normal_depth, $tangent_depth>,)*
+        }
+
+        impl generic::Cube for $cube {
+            const SOLVED: Self = Self {
+                corners: CornerSet::SOLVED,
+                $($edges: EdgeSet::SOLVED,)?
+
+                $($wings: WingSet::SOLVED,)*
+
+                $($center_corners: CenterCornerSet::SOLVED,)*
+                $($center_edges: CenterEdgeSet::SOLVED,)*
+                $($center_wings: CenterWingSet::SOLVED,)*
+            };
+
+            fn is_solved(&self) -> bool
+            where
+                Self: 'static,
+            {
+                self.corners.is_solved()
+                    $(&& self.$edges.is_solved())?
+                    $(&& self.$wings.is_solved())*
+                    $(&& self.$center_corners.is_solved())*
+                    $(&& self.$center_edges.is_solved())*
+                    $(&& self.$center_wings.is_solved())*
+            }
+        }
+
+        impl AsMove for $cube {
+            type Move = WideAxisMove<$width>;
+        }
+
+        impl generic::Movable<WideAxisMove<$width>> for $cube {
+            fn apply(&mut self, m: &WideAxisMove<$width>) {
+                self.corners.apply(&m.axis_move);
+                $(self.$edges.apply(&m.axis_move);)?
+                $(self.$wings.apply(m);)*
+                $(self.$center_corners.apply(m);)*
+                $(self.$center_edges.apply(m);)*
+                $(self.$center_wings.apply(m);)*
+            }
+        }
+
+        impl CubeN for $cube {
+            const N: u32 = $n;
+            type WideMove = WideAxisMove<$width>;
+
+            fn piece_orders(&self) -> PieceOrders {
+                #[allow(unused_mut, unused_assignments)]
+                let mut edges = None;
+                $(edges = Some(self.$edges.order());)?
+
+                PieceOrders {
+                    corners: self.corners.order(),
+                    edges,
+                    wings: Some([$(self.$wings.order()),*].into_iter().fold(1, lcm)),
+                    centers: Some(
+                        [
+                            $(self.$center_corners.period(),)*
+                            $(self.$center_edges.period(),)*
+                            $(self.$center_wings.period(),)*
+                        ]
+                        .into_iter()
+                        .fold(1, lcm),
+                    ),
+                }
+            }
+        }
+
+        impl Distribution<$cube> for Standard {
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $cube {
+                let mut cube = $cube::SOLVED;
+
+                let corner_parity = randomize_corners(&mut cube.corners, rng);
+                $(randomize_edges(&mut cube.$edges, corner_parity, rng);)?
+
+                // Indexed by depth
+                let mut wing_parity = [false; $width + 1];
+                $(wing_parity[$wing_depth] = randomize_wings(&mut cube.$wings, rng);)*
+
+                $(randomize_center_corners(&mut cube.$center_corners, corner_parity, rng);)*
+                $(randomize_center_edges(
+                    &mut cube.$center_edges,
+                    corner_parity ^ wing_parity[$center_edge_depth],
+                    rng,
+                );)*
+                $(randomize_center_wings(
+                    &mut cube.$center_wings,
+                    corner_parity ^ wing_parity[$normal_depth] ^ wing_parity[$tangent_depth],
+                    rng,
+                );)*
+
+                cube
+            }
+        }
+
+        /// The centers are told apart by where they are in the solved cube, so there's nothing else to track.
+        impl SuperCube for $cube {
+            type Centers = ();
+
+            const SOLVED_CENTERS: Self::Centers = ();
+
+            fn move_centers(_centers: &mut Self::Centers, _m: &WideAxisMove<$width>) {}
+
+            fn are_centers_solved(&self, _centers: &Self::Centers) -> bool {
+                true
+                    $(&& self.$center_corners == CenterCornerSet::SOLVED)*
+                    $(&& self.$center_edges == CenterEdgeSet::SOLVED)*
+                    $(&& self.$center_wings == CenterWingSet::SOLVED)*
+            }
+
+            /// The centers of a random cube already have the right permutation parities.
+            fn random_centers<R: Rng + ?Sized>(&self, _rng: &mut R) -> Self::Centers {}
+        }
+
+        impl_movable_wide_move_inductively!($cube, $width, [$($narrower_width),*]);
+        impl_state_algebra!(
+            $cube,
+            [
+                corners,
+                $($edges,)?
+                $($wings,)*
+                $($center_corners,)*
+                $($center_edges,)*
+                $($center_wings,)*
+            ]
+        );
+        impl_movable_slice_move!($cube, $width, [$($slice_depth),*]);
+    };
+}
+
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+
+use crate::generic::{
+    self, algebra::impl_state_algebra, moves::AsMove, utils::lcm, Cube, StateAlgebra,
+};
+
+use super::{
+    center::{corner::CenterCornerSet, edge::CenterEdgeSet, wing::CenterWingSet},
+    invariants::{
+        randomize_center_corners, randomize_center_edges, randomize_center_wings,
+        randomize_corners, randomize_edges, randomize_wings,
+    },
+    moves::{slice::impl_movable_slice_move, wide::impl_movable_wide_move_inductively},
+    order::PieceOrders,
+    pieces::{corner::CornerSet, edge::EdgeSet, wing::WingSet},
+    CubeN, SuperCube, WideAxisMove,
+};
+
+cube_nxn! {
+    /// The 8x8x8 cube.
+    ///
+    /// See [`crate::cube_n`] for more info.
+    Cube8 {
+        size: 8,
+        width: 3,
+        wings: [wings_1: 1, wings_2: 2, wings_3: 3],
+        center_corners: [center_corners_1: 1, center_corners_2: 2, center_corners_3: 3],
+        center_edges: [],
+        center_wings: [
+            center_wings_1_2: (1, 2),
+            center_wings_1_3: (1, 3),
+            center_wings_2_3: (2, 3),
+        ],
+        narrower_widths: [0, 1, 2],
+        slice_depths: [1, 2, 3, 4, 5, 6],
+    }
+}
+
+cube_nxn! {
+    /// The 9x9x9 cube.
+    ///
+    /// See [`crate::cube_n`] for more info.
+    Cube9 {
+        size: 9,
+        width: 3,
+        edges: edges,
+        wings: [wings_1: 1, wings_2: 2, wings_3: 3],
+        center_corners: [center_corners_1: 1, center_corners_2: 2, center_corners_3: 3],
+        center_edges: [center_edges_1: 1, center_edges_2: 2, center_edges_3: 3],
+        center_wings: [
+            center_wings_1_2: (1, 2),
+            center_wings_1_3: (1, 3),
+            center_wings_2_3: (2, 3),
+        ],
+        narrower_widths: [0, 1, 2],
+        slice_depths: [1, 2, 3, 4, 5, 6, 7],
+    }
+}
+
+cube_nxn! {
+    /// The 10x10x10 cube.
+    ///
+    /// See [`crate::cube_n`] for more info.
+    Cube10 {
+        size: 10,
+        width: 4,
+        wings: [wings_1: 1, wings_2: 2, wings_3: 3, wings_4: 4],
+        center_corners: [
+            center_corners_1: 1,
+            center_corners_2: 2,
+            center_corners_3: 3,
+            center_corners_4: 4,
+        ],
+        center_edges: [],
+        center_wings: [
+            center_wings_1_2: (1, 2),
+            center_wings_1_3: (1, 3),
+            center_wings_1_4: (1, 4),
+            center_wings_2_3: (2, 3),
+            center_wings_2_4: (2, 4),
+            center_wings_3_4: (3, 4),
+        ],
+        narrower_widths: [0, 1, 2, 3],
+        slice_depths: [1, 2, 3, 4, 5, 6, 7, 8],
+    }
+}
+
+cube_nxn! {
+    /// The 11x11x11 cube.
+    ///
+    /// See [`crate::cube_n`] for more info.
+    Cube11 {
+        size: 11,
+        width: 4,
+        edges: edges,
+        wings: [wings_1: 1, wings_2: 2, wings_3: 3, wings_4: 4],
+        center_corners: [
+            center_corners_1: 1,
+            center_corners_2: 2,
+            center_corners_3: 3,
+            center_corners_4: 4,
+        ],
+        center_edges: [
+            center_edges_1: 1,
+            center_edges_2: 2,
+            center_edges_3: 3,
+            center_edges_4: 4,
+        ],
+        center_wings: [
+            center_wings_1_2: (1, 2),
+            center_wings_1_3: (1, 3),
+            center_wings_1_4: (1, 4),
+            center_wings_2_3: (2, 3),
+            center_wings_2_4: (2, 4),
+            center_wings_3_4: (3, 4),
+        ],
+        narrower_widths: [0, 1, 2, 3],
+        slice_depths: [1, 2, 3, 4, 5, 6, 7, 8, 9],
+    }
+}
+
+cube_nxn! {
+    /// The 12x12x12 cube.
+    ///
+    /// See [`crate::cube_n`] for more info.
+    Cube12 {
+        size: 12,
+        width: 5,
+        wings: [wings_1: 1, wings_2: 2, wings_3: 3, wings_4: 4, wings_5: 5],
+        center_corners: [
+            center_corners_1: 1,
+            center_corners_2: 2,
+            center_corners_3: 3,
+            center_corners_4: 4,
+            center_corners_5: 5,
+        ],
+        center_edges: [],
+        center_wings: [
+            center_wings_1_2: (1, 2),
+            center_wings_1_3: (1, 3),
+            center_wings_1_4: (1, 4),
+            center_wings_1_5: (1, 5),
+            center_wings_2_3: (2, 3),
+            center_wings_2_4: (2, 4),
+            center_wings_2_5: (2, 5),
+            center_wings_3_4: (3, 4),
+            center_wings_3_5: (3, 5),
+            center_wings_4_5: (4, 5),
+        ],
+        narrower_widths: [0, 1, 2, 3, 4],
+        slice_depths: [1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+    }
+}
+
+cube_nxn! {
+    /// The 13x13x13 cube.
+    ///
+    /// See [`crate::cube_n`] for more info.
+    Cube13 {
+        size: 13,
+        width: 5,
+        edges: edges,
+        wings: [wings_1: 1, wings_2: 2, wings_3: 3, wings_4: 4, wings_5: 5],
+        center_corners: [
+            center_corners_1: 1,
+            center_corners_2: 2,
+            center_corners_3: 3,
+            center_corners_4: 4,
+            center_corners_5: 5,
+        ],
+        center_edges: [
+            center_edges_1: 1,
+            center_edges_2: 2,
+            center_edges_3: 3,
+            center_edges_4: 4,
+            center_edges_5: 5,
+        ],
+        center_wings: [
+            center_wings_1_2: (1, 2),
+            center_wings_1_3: (1, 3),
+            center_wings_1_4: (1, 4),
+            center_wings_1_5: (1, 5),
+            center_wings_2_3: (2, 3),
+            center_wings_2_4: (2, 4),
+            center_wings_2_5: (2, 5),
+            center_wings_3_4: (3, 4),
+            center_wings_3_5: (3, 5),
+            center_wings_4_5: (4, 5),
+        ],
+        narrower_widths: [0, 1, 2, 3, 4],
+        slice_depths: [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
+    }
+}
@@ -0,0 +1,144 @@
+#![cfg(test)]
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    cube_n::{
+        invariants::is_odd_permutation,
+        moves::{
+            perms::{self, pll},
+            slice::SliceMove,
+            ExtendedAxisMove,
+        },
+        space::Direction,
+        AxisMove, IntoOrientable,
+    },
+    generic::{Alg, Cube, Movable, Parsable, StateAlgebra},
+};
+
+use super::*;
+
+fn assert_moves_like_a_cube<C, const W: u32>()
+where
+    C: CubeN + Movable<WideAxisMove<W>> + StateAlgebra + std::fmt::Debug + 'static,
+{
+    for depth in 0..=W {
+        let sexy = perms::SEXY_MOVE.clone().widen::<W>(depth).unwrap();
+        assert_eq!(sexy.order::<C>(), 6, "{}x{}: {sexy}", C::N, C::N);
+
+        let t_perm = pll::T.clone().widen::<W>(depth).unwrap();
+        let cube = C::SOLVED.moved(&t_perm);
+        assert!(!cube.is_solved());
+        assert!(
+            cube.moved(&t_perm).is_solved(),
+            "{}x{}: {t_perm}",
+            C::N,
+            C::N
+        );
+    }
+
+    for mov in &AxisMove::all() {
+        for depth in 0..=W {
+            let mov = mov.clone().widen::<W>(depth).unwrap();
+            let mut cube = C::SOLVED;
+
+            cube.apply(&mov);
+            assert!(!cube.is_solved(), "{}x{}: {mov}", C::N, C::N);
+
+            for _ in 1..4 {
+                cube.apply(&mov);
+            }
+
+            assert!(cube.is_solved(), "{}x{}: {mov}", C::N, C::N);
+        }
+    }
+
+    let mut rng = StdRng::seed_from_u64(69420);
+    let alg = Alg::<WideAxisMove<W>>::random_unnormalized_with_rng(20, &mut rng);
+    let state = C::SOLVED.moved(&alg);
+
+    assert!(state.compose(&state.inverse()).is_solved());
+    assert!(state.moved(&alg.reversed()).is_solved());
+}
+
+#[test]
+fn big_cubes_move_like_cubes() {
+    assert_moves_like_a_cube::<Cube8, 3>();
+    assert_moves_like_a_cube::<Cube9, 3>();
+    assert_moves_like_a_cube::<Cube10, 4>();
+    assert_moves_like_a_cube::<Cube11, 4>();
+    assert_moves_like_a_cube::<Cube12, 5>();
+    assert_moves_like_a_cube::<Cube13, 5>();
+}
+
+#[test]
+fn every_depth_moves_different_pieces() {
+    // Each layer moves a different set of pieces, so none of these wide moves cancel out
+    let alg = Alg::<WideAxisMove<5>>::parse("R r 3Rw 4Rw 5Rw 6Rw").unwrap();
+
+    let mut cube = Cube13::SOLVED;
+    for mov in &alg.moves {
+        cube.apply(mov);
+        assert!(!cube.is_solved(), "{mov}");
+    }
+
+    let alg = Alg::<WideAxisMove<5>>::parse("6Rw' 5Rw' 4Rw' 3Rw' r' R'").unwrap();
+    assert!(cube.moved(&alg).is_solved());
+}
+
+#[test]
+fn parses_and_does_big_moves() {
+    let alg = Alg::<SliceMove<11>>::parse("12R 7U' 2F2").unwrap();
+    let cube = Cube13::SOLVED.moved(&alg);
+    assert!(!cube.is_solved());
+    assert!(cube.moved(&alg.reversed()).is_solved());
+
+    // The middle slice of a 13x13 is the 7th layer from either side
+    let slice = Alg::<ExtendedAxisMove>::parse("7R").unwrap();
+    let wide = Alg::<ExtendedAxisMove>::parse("7Rw 6Rw'").unwrap();
+    assert_eq!(
+        Cube13::SOLVED.orientable().moved(&slice),
+        Cube13::SOLVED.orientable().moved(&wide)
+    );
+
+    let alg = Alg::<ExtendedAxisMove>::parse("x 8Rw 2-5Uw' M2 e S'").unwrap();
+    let cube = Cube12::SOLVED.orientable().moved(&alg);
+    assert!(cube.moved(&alg.reversed()) == Cube12::SOLVED.orientable());
+}
+
+#[test]
+fn piece_orders() {
+    let orders = Cube9::SOLVED
+        .moved(&WideAxisMove::<3>::parse("3Rw").unwrap())
+        .piece_orders();
+
+    assert_eq!(orders.edges, Some(4));
+    assert_eq!(orders.wings, Some(4));
+
+    assert_eq!(Cube8::SOLVED.piece_orders().edges, None);
+}
+
+#[test]
+fn random_cubes_uphold_parities() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..20 {
+        let cube: Cube9 = rng.gen();
+
+        let c = is_odd_permutation(&cube.corners, |_| true);
+        let w_2 = is_odd_permutation(&cube.wings_2.set, |_| true);
+        let w_3 = is_odd_permutation(&cube.wings_3.set, |_| true);
+
+        assert_eq!(is_odd_permutation(&cube.edges, |_| true), c);
+        assert_eq!(is_odd_permutation(&cube.center_corners_3.set, |_| true), c);
+        assert_eq!(
+            is_odd_permutation(&cube.center_edges_2.set, |_| true),
+            c ^ w_2
+        );
+        assert_eq!(
+            is_odd_permutation(&cube.center_wings_2_3.set, |wing| wing.chirality()
+                == Direction::Positive),
+            c ^ w_2 ^ w_3
+        );
+    }
+}
@@ -1,7 +1,5 @@
 //! Collection of functions to assert if cube invariants are upheld and to fix them.
 //!
-//! TODO: This docs are written for a 3x3x3 cube, but they should be valid for any `NxNxN` cube.
-//!
 //! Mainly used for [`Cube::random`()](rand::random::<Cube3>())
 //!
 //! There are thre invariants we need to uphold in a 3x3x3 Rubik's cube. We can deduce them by analizing what
@@ -27,15 +25,47 @@
 //!
 //! PS: The reason to change the orientation of the last piece is because it makes implementing
 //! [`mus::index::OrientationIndexable`](super::cube3::mus::index::OrientationIndexable) nicer for corners.
+//!
+//! # Big cubes
+//!
+//! The corners and the middle edges of big cubes follow the same rules as in the 3x3x3. The rest of the pieces
+//! can't be twisted or flipped, so the only constraints are on the parity of each orbit. They can be found by
+//! counting the 4-cycles that a quarter turn of a single layer does on each orbit. Calling `c` the parity of the
+//! corners (which only depends on the outer layers) and `w_k` the parity of the wings at depth `k` (which only depends
+//! on the layers at depth `k`), there are these constraints:
+//!
+//! - Center-corners at depth `k` have parity `c` (an outer layer turn cycles 4 of them, an inner layer turn 8).
+//! - Center-edges at depth `k` have parity `c + w_k` (both outer layers and layers at depth `k` cycle 4 of them).
+//! - Center-wings at depths `a` and `b` come in two orbits of 24 pieces with opposite [chirality](CenterWing::chirality).
+//!   Outer layers and layers at depths `a` or `b` cycle 4 pieces of each orbit, so both have parity `c + w_a + w_b`.
+//!
+//! The corners and the wings can be anything, so the parities of the rest of pieces get fixed according to them
+//! (see [`randomize_wings`] and friends).
 
 use std::fmt::Debug;
 
+use rand::Rng;
+
 use crate::{
     cube_n::{cube3::mus::index::PositionIndexable, Cube3, Edge},
     generic::piece::PieceSetDescriptor,
 };
 
-use super::pieces::{corner::CornerSet, edge::EdgeSet};
+pub use crate::generic::piece::{fix_orbit_parity, is_odd_permutation, shuffle_orbit};
+
+use super::{
+    pieces::{
+        center::{
+            corner::CenterCornerSet,
+            edge::CenterEdgeSet,
+            wing::{CenterWing, CenterWingSet},
+        },
+        corner::CornerSet,
+        edge::EdgeSet,
+        wing::WingSet,
+    },
+    space::Direction,
+};
 
 /// Swaps `cube.edges[0]` and `cube.edges[1]` if the parity of the edge permutation is different from the parity of the corner permutation.
 ///
@@ -59,8 +89,8 @@ fn swap_cycles<T: PositionIndexable + PartialEq + Debug, const N: usize>(values:
     loop {
         if visited[current_index] {
             let Some(first_unvisited) = visited.iter().position(|x| !x) else {
-				return cycles;
-			};
+                return cycles;
+            };
 
             cycles += 1;
             current_index = first_unvisited;
@@ -116,3 +146,71 @@ pub fn fix_corner_multiplicity(corners: &mut CornerSet) {
         oriented_corners % 3 == 0
     });
 }
+
+/// Randomizes the corners of a cube, fixing their orientation. Returns whether their permutation is odd.
+pub fn randomize_corners(corners: &mut CornerSet, rng: &mut (impl Rng + ?Sized)) -> bool {
+    corners.shuffle(rng);
+    corners
+        .iter_mut_unchecked()
+        .for_each(|corner| corner.orientation_axis = rng.gen());
+
+    fix_corner_multiplicity(corners);
+
+    is_odd_permutation(corners, |_| true)
+}
+
+/// Randomizes the (middle) edges of a cube, such that the parity of their permutation is `odd` and
+/// the number of flipped edges is even.
+pub fn randomize_edges(edges: &mut EdgeSet, odd: bool, rng: &mut (impl Rng + ?Sized)) {
+    edges.shuffle(rng);
+    edges
+        .iter_mut_unchecked()
+        .for_each(|edge| edge.oriented = rng.gen());
+
+    fix_orbit_parity(edges, |_| true, odd);
+    fix_edge_flip_parity(edges);
+}
+
+/// Randomizes the wings at depth `K`. Returns whether their permutation is odd.
+pub fn randomize_wings<const K: u32>(
+    wings: &mut WingSet<K>,
+    rng: &mut (impl Rng + ?Sized),
+) -> bool {
+    wings.set.shuffle(rng);
+    is_odd_permutation(&wings.set, |_| true)
+}
+
+/// Randomizes the center-corners at depth `K`, such that the parity of their permutation is `odd`.
+pub fn randomize_center_corners<const K: u32>(
+    center_corners: &mut CenterCornerSet<K>,
+    odd: bool,
+    rng: &mut (impl Rng + ?Sized),
+) {
+    center_corners.set.shuffle(rng);
+    fix_orbit_parity(&mut center_corners.set, |_| true, odd);
+}
+
+/// Randomizes the center-edges at depth `K`, such that the parity of their permutation is `odd`.
+pub fn randomize_center_edges<const K: u32>(
+    center_edges: &mut CenterEdgeSet<K>,
+    odd: bool,
+    rng: &mut (impl Rng + ?Sized),
+) {
+    center_edges.set.shuffle(rng);
+    fix_orbit_parity(&mut center_edges.set, |_| true, odd);
+}
+
+/// Randomizes the center-wings at depths `A` and `B`, such that the parity of the permutation of both
+/// orbits (one for each [chirality](CenterWing::chirality)) is `odd`.
+pub fn randomize_center_wings<const A: u32, const B: u32>(
+    center_wings: &mut CenterWingSet<A, B>,
+    odd: bool,
+    rng: &mut (impl Rng + ?Sized),
+) {
+    for chirality in [Direction::Positive, Direction::Negative] {
+        let orbit = |wing: &CenterWing| wing.chirality() == chirality;
+
+        shuffle_orbit(&mut center_wings.set, orbit, rng);
+        fix_orbit_parity(&mut center_wings.set, orbit, odd);
+    }
+}
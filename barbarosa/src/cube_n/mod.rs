@@ -22,20 +22,25 @@ mod cube4;
 mod cube5;
 mod cube6;
 mod cube7;
+mod cube_nxn;
 
 pub mod invariants;
 pub mod moves;
+pub mod order;
 mod orientable;
 mod parser;
 pub mod pieces;
+pub mod reduction;
 pub mod search;
 pub mod space;
+mod supercube;
 pub mod visualization;
 
-pub use moves::{AxisMove, WideAxisMove};
+pub use moves::{wide::WideMove, AxisMove, WideAxisMove};
 use nalgebra::{Vector2, Vector3};
-pub use orientable::{Orientable, Orientation};
+pub use orientable::{IntoOrientable, Orientable, Orientation};
 pub use pieces::{center, Corner, Edge, Wing};
+pub use supercube::{IntoSuper, Super, SuperCube};
 
 pub use cube2::Cube2;
 pub use cube3::Cube3;
@@ -43,18 +48,26 @@ pub use cube4::Cube4;
 pub use cube5::Cube5;
 pub use cube6::Cube6;
 pub use cube7::Cube7;
+pub use cube_nxn::{Cube10, Cube11, Cube12, Cube13, Cube8, Cube9};
 
 use crate::generic;
 
+use self::order::PieceOrders;
+
 use self::space::Direction;
 
 type Vec2 = Vector2<Direction>;
 type Vec3 = Vector3<Direction>;
 
 /// An `NxNxN` cube.
-///
-/// Currently just a marker trait.
-pub trait CubeN: generic::Cube {
+pub trait CubeN: generic::Cube + generic::Movable<Self::WideMove> {
     /// The size of the cube.
     const N: u32;
+
+    /// The widest moves of the cube, which is `WideAxisMove<{N / 2 - 1}>`. Deeper moves are the same as
+    /// moving from the opposite face and rotating, so [`Orientable`] cubes use these to do any move.
+    type WideMove: WideMove;
+
+    /// The order of each type of piece of the cube. See [`PieceOrders`].
+    fn piece_orders(&self) -> PieceOrders;
 }
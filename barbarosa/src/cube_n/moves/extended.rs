@@ -1,16 +1,17 @@
 use crate::{
-    cube3::Cube3,
     cube_n::{
-        orientable::Orientable,
-        space::{Direction, Face},
-        Cube2, Cube4, Cube5, Cube6, Cube7, CubeN,
+        orientable::{Orientable, Orientation},
+        space::{Axis, Direction, Face},
+        CubeN, WideMove,
     },
-    generic::{Movable, Move},
+    generic::{moves::Mergeable, Alg, Movable, Move},
 };
 
 use super::{
+    notation::{DisplayNotation, Notation},
     rotation::{AxisRotation, Rotatable},
-    AxisMove, WideAxisMove,
+    wide::WideMoveCreationError,
+    AxisMove, SliceMove, WideAxisMove,
 };
 
 /// Fancier moves that only work on [`Orientable`] cubes.
@@ -35,6 +36,68 @@ pub enum ExtendedAxisMove {
     /// A slice move.
     #[allow(missing_docs)]
     Slice { rot: AxisRotation, wide: bool },
+
+    /// A move of a range of layers that doesn't include the outer layer, such as `2R` or `2-3Rw` in `SiGN` notation.
+    ///
+    /// The range goes from depth `start` to depth `end` (both inclusive), where depth 0 is the outer layer.
+    /// So, `2R` has `start` and `end` set to 1. Use [`ExtendedAxisMove::layers`] to create one.
+    Layers {
+        /// The corresponding outer layer move
+        axis_move: AxisMove,
+        /// The depth of the first layer that gets moved
+        start: u32,
+        /// The depth of the last layer that gets moved
+        end: u32,
+    },
+}
+
+impl ExtendedAxisMove {
+    /// Creates a move of the layers from depth `start` to depth `end` (both inclusive).
+    ///
+    /// If `start` is 0, the move is a regular [`WideAxisMove`] (or an [`AxisMove`] if `end` is also 0).
+    /// Otherwise, it's a [`ExtendedAxisMove::Layers`] move.
+    pub fn layers(axis_move: AxisMove, start: u32, end: u32) -> Self {
+        match (start, end) {
+            (0, 0) => Self::Regular(axis_move),
+            (0, depth) => Self::Wide(
+                axis_move
+                    .widen(depth)
+                    .expect("`u32::MAX` is bigger or equal than any depth"),
+            ),
+            (start, end) => Self::Layers {
+                axis_move,
+                start,
+                end,
+            },
+        }
+    }
+}
+
+impl<const N: u32> From<SliceMove<N>> for ExtendedAxisMove {
+    fn from(m: SliceMove<N>) -> Self {
+        let depth = m.depth();
+        Self::layers(m.axis_move, depth, depth)
+    }
+}
+
+/// The wide moves that make up a [`ExtendedAxisMove::Layers`] move: moving every layer up to `end` and undoing
+/// the ones before `start`.
+fn layers_as_wide_moves(
+    axis_move: &AxisMove,
+    start: u32,
+    end: u32,
+) -> impl Iterator<Item = WideAxisMove<{ u32::MAX }>> {
+    let widen = |mov: AxisMove, depth| {
+        mov.widen(depth)
+            .expect("`u32::MAX` is bigger or equal than any depth")
+    };
+
+    let outer = widen(axis_move.clone(), end);
+    let inner = start
+        .checked_sub(1)
+        .map(|depth| widen(axis_move.inverse(), depth));
+
+    std::iter::once(outer).chain(inner)
 }
 
 impl Move for ExtendedAxisMove {
@@ -47,69 +110,249 @@ impl Move for ExtendedAxisMove {
                 rot: rot.inverse(),
                 wide: *wide,
             },
+            Self::Layers {
+                axis_move,
+                start,
+                end,
+            } => Self::Layers {
+                axis_move: axis_move.inverse(),
+                start: *start,
+                end: *end,
+            },
         }
     }
 }
 
+impl Mergeable for ExtendedAxisMove {
+    type Axis = Axis;
+
+    fn axis(&self) -> Axis {
+        match self {
+            Self::Regular(m) | Self::Layers { axis_move: m, .. } => m.face.axis,
+            Self::Rotation(rot) | Self::Slice { rot, .. } => rot.axis,
+            Self::Wide(m) => m.face().axis,
+        }
+    }
+
+    fn merge(&self, other: &Self) -> Option<Option<Self>> {
+        let merge_rotations = |a: &AxisRotation, b: &AxisRotation| {
+            (a.axis == b.axis)
+                .then(|| (a.amount + b.amount).map(|amount| AxisRotation::new(a.axis, amount)))
+        };
+
+        match (self, other) {
+            (Self::Regular(a), Self::Regular(b)) => Some(a.merge(b)?.map(Self::Regular)),
+            (Self::Wide(a), Self::Wide(b)) => Some(a.merge(b)?.map(Self::Wide)),
+            (Self::Rotation(a), Self::Rotation(b)) => {
+                Some(merge_rotations(a, b)?.map(Self::Rotation))
+            }
+            (
+                Self::Slice { rot: a, wide },
+                Self::Slice {
+                    rot: b,
+                    wide: other_wide,
+                },
+            ) if wide == other_wide => {
+                Some(merge_rotations(a, b)?.map(|rot| Self::Slice { rot, wide: *wide }))
+            }
+            (
+                Self::Layers {
+                    axis_move: a,
+                    start,
+                    end,
+                },
+                Self::Layers {
+                    axis_move: b,
+                    start: other_start,
+                    end: other_end,
+                },
+            ) if (start, end) == (other_start, other_end) => {
+                Some(a.merge(b)?.map(|axis_move| Self::Layers {
+                    axis_move,
+                    start: *start,
+                    end: *end,
+                }))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl DisplayNotation for ExtendedAxisMove {
+    fn fmt_notation(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        notation: Notation,
+    ) -> std::fmt::Result {
+        match self {
+            Self::Regular(m) => write!(f, "{m}"),
+            Self::Rotation(rot) => write!(f, "{rot}"),
+            Self::Wide(m) => m.fmt_notation(f, notation),
+            Self::Layers {
+                axis_move,
+                start,
+                end,
+            } if start == end => write!(f, "{}{axis_move}", start + 1),
+            // WCA notation can't write inner layers as a single move, so both styles use SiGN
+            Self::Layers {
+                axis_move,
+                start,
+                end,
+            } => write!(
+                f,
+                "{}-{}{}w{}",
+                start + 1,
+                end + 1,
+                axis_move.face,
+                axis_move.amount
+            ),
+            Self::Slice { rot, wide } => {
+                // E and M slices are inverted (see the parser)
+                let (slice, amount) = match rot.axis {
+                    Axis::X => ("M", rot.amount * Direction::Negative),
+                    Axis::Y => ("E", rot.amount * Direction::Negative),
+                    Axis::Z => ("S", rot.amount),
+                };
+
+                match wide {
+                    false => write!(f, "{slice}{amount}"),
+                    true => write!(f, "{}{amount}", slice.to_lowercase()),
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ExtendedAxisMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_notation(f, Notation::default())
+    }
+}
+
 fn opposite<const N: u32>(m: &WideAxisMove<N>, depth: u32) -> WideAxisMove<N> {
     AxisMove::new(m.face().opposite(), m.amount())
         .widen(depth)
         .unwrap()
 }
 
-// This has to be a macro because the type system can't enforce that a `CubeN::N` is movable by `WideAxisMove<{N / 2 - 1}>`
-macro_rules! impl_movable_extended {
-    ([$($cube:ty),*]) => {
-        $(
-            impl_movable_extended!($cube);
-        )*
-    };
+/// Translates a move done on a cube of size `n`, held in `orientation`, into wide moves of a cube with fixed
+/// centers (like the base cube of an [`Orientable`]). The orientation gets updated with the rotations done
+/// by the move.
+///
+/// The wide moves have a depth of at most `n / 2 - 1`, since deeper moves are done from the opposite side.
+fn fixed_center_moves(
+    m: &ExtendedAxisMove,
+    n: u32,
+    orientation: &mut Orientation,
+) -> Vec<WideAxisMove<{ u32::MAX }>> {
+    match m {
+        ExtendedAxisMove::Regular(m) => vec![m
+            .clone()
+            .reoriented(orientation)
+            .widen(0)
+            .expect("`u32::MAX` is bigger or equal than any depth")],
+        ExtendedAxisMove::Rotation(rot) => {
+            orientation.rotate(rot);
+            vec![]
+        }
+        ExtendedAxisMove::Wide(m) => {
+            // A wide move of depth `d` turns `d + 1` layers
+            let Some(depth_oppossite) = n.checked_sub(m.depth() + 2) else {
+                // If it turns every layer, then it is equivalent to a rotation
+                orientation.rotate(&AxisRotation::from(&m.axis_move));
+                return vec![];
+            };
 
-    ($cube:ty) => {
-        impl Movable<ExtendedAxisMove> for Orientable<$cube> {
-            fn apply(&mut self, m: &ExtendedAxisMove) {
-                match m {
-                    ExtendedAxisMove::Regular(m) => self.apply(m),
-                    ExtendedAxisMove::Rotation(rot) => self.orientation.rotate(rot),
-                    ExtendedAxisMove::Wide(m) => {
-                        let Some(depth_oppossite) = <$cube>::N.checked_sub(m.depth()) else {
-                            // If depth is more than N, then it is equivalent to a rotation
-                            self.orientation.rotate(&AxisRotation::from(&m.axis_move));
-                            return;
-                        };
-
-                        let m = if m.depth() < depth_oppossite {
-                            m.clone()
-                        } else {
-                            self.orientation.rotate(&AxisRotation::from(&m.axis_move));
-                            opposite(&m, depth_oppossite)
-                        };
-
-                        let m = m.set_max_depth::<{ <$cube>::N / 2 - 1 }>()
-                            .expect("the depth from both sides can't be larger than N/2");
-                        self.base_cube.apply(&m);
-                    },
-                    ExtendedAxisMove::Slice { rot, wide } => {
-                        for dir in [Direction::Positive, Direction::Negative] {
-                            let depth = match wide {
-                                false => 0,
-                                true => <$cube>::N / 2 - 1,
-                            };
-
-                            let m = WideAxisMove::<{ <$cube>::N / 2 - 1 }>::new(Face::new(rot.axis, dir), rot.amount * Direction::Negative, depth).unwrap();
-
-                            self.base_cube.apply(&m);
-                        }
-
-                        self.orientation.rotate(rot);
-                    },
-                }
-            }
+            let mut m = if m.depth() <= depth_oppossite {
+                m.clone()
+            } else {
+                orientation.rotate(&AxisRotation::from(&m.axis_move));
+                opposite(m, depth_oppossite)
+            };
+
+            m.axis_move.reorient(orientation);
+            vec![m]
         }
-    };
+        ExtendedAxisMove::Slice { rot, wide } => {
+            let depth = match wide {
+                false => 0,
+                true => n / 2 - 1,
+            };
+
+            let moves = [Direction::Positive, Direction::Negative]
+                .into_iter()
+                .map(|dir| {
+                    let axis_move = AxisMove::new(Face::new(rot.axis, dir), rot.amount * -dir);
+                    axis_move
+                        .reoriented(orientation)
+                        .widen(depth)
+                        .expect("`u32::MAX` is bigger or equal than any depth")
+                })
+                .collect();
+
+            orientation.rotate(rot);
+            moves
+        }
+        ExtendedAxisMove::Layers {
+            axis_move,
+            start,
+            end,
+        } => layers_as_wide_moves(axis_move, *start, *end)
+            .flat_map(|m| fixed_center_moves(&ExtendedAxisMove::Wide(m), n, orientation))
+            .collect(),
+    }
+}
+
+impl Alg<ExtendedAxisMove> {
+    /// Removes the rotations of an alg for a cube of size `n`, by folding them into the moves that come after.
+    /// Slices and deep wide moves also rotate the cube, so they get replaced by moves of the outer layers.
+    ///
+    /// Returns the equivalent alg made of wide moves with a depth of at most `N`, together with the orientation
+    /// the cube ends up in. Fails if the alg needs deeper wide moves than `N`, which can only happen if `N`
+    /// is smaller than `n / 2 - 1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use barbarosa::prelude::*;
+    /// use barbarosa::cube_n::{moves::ExtendedAxisMove, space::Face, AxisMove, Orientation};
+    /// use barbarosa::generic::Alg;
+    ///
+    /// let alg = Alg::<ExtendedAxisMove>::parse("y R U R'").unwrap();
+    /// let (moves, orientation) = alg.without_rotations::<0>(3).unwrap();
+    ///
+    /// assert_eq!(Alg::<AxisMove>::from(moves).to_string(), "B U B'");
+    /// assert_eq!(orientation, Orientation::try_from([Face::F, Face::U]).unwrap());
+    /// ```
+    pub fn without_rotations<const N: u32>(
+        &self,
+        n: u32,
+    ) -> Result<(Alg<WideAxisMove<N>>, Orientation), WideMoveCreationError> {
+        let mut orientation = Orientation::default();
+
+        let moves = self
+            .moves
+            .iter()
+            .flat_map(|m| fixed_center_moves(m, n, &mut orientation))
+            .map(WideAxisMove::set_max_depth)
+            .collect::<Result<_, _>>()?;
+
+        Ok((moves, orientation))
+    }
 }
 
-impl_movable_extended!([Cube2, Cube3, Cube4, Cube5, Cube6, Cube7]);
+/// Any move can be done with the widest moves of the base cube (see [`CubeN::WideMove`]), while the
+/// orientation keeps track of the rotations.
+impl<C: CubeN> Movable<ExtendedAxisMove> for Orientable<C> {
+    fn apply(&mut self, m: &ExtendedAxisMove) {
+        for m in fixed_center_moves(m, C::N, &mut self.orientation) {
+            let m = C::WideMove::from_wide(m)
+                .expect("the depth from both sides can't be larger than N/2");
+
+            self.base_cube.apply(&m);
+        }
+    }
+}
 
 impl From<AxisMove> for ExtendedAxisMove {
     fn from(m: AxisMove) -> Self {
@@ -0,0 +1,136 @@
+//! Metrics to count the moves of `NxN` algs.
+//!
+//! See [`TurnMetric`] for more info.
+
+use crate::generic::moves::Metric;
+
+use super::{
+    Amount, AxisMove, ExtendedAxisMove, NonRedundantAxisMove, QuarterAxisMove, SliceMove,
+    WideAxisMove,
+};
+
+/// The usual metrics to count the moves of `NxN` algs.
+///
+/// They differ in how they count double moves, slices (a single inner layer, like `M` or `2R`)
+/// and rotations:
+///
+/// | Metric   | `R` | `R2` | `Rw` | `M` | `M2` | `x` |
+/// |----------|-----|------|------|-----|------|-----|
+/// | [HTM](TurnMetric::Htm)   | 1 | 1 | 1 | 2 | 2 | 0 |
+/// | [QTM](TurnMetric::Qtm)   | 1 | 2 | 1 | 2 | 4 | 0 |
+/// | [STM](TurnMetric::Stm)   | 1 | 1 | 1 | 1 | 1 | 0 |
+/// | [ETM](TurnMetric::Etm)   | 1 | 1 | 1 | 1 | 1 | 1 |
+/// | [QSTM](TurnMetric::Qstm) | 1 | 2 | 1 | 1 | 2 | 0 |
+///
+/// # Example
+///
+/// ```rust
+/// use barbarosa::cube_n::moves::{metric::TurnMetric, ExtendedAxisMove};
+/// use barbarosa::generic::{Alg, Parsable};
+///
+/// let alg = Alg::<ExtendedAxisMove>::parse("x M' U2 M U2").unwrap();
+///
+/// assert_eq!(alg.count(TurnMetric::Htm), 6);
+/// assert_eq!(alg.count(TurnMetric::Qtm), 8);
+/// assert_eq!(alg.count(TurnMetric::Stm), 4);
+/// assert_eq!(alg.count(TurnMetric::Etm), 5);
+/// assert_eq!(alg.count(TurnMetric::Qstm), 6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TurnMetric {
+    /// Half turn metric, where any turn of the outer layers counts as one. Slices count as two, since they're
+    /// the same as turning both outer layers.
+    #[default]
+    Htm,
+
+    /// Quarter turn metric. Like [HTM](TurnMetric::Htm), but double moves count as two.
+    Qtm,
+
+    /// Slice turn metric, where any turn of any block of layers counts as one.
+    Stm,
+
+    /// Execution turn metric. Like [STM](TurnMetric::Stm), but rotations count as one too.
+    Etm,
+
+    /// Quarter slice turn metric. Like [STM](TurnMetric::Stm), but double moves count as two.
+    Qstm,
+}
+
+impl TurnMetric {
+    /// The cost of turning a block of layers. `slice` is whether the block doesn't include an outer layer.
+    fn turn_cost(&self, amount: Amount, slice: bool) -> u32 {
+        let quarter_turns = match amount {
+            Amount::Double => 2,
+            Amount::Single | Amount::Inverse => 1,
+        };
+
+        let outer_layers = match slice {
+            true => 2,
+            false => 1,
+        };
+
+        match self {
+            TurnMetric::Htm => outer_layers,
+            TurnMetric::Qtm => outer_layers * quarter_turns,
+            TurnMetric::Stm | TurnMetric::Etm => 1,
+            TurnMetric::Qstm => quarter_turns,
+        }
+    }
+
+    /// The cost of rotating the whole cube.
+    fn rotation_cost(&self) -> u32 {
+        match self {
+            TurnMetric::Etm => 1,
+            _ => 0,
+        }
+    }
+}
+
+impl Metric<AxisMove> for TurnMetric {
+    fn cost(&self, mov: &AxisMove) -> u32 {
+        self.turn_cost(mov.amount, false)
+    }
+}
+
+impl Metric<QuarterAxisMove> for TurnMetric {
+    fn cost(&self, mov: &QuarterAxisMove) -> u32 {
+        self.cost(&AxisMove::from(mov))
+    }
+}
+
+impl Metric<NonRedundantAxisMove> for TurnMetric {
+    fn cost(&self, mov: &NonRedundantAxisMove) -> u32 {
+        match mov {
+            NonRedundantAxisMove::Single(mov) => self.cost(mov),
+            NonRedundantAxisMove::Double {
+                amount_positive,
+                amount_negative,
+                ..
+            } => self.turn_cost(*amount_positive, false) + self.turn_cost(*amount_negative, false),
+        }
+    }
+}
+
+impl<const N: u32> Metric<WideAxisMove<N>> for TurnMetric {
+    fn cost(&self, mov: &WideAxisMove<N>) -> u32 {
+        self.cost(&mov.axis_move)
+    }
+}
+
+impl<const N: u32> Metric<SliceMove<N>> for TurnMetric {
+    fn cost(&self, mov: &SliceMove<N>) -> u32 {
+        self.turn_cost(mov.axis_move.amount, true)
+    }
+}
+
+impl Metric<ExtendedAxisMove> for TurnMetric {
+    fn cost(&self, mov: &ExtendedAxisMove) -> u32 {
+        match mov {
+            ExtendedAxisMove::Regular(mov) => self.cost(mov),
+            ExtendedAxisMove::Wide(mov) => self.cost(mov),
+            ExtendedAxisMove::Rotation(_) => self.rotation_cost(),
+            ExtendedAxisMove::Slice { rot, .. } => self.turn_cost(rot.amount, true),
+            ExtendedAxisMove::Layers { axis_move, .. } => self.turn_cost(axis_move.amount, true),
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Mirroring moves and algs of `NxNxN` cubes.
+//!
+//! See [`Mirrorable`] for more info.
+
+use crate::{
+    cube_n::space::{Axis, Direction, Face},
+    generic::{moves::AsMove, Alg},
+};
+
+use super::{
+    rotation::AxisRotation, AxisMove, ExtendedAxisMove, NonRedundantAxisMove, QuarterAxisMove,
+    SliceMove, WideAxisMove,
+};
+
+/// A plane of the cube that moves can be mirrored through. Each one is named after the slice in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MirrorPlane {
+    /// The plane between R and L, which swaps both faces
+    M,
+    /// The plane between U and D, which swaps both faces
+    E,
+    /// The plane between F and B, which swaps both faces
+    S,
+}
+
+impl MirrorPlane {
+    /// The axis perpendicular to the plane
+    pub fn normal(&self) -> Axis {
+        match self {
+            MirrorPlane::M => Axis::X,
+            MirrorPlane::E => Axis::Y,
+            MirrorPlane::S => Axis::Z,
+        }
+    }
+}
+
+/// Things that can be mirrored through a [`MirrorPlane`].
+///
+/// Mirroring a move swaps the faces on both sides of the plane and reverses the direction of every turn,
+/// since mirrors swap clockwise and counterclockwise. For example, mirroring through the M plane turns a
+/// right-handed alg into its left-handed version:
+///
+/// ```rust
+/// use barbarosa::prelude::*;
+/// use barbarosa::cube_n::moves::mirror::{Mirrorable, MirrorPlane};
+/// use barbarosa::generic::Alg;
+///
+/// let alg = Alg::<AxisMove>::parse("R U R' U'").unwrap();
+///
+/// assert_eq!(alg.mirrored(MirrorPlane::M).to_string(), "L' U' L U");
+/// ```
+pub trait Mirrorable: Sized {
+    /// Mirrors `self` through the given plane
+    fn mirror(&mut self, plane: MirrorPlane);
+
+    /// Returns a mirrored copy of `self`
+    ///
+    /// See also [`Self::mirror`]
+    fn mirrored(mut self, plane: MirrorPlane) -> Self {
+        self.mirror(plane);
+        self
+    }
+}
+
+impl Mirrorable for Face {
+    fn mirror(&mut self, plane: MirrorPlane) {
+        if self.axis == plane.normal() {
+            *self = self.opposite();
+        }
+    }
+}
+
+impl Mirrorable for AxisMove {
+    fn mirror(&mut self, plane: MirrorPlane) {
+        self.face.mirror(plane);
+        self.amount = self.amount * Direction::Negative;
+    }
+}
+
+impl Mirrorable for QuarterAxisMove {
+    fn mirror(&mut self, plane: MirrorPlane) {
+        self.face.mirror(plane);
+        self.direction = -self.direction;
+    }
+}
+
+impl<const N: u32> Mirrorable for WideAxisMove<N> {
+    fn mirror(&mut self, plane: MirrorPlane) {
+        self.axis_move.mirror(plane);
+    }
+}
+
+impl<const N: u32> Mirrorable for SliceMove<N> {
+    fn mirror(&mut self, plane: MirrorPlane) {
+        self.axis_move.mirror(plane);
+    }
+}
+
+/// Rotations around the normal of the plane stay the same, since both the face and the direction get
+/// swapped (`x` is both `R` and `L'`). The rest get inverted.
+impl Mirrorable for AxisRotation {
+    fn mirror(&mut self, plane: MirrorPlane) {
+        if self.axis != plane.normal() {
+            *self = self.inverse();
+        }
+    }
+}
+
+impl Mirrorable for NonRedundantAxisMove {
+    fn mirror(&mut self, plane: MirrorPlane) {
+        match self {
+            NonRedundantAxisMove::Single(mov) => mov.mirror(plane),
+            NonRedundantAxisMove::Double {
+                axis,
+                amount_positive,
+                amount_negative,
+            } => {
+                if *axis == plane.normal() {
+                    std::mem::swap(amount_positive, amount_negative);
+                }
+
+                *amount_positive = *amount_positive * Direction::Negative;
+                *amount_negative = *amount_negative * Direction::Negative;
+            }
+        }
+    }
+}
+
+impl Mirrorable for ExtendedAxisMove {
+    fn mirror(&mut self, plane: MirrorPlane) {
+        match self {
+            ExtendedAxisMove::Regular(mov) | ExtendedAxisMove::Layers { axis_move: mov, .. } => {
+                mov.mirror(plane)
+            }
+            ExtendedAxisMove::Wide(mov) => mov.mirror(plane),
+            ExtendedAxisMove::Rotation(rot) | ExtendedAxisMove::Slice { rot, .. } => {
+                rot.mirror(plane)
+            }
+        }
+    }
+}
+
+impl<T: AsMove> Mirrorable for Alg<T>
+where
+    T::Move: Mirrorable,
+{
+    fn mirror(&mut self, plane: MirrorPlane) {
+        for mov in &mut self.moves {
+            mov.mirror(plane);
+        }
+    }
+}
@@ -11,9 +11,13 @@ use self::wide::WideMoveCreationError;
 use super::space::{Axis, Direction, Face};
 use crate::generic;
 
+pub mod metric;
+pub mod mirror;
 pub mod non_redundant;
+pub mod notation;
 pub mod perms;
 pub mod rotation;
+pub mod slice;
 pub mod wide;
 
 mod amount;
@@ -25,6 +29,7 @@ pub use amount::Amount;
 pub use extended::ExtendedAxisMove;
 pub use non_redundant::NonRedundantAxisMove;
 pub use quarter::QuarterAxisMove;
+pub use slice::SliceMove;
 pub use wide::WideAxisMove;
 
 /// A clockwise [move](generic::Move) on an axis. Main type of move for cuboids.
@@ -33,6 +38,7 @@ pub use wide::WideAxisMove;
 ///
 /// Variants of axis moves are:
 /// - [`WideAxisMove`] for big cubes (4x4 and up)
+/// - [`SliceMove`] for inner layers of big cubes
 /// - [`QuarterAxisMove`]
 /// - [`NonRedundantAxisMove`]
 #[derive(Debug, PartialEq, Eq, Clone, RandGen)]
@@ -59,6 +65,22 @@ impl generic::Move for AxisMove {
     }
 }
 
+impl generic::moves::Mergeable for AxisMove {
+    type Axis = Axis;
+
+    fn axis(&self) -> Axis {
+        self.face.axis
+    }
+
+    fn merge(&self, other: &Self) -> Option<Option<Self>> {
+        if self.face != other.face {
+            return None;
+        }
+
+        Some((self.amount + other.amount).map(|amount| Self::new(self.face.clone(), amount)))
+    }
+}
+
 impl AxisMove {
     const DISTINCT_MOVES: usize = 3 * 2 * 3;
 
@@ -236,19 +236,19 @@ pub fn absorve(
     }
 }
 
-/// The result of doing an [absorve]
+/// The result of doing an [`absorve`]
 #[derive(Debug, PartialEq, Eq)]
 pub enum AbsorveResult {
-    /// The move was absorved into the [NonRedundantAxisMove]. E.g.: `R2 + R => R'`
+    /// The move was absorved into the [`NonRedundantAxisMove`]. E.g.: `R2 + R => R'`
     Absorved,
 
-    /// The move was not absorved, but it was added to the [NonRedundantAxisMove]. E.g.: `L + R2 => R2 L`
+    /// The move was not absorved, but it was added to the [`NonRedundantAxisMove`]. E.g.: `L + R2 => R2 L`
     Added,
 
-    /// The move was not absorved, and it was not added to the [NonRedundantAxisMove]. E.g.: `R2 + F => Whoops`
+    /// The move was not absorved, and it was not added to the [`NonRedundantAxisMove`]. E.g.: `R2 + F => Whoops`
     NotAdded,
 
-    /// The move cancelled out with (one of the) [NonRedundantAxisMove]\(s\). E.g.: `R + R' => None` or `R2 L + L' => R2`
+    /// The move cancelled out with (one of the) [`NonRedundantAxisMove`]\(s\). E.g.: `R + R' => None` or `R2 L + L' => R2`
     Collapsed,
 }
 
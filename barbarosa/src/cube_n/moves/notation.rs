@@ -0,0 +1,89 @@
+//! Notation styles for moves of big cubes.
+//!
+//! See [`Notation`] for more info.
+
+use std::fmt::{Display, Formatter, Result};
+
+use crate::generic::{moves::AsMove, Alg};
+
+use super::AxisMove;
+
+/// The notation used to display moves of big cubes.
+///
+/// Both styles agree on outer layer moves, rotations and slices. They differ in:
+/// - Wide moves: WCA writes `Rw` and `3Rw`, while `SiGN` writes `r` and `3r`.
+/// - Inner layer moves: `SiGN` writes `2R` and `2-3Rw`. WCA notation doesn't have a way to write them as a
+///   single move, so they're written in `SiGN` notation with both styles.
+///
+/// Both styles can always be parsed, regardless of the notation used for displaying.
+///
+/// # Example
+///
+/// ```rust
+/// use barbarosa::prelude::*;
+/// use barbarosa::cube_n::moves::{notation::{DisplayNotation, Notation}, ExtendedAxisMove};
+/// use barbarosa::generic::Alg;
+///
+/// let alg = Alg::<ExtendedAxisMove>::parse("3Rw U r' 2R").unwrap();
+///
+/// assert_eq!(alg.notated(Notation::Wca).to_string(), "3Rw U Rw' 2R");
+/// assert_eq!(alg.notated(Notation::Sign).to_string(), "3r U r' 2R");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Notation {
+    /// The notation used in official WCA scrambles. This is the default when using [`Display`].
+    #[default]
+    Wca,
+
+    /// The `SiGN` notation, used by some alg databases and tools.
+    Sign,
+}
+
+/// Something that can be displayed with a specific [`Notation`].
+pub trait DisplayNotation {
+    /// Formats `self` using the given notation.
+    fn fmt_notation(&self, f: &mut Formatter<'_>, notation: Notation) -> Result;
+
+    /// Returns a wrapper that implements [`Display`] using the given notation.
+    fn notated(&self, notation: Notation) -> Notated<'_, Self> {
+        Notated {
+            value: self,
+            notation,
+        }
+    }
+}
+
+/// Wrapper to display a value with a specific [`Notation`]. See [`DisplayNotation::notated`].
+pub struct Notated<'a, T: ?Sized> {
+    value: &'a T,
+    notation: Notation,
+}
+
+impl<T: DisplayNotation + ?Sized> Display for Notated<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.value.fmt_notation(f, self.notation)
+    }
+}
+
+impl DisplayNotation for AxisMove {
+    fn fmt_notation(&self, f: &mut Formatter<'_>, _notation: Notation) -> Result {
+        write!(f, "{self}")
+    }
+}
+
+impl<T: AsMove> DisplayNotation for Alg<T>
+where
+    T::Move: DisplayNotation,
+{
+    fn fmt_notation(&self, f: &mut Formatter<'_>, notation: Notation) -> Result {
+        for (i, mov) in self.moves.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+
+            mov.fmt_notation(f, notation)?;
+        }
+
+        Ok(())
+    }
+}
@@ -25,3 +25,27 @@ pub mod pll {
     pub static J: Lazy<Alg<AxisMove>> =
         Lazy::new(|| "R U R' F' R U R' U' R' F R2 U' R' U'".try_into().unwrap());
 }
+
+/// Algs to fix the parities of the big cubes, which are states that can't happen on a 3x3x3.
+pub mod parity {
+    use crate::cube_n::{reduction::parse, WideAxisMove};
+
+    use super::*;
+
+    /// Flips the wings at `depth` of the UF edge of the `n`x`n`x`n`, keeping the rest of the edges and the centers
+    /// solved. Besides OLL parity, on bigger cubes it fixes the parity of the wings of a single depth.
+    pub fn flip<const W: u32>(n: u32, depth: u32) -> Alg<WideAxisMove<W>> {
+        let d = depth + 1;
+
+        parse(
+            &format!("{d}R2 B2 U2 {d}L U2 {d}R' U2 {d}R U2 F2 {d}R F2 {d}L' B2 {d}R2"),
+            n,
+        )
+    }
+
+    /// OLL parity. Flips the UF edge, keeping the centers solved
+    pub static OLL: Lazy<Alg<WideAxisMove<1>>> = Lazy::new(|| flip(4, 1));
+
+    /// PLL parity. Swaps the UF and UB edges, keeping the centers solved
+    pub static PLL: Lazy<Alg<WideAxisMove<1>>> = Lazy::new(|| parse("2R2 U2 2R2 Uw2 2R2 2U2", 4));
+}
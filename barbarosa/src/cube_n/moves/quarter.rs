@@ -1,7 +1,9 @@
+use itertools::iproduct;
 use rand_derive2::RandGen;
+use strum::IntoEnumIterator;
 
 use crate::{
-    cube_n::space::{Direction, Face},
+    cube_n::space::{Axis, Direction, Face},
     generic::{self, Alg, Cube, Movable},
 };
 
@@ -29,11 +31,39 @@ impl generic::Move for QuarterAxisMove {
     }
 }
 
+impl generic::moves::Mergeable for QuarterAxisMove {
+    type Axis = Axis;
+
+    fn axis(&self) -> Axis {
+        self.face.axis
+    }
+
+    /// Quarter moves can only cancel out, since merging two equal moves would give a double move.
+    fn merge(&self, other: &Self) -> Option<Option<Self>> {
+        match self.face == other.face && self.direction != other.direction {
+            true => Some(None),
+            false => None,
+        }
+    }
+}
+
+impl std::fmt::Display for QuarterAxisMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.face, Amount::from(self.direction))
+    }
+}
+
 impl QuarterAxisMove {
     /// Creates a new [`QuarterAxisMove`]
     pub const fn new(face: Face, direction: Direction) -> Self {
         Self { face, direction }
     }
+
+    /// Returns an iterator over all the quarter moves
+    pub fn all() -> impl Iterator<Item = Self> {
+        iproduct!(Face::iter(), Direction::iter())
+            .map(|(face, direction)| Self::new(face, direction))
+    }
 }
 
 impl From<&QuarterAxisMove> for AxisMove {
@@ -5,14 +5,15 @@
 use nalgebra::vector;
 
 use crate::{
+    cube_n::Orientation,
     cube_n::{
         space::{Axis, Direction, Face},
         Vec2, Vec3,
     },
-    generic::Alg,
+    generic::{moves::AsMove, Alg},
 };
 
-use super::{Amount, AxisMove};
+use super::{Amount, AxisMove, ExtendedAxisMove};
 
 /// A rotation around an axis. This is similar to an [`AxisMove`](super::AxisMove), but it doesn't
 /// specify the face.
@@ -46,6 +47,18 @@ impl AxisRotation {
     }
 }
 
+impl std::fmt::Display for AxisRotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let axis = match self.axis {
+            Axis::X => "x",
+            Axis::Y => "y",
+            Axis::Z => "z",
+        };
+
+        write!(f, "{axis}{}", self.amount)
+    }
+}
+
 /// Things that can be rotated.
 pub trait Rotatable: Sized {
     /// Rotates a piece according to an [`AxisRotation`]
@@ -58,6 +71,39 @@ pub trait Rotatable: Sized {
         self.rotate(rotation);
         self
     }
+
+    /// Translates something done while holding the cube in `orientation` to the default orientation.
+    ///
+    /// For algs, this is the same as conjugating them by the rotations of the orientation, so it gives
+    /// the version of the alg for a different angle.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use barbarosa::prelude::*;
+    /// use barbarosa::cube_n::{moves::rotation::Rotatable, space::Face, Orientation};
+    /// use barbarosa::generic::Alg;
+    ///
+    /// let alg = Alg::<AxisMove>::parse("R U R' U'").unwrap();
+    ///
+    /// // After a `y`, the original R face is in front and the original B face is on the right
+    /// let orientation = Orientation::try_from([Face::F, Face::U]).unwrap();
+    ///
+    /// assert_eq!(alg.reoriented(&orientation).to_string(), "B U B' U'");
+    /// ```
+    fn reorient(&mut self, orientation: &Orientation) {
+        for rotation in orientation.inverse_rotations() {
+            self.rotate(&rotation);
+        }
+    }
+
+    /// Returns a copy of `self` translated to the default orientation.
+    ///
+    /// See also [`Self::reorient`]
+    fn reoriented(mut self, orientation: &Orientation) -> Self {
+        self.reorient(orientation);
+        self
+    }
 }
 
 impl Rotatable for Face {
@@ -116,7 +162,33 @@ impl Rotatable for AxisMove {
     }
 }
 
-impl Rotatable for Alg<AxisMove> {
+/// A rotation gets rotated as the move of the positive face of its axis (`x` is rotated like `R`).
+impl Rotatable for AxisRotation {
+    fn rotate(&mut self, rotation: &AxisRotation) {
+        let face = Face::new(self.axis, Direction::Positive);
+        let mov = AxisMove::new(face, self.amount).rotated(rotation);
+        *self = AxisRotation::from(&mov);
+    }
+}
+
+impl Rotatable for ExtendedAxisMove {
+    fn rotate(&mut self, rotation: &AxisRotation) {
+        match self {
+            ExtendedAxisMove::Regular(mov) | ExtendedAxisMove::Layers { axis_move: mov, .. } => {
+                mov.rotate(rotation)
+            }
+            ExtendedAxisMove::Wide(mov) => mov.axis_move.rotate(rotation),
+            ExtendedAxisMove::Rotation(rot) | ExtendedAxisMove::Slice { rot, .. } => {
+                rot.rotate(rotation)
+            }
+        }
+    }
+}
+
+impl<T: AsMove> Rotatable for Alg<T>
+where
+    T::Move: Rotatable,
+{
     fn rotate(&mut self, rotation: &AxisRotation) {
         for mov in &mut self.moves {
             mov.rotate(rotation);
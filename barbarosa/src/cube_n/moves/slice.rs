@@ -0,0 +1,204 @@
+//! Moves of a single inner layer of big cubes.
+//!
+//! See [`SliceMove`] for more info.
+
+use std::mem;
+
+use rand::prelude::Distribution;
+
+use crate::{
+    cube_n::{
+        space::{Axis, Direction},
+        CubeN, Orientable,
+    },
+    generic::{self, moves::Mergeable, Movable},
+};
+
+use super::{
+    notation::{DisplayNotation, Notation},
+    wide::WideMoveCreationError,
+    Amount, AxisMove, ExtendedAxisMove, WideAxisMove,
+};
+
+/// A move of a single inner layer, at a depth of at most `N`.
+///
+/// The depth counts the layers from the face of the move, so the layer next to the face has depth 1. For
+/// example, the 5x5 moves `2R` and `3R` (the same as `M'`) have depths 1 and 2 respectively. This is written
+/// in `SiGN` notation as the layer number followed by the face. See [`Notation`] for more info.
+///
+/// # Example
+///
+/// ```rust
+/// use barbarosa::prelude::*;
+/// use barbarosa::cube_n::{moves::slice::SliceMove, Cube5, WideAxisMove};
+/// use barbarosa::generic::Alg;
+///
+/// let slice = SliceMove::<3>::parse("2R").unwrap();
+/// let wide = Alg::<WideAxisMove<1>>::parse("Rw R'").unwrap();
+///
+/// assert_eq!(Cube5::SOLVED.moved(&slice), Cube5::SOLVED.moved(&wide));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SliceMove<const N: u32> {
+    /// invariant: 1 <= depth <= N
+    depth: u32,
+
+    /// The move of the outer layer with the same face and amount
+    pub axis_move: AxisMove,
+}
+
+impl<const N: u32> SliceMove<N> {
+    /// Creates a new [`SliceMove<N>`].
+    ///
+    /// Returns [`WideMoveCreationError`] if the depth is 0 (which is the outer layer) or greater than `N`.
+    pub fn new(axis_move: AxisMove, depth: u32) -> Result<Self, WideMoveCreationError> {
+        match depth {
+            0 => Err(WideMoveCreationError::OuterLayer(depth)),
+            depth if depth > N => Err(WideMoveCreationError::ExcededDepth(depth, N)),
+            depth => Ok(Self { depth, axis_move }),
+        }
+    }
+
+    /// Depth of the move
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    /// Returns a new [`SliceMove<N>`] with the max depth set to `M`.
+    pub fn set_max_depth<const M: u32>(self) -> Result<SliceMove<M>, WideMoveCreationError> {
+        if self.depth > M {
+            return Err(WideMoveCreationError::ExcededDepth(self.depth, M));
+        }
+
+        // Safe because we're changing between slice moves which have the same exact structure
+        let output = unsafe { mem::transmute::<SliceMove<N>, SliceMove<M>>(self) };
+
+        Ok(output)
+    }
+
+    /// The wide moves that make up the slice: moving every layer up to the slice and undoing the outer ones.
+    pub fn as_wide_moves(&self) -> [WideAxisMove<N>; 2] {
+        let widen = |mov: AxisMove, depth| {
+            mov.widen(depth)
+                .expect("the depth of the slice is at most `N`")
+        };
+
+        [
+            widen(self.axis_move.clone(), self.depth),
+            widen(generic::Move::inverse(&self.axis_move), self.depth - 1),
+        ]
+    }
+}
+
+impl<const N: u32> generic::Move for SliceMove<N> {
+    fn inverse(&self) -> Self {
+        Self {
+            depth: self.depth,
+            axis_move: self.axis_move.inverse(),
+        }
+    }
+}
+
+impl<const N: u32> Mergeable for SliceMove<N> {
+    type Axis = Axis;
+
+    fn axis(&self) -> Axis {
+        self.axis_move.face.axis
+    }
+
+    fn merge(&self, other: &Self) -> Option<Option<Self>> {
+        if self.depth != other.depth {
+            return None;
+        }
+
+        let merged = self.axis_move.merge(&other.axis_move)?;
+
+        Some(merged.map(|axis_move| Self {
+            depth: self.depth,
+            axis_move,
+        }))
+    }
+}
+
+impl<const N: u32> DisplayNotation for SliceMove<N> {
+    fn fmt_notation(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        _notation: Notation,
+    ) -> std::fmt::Result {
+        // `SiGN` is the only notation that can write them as a single move, so both styles use it
+        write!(f, "{}{}", self.depth + 1, self.axis_move)
+    }
+}
+
+impl<const N: u32> std::fmt::Display for SliceMove<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_notation(f, Notation::default())
+    }
+}
+
+impl<const N: u32> Distribution<SliceMove<N>> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> SliceMove<N> {
+        let axis_move = rng.gen();
+        let depth = rng.gen_range(1..=N);
+        SliceMove::new(axis_move, depth).unwrap()
+    }
+}
+
+/// Applies a slice move to a cube whose wide moves have a depth of at most `W`.
+///
+/// Inner layers closer to the opposite face get moved from that face instead. The only layer that is
+/// out of reach of wide moves is the middle one in odd cubes. Moving it would move the centers, which
+/// are the reference for the rest of the pieces, so the layers on both sides get moved the other way
+/// instead (just like `M` is `R L'` in a cube without rotations). Use an [`Orientable`] cube to keep
+/// track of where the centers end up.
+pub(crate) fn apply_slice_move<C, const W: u32, const N: u32>(cube: &mut C, m: &SliceMove<N>)
+where
+    C: CubeN + Movable<WideAxisMove<W>>,
+{
+    let AxisMove { face, amount } = m.axis_move.clone();
+    let opposite_depth = C::N - 1 - m.depth;
+
+    let (face, amount, depth) = match opposite_depth < m.depth {
+        true => (
+            face.opposite(),
+            amount * Direction::Negative,
+            opposite_depth,
+        ),
+        false => (face, amount, m.depth),
+    };
+
+    let wide = |face, amount: Amount, depth| {
+        WideAxisMove::<W>::new(face, amount, depth).expect("the slice is in the cube")
+    };
+
+    if depth <= W {
+        cube.apply(&wide(face.clone(), amount, depth));
+        cube.apply(&wide(face, amount * Direction::Negative, depth - 1));
+    } else {
+        cube.apply(&wide(face.opposite(), amount, W));
+        cube.apply(&wide(face, amount * Direction::Negative, W));
+    }
+}
+
+/// Slices move the centers of odd cubes, so they get applied as [`ExtendedAxisMove::Layers`] to keep track
+/// of the orientation.
+impl<const N: u32, C: CubeN> Movable<SliceMove<N>> for Orientable<C> {
+    fn apply(&mut self, m: &SliceMove<N>) {
+        self.apply(&ExtendedAxisMove::from(m.clone()));
+    }
+}
+
+macro_rules! impl_movable_slice_move {
+    ($cube:ty, $max_width:literal, [$($depth:literal),*]) => {
+        $(
+            impl crate::generic::Movable<crate::cube_n::moves::slice::SliceMove<$depth>> for $cube {
+                fn apply(&mut self, m: &crate::cube_n::moves::slice::SliceMove<$depth>) {
+                    crate::cube_n::moves::slice::apply_slice_move::<$cube, $max_width, $depth>(self, m);
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_movable_slice_move;
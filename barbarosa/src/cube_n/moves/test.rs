@@ -9,10 +9,10 @@ use crate::{
     cube_n::{
         moves::{
             perms::{self, pll},
-            QuarterAxisMove,
+            ExtendedAxisMove, QuarterAxisMove,
         },
         space::{Axis, Face},
-        AxisMove, Edge, WideAxisMove,
+        AxisMove, Cube4, Cube5, Cube6, Cube7, CubeN, Edge, Orientable, WideAxisMove,
     },
     generic::{alg::Alg, Cube, Movable, Parsable},
 };
@@ -20,6 +20,7 @@ use crate::{
 use super::{
     non_redundant::NonRedundantAxisMove,
     rotation::{AxisRotation, Rotatable},
+    slice::SliceMove,
     Amount,
 };
 
@@ -175,6 +176,22 @@ fn alg_and_inverse_solve_cube() {
     assert!(cube.is_solved());
 }
 
+#[test]
+fn axis_moves_turn_the_outer_layer() {
+    for face in Face::iter() {
+        let m = AxisMove::new(face, Amount::Single);
+
+        assert_eq!(
+            Cube4::SOLVED.moved(&m),
+            Cube4::SOLVED.moved(&m.clone().widen::<1>(0).unwrap())
+        );
+        assert_eq!(
+            Cube6::SOLVED.moved(&m),
+            Cube6::SOLVED.moved(&m.clone().widen::<2>(0).unwrap())
+        );
+    }
+}
+
 #[test]
 fn quarter_moves() {
     let alg: Alg<AxisMove> = Alg::random_with_rng(10, &mut StdRng::seed_from_u64(69420));
@@ -189,6 +206,17 @@ fn quarter_moves() {
     );
 }
 
+#[test]
+fn all_quarter_moves() {
+    let moves: Vec<_> = QuarterAxisMove::all().collect();
+
+    assert_eq!(moves.len(), 12);
+    assert!((0..moves.len()).all(|i| !moves[..i].contains(&moves[i])));
+    assert!(moves
+        .iter()
+        .all(|mov| AxisMove::from(mov).amount != Amount::Double));
+}
+
 #[test]
 fn non_redundant_of_axis() {
     let moves = NonRedundantAxisMove::of_axis(Axis::X).collect::<Vec<_>>();
@@ -221,7 +249,375 @@ fn non_redundant_given_last_axis() {
     }
 }
 
+#[test]
+fn simplifies_axis_moves() {
+    let simplified = |alg: &str| {
+        Alg::<AxisMove>::parse(alg)
+            .unwrap()
+            .simplified()
+            .to_string()
+    };
+
+    assert_eq!(simplified("R R"), "R2");
+    assert_eq!(simplified("R R'"), "");
+    assert_eq!(simplified("R L R'"), "L");
+    assert_eq!(simplified("R U U' R'"), "");
+    assert_eq!(simplified("R2 L R2 L"), "L2");
+    assert_eq!(simplified("R U R' U'"), "R U R' U'");
+    assert_eq!(simplified("F R R R R B"), "F B");
+    assert_eq!(simplified("U D U D' U2"), "");
+}
+
+#[test]
+fn simplified_algs_do_the_same() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..50 {
+        let alg = Alg::<AxisMove>::random_unnormalized_with_rng(30, &mut rng);
+        let simplified = alg.clone().simplified();
+
+        assert!(simplified.moves.len() <= alg.moves.len());
+        assert_eq!(Cube3::SOLVED.moved(&alg), Cube3::SOLVED.moved(&simplified));
+        assert_eq!(simplified.clone().simplified(), simplified);
+    }
+}
+
+#[test]
+fn simplifies_wide_moves() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    let alg = Alg::<WideAxisMove<1>>::parse("Rw R Rw L Rw2").unwrap();
+    assert_eq!(alg.simplified().to_string(), "R L");
+
+    for _ in 0..50 {
+        let alg = Alg::<WideAxisMove<1>>::random_unnormalized_with_rng(30, &mut rng);
+        let simplified = alg.clone().simplified();
+
+        assert!(simplified.moves.len() <= alg.moves.len());
+        assert_eq!(Cube4::SOLVED.moved(&alg), Cube4::SOLVED.moved(&simplified));
+    }
+}
+
+#[test]
+fn simplifies_quarter_moves() {
+    let alg: Alg<QuarterAxisMove> = Alg::<AxisMove>::parse("R2 L R' U").unwrap().into();
+
+    assert_eq!(alg.simplified().to_string(), "R L U");
+}
+
+#[test]
+fn simplifies_extended_moves() {
+    let simplified = |alg: &str| {
+        Alg::<ExtendedAxisMove>::parse(alg)
+            .unwrap()
+            .simplified()
+            .to_string()
+    };
+
+    assert_eq!(simplified("x R x'"), "R");
+    assert_eq!(simplified("M M' R"), "R");
+    assert_eq!(simplified("x y y'"), "x");
+    assert_eq!(simplified("y R y'"), "y R y'");
+    assert_eq!(simplified("M R M R' x2"), "M2 x2");
+    assert_eq!(simplified("Rw Rw R Lw"), "Rw2 R Lw");
+    assert_eq!(simplified("e e e"), "e'");
+
+    for alg in ["x R x'", "M R M R' x2", "y R U' Rw2 E y'", "S' F2 s"] {
+        let alg = Alg::<ExtendedAxisMove>::parse(alg).unwrap();
+
+        assert_eq!(
+            Orientable::new(Cube3::SOLVED).moved(&alg),
+            Orientable::new(Cube3::SOLVED).moved(&alg.clone().simplified()),
+        );
+    }
+}
+
+#[test]
+fn displays_extended_moves() {
+    for mov in [
+        "R", "R2", "x", "y'", "z2", "Rw", "Lw'", "M", "M'", "E2", "S", "s'", "m",
+    ] {
+        assert_eq!(ExtendedAxisMove::parse(mov).unwrap().to_string(), mov);
+    }
+}
+
 // #[test]
 // fn extended_moves() {
 //     todo!("parse extended moves")
 // }
+
+#[test]
+fn displays_notation_styles() {
+    use crate::cube_n::moves::notation::{DisplayNotation, Notation};
+
+    let cases = [
+        ("R", "R", "R"),
+        ("Rw'", "Rw'", "r'"),
+        ("3Rw2", "3Rw2", "3r2"),
+        ("4u", "4Uw", "4u"),
+        ("2R", "2R", "2R"),
+        ("3L2", "3L2", "3L2"),
+        ("2-3Rw'", "2-3Rw'", "2-3Rw'"),
+        ("3-4r", "3-4Rw", "3-4Rw"),
+        ("M2", "M2", "M2"),
+        ("x'", "x'", "x'"),
+    ];
+
+    for (input, wca, sign) in cases {
+        let mov = ExtendedAxisMove::parse(input).unwrap();
+
+        assert_eq!(mov.notated(Notation::Wca).to_string(), wca);
+        assert_eq!(mov.notated(Notation::Sign).to_string(), sign);
+        assert_eq!(mov.to_string(), wca);
+
+        // Every move is written as a single token, so it parses back to itself
+        assert_eq!(ExtendedAxisMove::parse(wca).unwrap(), mov);
+        assert_eq!(ExtendedAxisMove::parse(sign).unwrap(), mov);
+
+        // Both notations have to parse back to the same moves
+        for output in [wca, sign] {
+            let alg = Alg::<ExtendedAxisMove>::parse(output).unwrap();
+            assert!(
+                Orientable::new(Cube7::SOLVED).moved(&alg)
+                    == Orientable::new(Cube7::SOLVED).moved(&mov),
+                "{output} should be the same as {input}"
+            );
+        }
+    }
+
+    let alg = Alg::<WideAxisMove<2>>::parse("R 3Rw' u2").unwrap();
+    assert_eq!(alg.notated(Notation::Wca).to_string(), "R 3Rw' Uw2");
+    assert_eq!(alg.notated(Notation::Sign).to_string(), "R 3r' u2");
+}
+
+#[test]
+fn layer_moves_are_inner_slices() {
+    for (layers, equivalent) in [
+        ("2R", "Rw R'"),
+        ("2-3Rw", "3Rw R'"),
+        ("3-4Uw2", "4Uw2 Uw2"),
+        ("2-4Lw", "M"),
+        ("3R", "m'"),
+        ("3F", "s"),
+    ] {
+        let layers = Alg::<ExtendedAxisMove>::parse(layers).unwrap();
+        let equivalent = Alg::<ExtendedAxisMove>::parse(equivalent).unwrap();
+
+        assert!(
+            Orientable::new(Cube5::SOLVED).moved(&layers)
+                == Orientable::new(Cube5::SOLVED).moved(&equivalent),
+            "{layers} should be the same as {equivalent}"
+        );
+    }
+}
+
+#[test]
+fn axis_moves_only_move_outer_layer_of_big_cubes() {
+    let mov = AxisMove::new(Face::R, Amount::Inverse);
+    let wide = |depth| mov.clone().widen::<1>(depth).unwrap();
+
+    assert_eq!(Cube4::SOLVED.moved(&mov), Cube4::SOLVED.moved(&wide(0)));
+    assert_ne!(Cube4::SOLVED.moved(&mov), Cube4::SOLVED.moved(&wide(1)));
+
+    // Outer layer moves don't move any centers
+    let orders = Cube5::SOLVED.moved(&mov).piece_orders();
+    assert_eq!(orders.centers, Some(1));
+}
+
+#[test]
+fn slice_moves_are_wide_moves_minus_outer_layers() {
+    fn assert_slice<C, const W: u32>(slice: &str, wide: &str)
+    where
+        C: Cube + Movable<SliceMove<2>> + Movable<WideAxisMove<W>> + Debug,
+    {
+        let slice = Alg::<SliceMove<2>>::parse(slice).unwrap();
+        let wide = Alg::<WideAxisMove<W>>::parse(wide).unwrap();
+
+        assert_eq!(C::SOLVED.moved(&slice), C::SOLVED.moved(&wide));
+    }
+
+    assert_slice::<Cube4, 1>("2R", "Rw R'");
+    assert_slice::<Cube4, 1>("3R", "Lw' L");
+    assert_slice::<Cube5, 1>("2U2 2F'", "Uw2 U2 Fw' F");
+    assert_slice::<Cube6, 2>("2R 3U'", "Rw R' 3Uw' Uw");
+}
+
+#[test]
+fn middle_slices_of_odd_cubes() {
+    // The centers don't move, so the layers on both sides move the other way
+    let slice = Alg::<SliceMove<1>>::parse("2L 2U' 2F").unwrap();
+    let outer = Alg::<AxisMove>::parse("R L' U D' B F'").unwrap();
+    assert_eq!(Cube3::SOLVED.moved(&slice), Cube3::SOLVED.moved(&outer));
+
+    let slice = Alg::<SliceMove<3>>::parse("3R").unwrap();
+    let wide = Alg::<WideAxisMove<1>>::parse("Lw Rw'").unwrap();
+    assert_eq!(Cube5::SOLVED.moved(&slice), Cube5::SOLVED.moved(&wide));
+
+    // Orientable cubes keep track of the centers
+    for (slice, middle) in [("2L 2F 2L'", "M S M'"), ("2F2 2U' 2F2", "S2 E S2")] {
+        let slice = Alg::<SliceMove<1>>::parse(slice).unwrap();
+        let middle = Alg::<ExtendedAxisMove>::parse(middle).unwrap();
+
+        assert!(
+            Orientable::new(Cube3::SOLVED).moved(&slice)
+                == Orientable::new(Cube3::SOLVED).moved(&middle),
+            "{slice} should be the same as {middle}"
+        );
+    }
+
+    // On big cubes `M` turns every inner layer, while `m` only turns the middle one
+    let slice = Alg::<SliceMove<3>>::parse("3U 3F 3U' 2R").unwrap();
+    let middle = Alg::<ExtendedAxisMove>::parse("e' s e 2R").unwrap();
+    assert!(
+        Orientable::new(Cube5::SOLVED).moved(&slice)
+            == Orientable::new(Cube5::SOLVED).moved(&middle)
+    );
+}
+
+#[test]
+fn slice_moves_on_orientable_cubes() {
+    let alg = Alg::<SliceMove<5>>::parse("2R 4U' 3F2 5L").unwrap();
+    let extended = Alg::<ExtendedAxisMove>::new(
+        alg.moves
+            .iter()
+            .cloned()
+            .map(ExtendedAxisMove::from)
+            .collect(),
+    );
+
+    let cube = Orientable::new(Cube7::SOLVED).moved(&alg);
+    assert!(cube.moved(&extended.reversed()) == Orientable::new(Cube7::SOLVED));
+    assert_eq!(
+        Cube7::SOLVED.moved(&alg).moved(&alg.reversed()),
+        Cube7::SOLVED
+    );
+}
+
+#[test]
+fn displays_slice_moves() {
+    use crate::cube_n::moves::notation::{DisplayNotation, Notation};
+
+    let alg = Alg::<SliceMove<2>>::parse("2R 3U' 2F2").unwrap();
+
+    // Like the rest of the moves, the default is WCA notation, which borrows `SiGN` for inner layers
+    assert_eq!(alg.to_string(), "2R 3U' 2F2");
+    assert_eq!(alg.notated(Notation::Wca).to_string(), "2R 3U' 2F2");
+    assert_eq!(alg.notated(Notation::Sign).to_string(), "2R 3U' 2F2");
+}
+
+#[test]
+fn counts_moves_in_metrics() {
+    use super::metric::TurnMetric::{self, *};
+
+    let counts = |metric: TurnMetric| {
+        [
+            Alg::<AxisMove>::parse("R U2 R' D'").unwrap().count(metric),
+            Alg::<WideAxisMove<2>>::parse("3Rw2 U r'")
+                .unwrap()
+                .count(metric),
+            Alg::<SliceMove<2>>::parse("2R2 3U").unwrap().count(metric),
+            Alg::<ExtendedAxisMove>::parse("y M2 U' 2-3Rw E x2")
+                .unwrap()
+                .count(metric),
+        ]
+    };
+
+    assert_eq!(counts(Htm), [4, 3, 4, 7]);
+    assert_eq!(counts(Qtm), [5, 4, 6, 9]);
+    assert_eq!(counts(Stm), [4, 3, 2, 4]);
+    assert_eq!(counts(Etm), [4, 3, 2, 6]);
+    assert_eq!(counts(Qstm), [5, 4, 3, 5]);
+}
+
+#[test]
+fn mirrors_algs() {
+    use super::mirror::{MirrorPlane, Mirrorable};
+
+    let mirrored = |alg: &str, plane| {
+        Alg::<ExtendedAxisMove>::parse(alg)
+            .unwrap()
+            .mirrored(plane)
+            .to_string()
+    };
+
+    assert_eq!(
+        mirrored("R U R' U R U2 R'", MirrorPlane::M),
+        "L' U' L U' L' U2 L"
+    );
+    assert_eq!(
+        mirrored("r U M' x y 2R", MirrorPlane::M),
+        "Lw' U' M' x y' 2L'"
+    );
+    assert_eq!(mirrored("R U x E", MirrorPlane::E), "R' D' x' E");
+    assert_eq!(mirrored("F R S z M", MirrorPlane::S), "B' R' S z M'");
+
+    let alg = Alg::<AxisMove>::random_unnormalized_with_rng(30, &mut StdRng::seed_from_u64(69420));
+    let t_perm = Alg::<AxisMove>::parse("(R U R' U' R' F R2 U' R' U' R U R' F')2").unwrap();
+
+    for plane in [MirrorPlane::M, MirrorPlane::E, MirrorPlane::S] {
+        assert_eq!(alg.clone().mirrored(plane).mirrored(plane), alg);
+
+        // Mirroring keeps the order of the algs
+        assert!(Cube3::SOLVED
+            .moved(&t_perm.clone().mirrored(plane))
+            .is_solved());
+    }
+}
+
+#[test]
+fn reorients_algs() {
+    use crate::cube_n::{moves::rotation::Rotatable, Orientation};
+
+    assert_eq!(Orientation::all().count(), 24);
+
+    let alg = Alg::<ExtendedAxisMove>::parse("R U2 x' r M' 2-3Fw E y S2 D'").unwrap();
+
+    for orientation in Orientation::all() {
+        let rotations = Alg::<ExtendedAxisMove>::new(
+            orientation
+                .rotations()
+                .map(ExtendedAxisMove::Rotation)
+                .collect(),
+        );
+
+        let conjugated = Orientable::new(Cube5::SOLVED)
+            .moved(&rotations)
+            .moved(&alg)
+            .moved(&rotations.reversed());
+
+        let reoriented =
+            Orientable::new(Cube5::SOLVED).moved(&alg.clone().reoriented(&orientation));
+
+        assert!(conjugated == reoriented, "Orientation: {orientation:?}");
+    }
+}
+
+#[test]
+fn removes_rotations() {
+    fn assert_same_cube<C, const W: u32>(cube: C, alg: &Alg<ExtendedAxisMove>)
+    where
+        C: CubeN + Movable<WideAxisMove<W>> + Debug + 'static,
+        Orientable<C>: Movable<Alg<ExtendedAxisMove>>,
+    {
+        let (moves, orientation) = alg.without_rotations::<W>(C::N).unwrap();
+
+        let expected = Orientable::new(cube.clone()).moved(alg);
+        let base_cube = cube.moved(&moves);
+
+        assert!(expected.base_cube == base_cube, "{}x{}: {alg}", C::N, C::N);
+        assert_eq!(expected.orientation, orientation);
+    }
+
+    let alg = Alg::<ExtendedAxisMove>::parse("x R U2 y' M' r E2 2-3Fw z' S 3Uw D' b x2 L").unwrap();
+    let scramble =
+        Alg::<AxisMove>::random_unnormalized_with_rng(30, &mut StdRng::seed_from_u64(69420));
+
+    assert_same_cube::<_, 0>(Cube3::SOLVED.moved(&scramble), &alg);
+    assert_same_cube::<_, 1>(Cube4::SOLVED.moved(&scramble), &alg);
+    assert_same_cube::<_, 1>(Cube5::SOLVED.moved(&scramble), &alg);
+    assert_same_cube::<_, 2>(Cube6::SOLVED.moved(&scramble), &alg);
+    assert_same_cube::<_, 2>(Cube7::SOLVED.moved(&scramble), &alg);
+
+    // The wide moves of big cubes don't fit in regular moves
+    assert!(alg.without_rotations::<0>(5).is_err());
+}
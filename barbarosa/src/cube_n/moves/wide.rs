@@ -5,15 +5,20 @@ use std::{mem, ops::Deref};
 use rand::prelude::Distribution;
 use thiserror::Error;
 
-use crate::cube_n::space::Face;
+use crate::cube_n::space::{Axis, Face};
 pub use crate::generic::{
     self,
     parse::{self, Parsable},
 };
 
-use self::generic::{piece::PieceSetDescriptor, Alg, Cube, Movable, Piece, PieceSet};
+use self::generic::{
+    moves::Mergeable,
+    piece::{OrientedPiece, PieceSetDescriptor},
+    Alg, Cube, Movable, Piece, PieceSet, StateAlgebra,
+};
 
 use super::{
+    notation::{DisplayNotation, Notation},
     rotation::{AxisRotation, Rotatable},
     Amount, AxisMove,
 };
@@ -95,27 +100,84 @@ impl<const N: u32> WideAxisMove<N> {
     }
 }
 
+/// A [`WideAxisMove`] with any maximum depth. This is used to name the widest moves of a cube in
+/// [`CubeN::WideMove`](crate::cube_n::CubeN::WideMove).
+pub trait WideMove: generic::Move {
+    /// Converts a wide move of any maximum depth into `Self`.
+    ///
+    /// Fails if the depth of the move is greater than the maximum depth of `Self`.
+    fn from_wide<const N: u32>(m: WideAxisMove<N>) -> Result<Self, WideMoveCreationError>;
+}
+
+impl<const M: u32> WideMove for WideAxisMove<M> {
+    fn from_wide<const N: u32>(m: WideAxisMove<N>) -> Result<Self, WideMoveCreationError> {
+        m.set_max_depth()
+    }
+}
+
+impl<const N: u32> Mergeable for WideAxisMove<N> {
+    type Axis = Axis;
+
+    fn axis(&self) -> Axis {
+        self.face().axis
+    }
+
+    fn merge(&self, other: &Self) -> Option<Option<Self>> {
+        if self.depth != other.depth {
+            return None;
+        }
+
+        let merged = self.axis_move.merge(&other.axis_move)?;
+
+        Some(merged.map(|axis_move| Self {
+            depth: self.depth,
+            axis_move,
+        }))
+    }
+}
+
 /// An error if the depth of a wide move is greater than `N`.
 #[derive(Debug, Error)]
 #[allow(missing_docs)]
 pub enum WideMoveCreationError {
     #[error("Exceded maximum depth (given: {0}, max: {1})")]
     ExcededDepth(u32, u32),
+
+    #[error("Layers are numbered starting at 1 (given: {0})")]
+    ZeroLayer(u32),
+
+    #[error("Invalid range of layers (given: {0}-{1})")]
+    InvalidLayerRange(u32, u32),
+
+    #[error("Slice moves can only move inner layers (given depth: {0})")]
+    OuterLayer(u32),
+}
+
+impl<const N: u32> DisplayNotation for WideAxisMove<N> {
+    fn fmt_notation(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        notation: Notation,
+    ) -> std::fmt::Result {
+        let AxisMove { face, amount } = &self.axis_move;
+
+        // The number of layers, which is omitted for two layers
+        let layers = match self.depth {
+            0 => return write!(f, "{}", self.axis_move),
+            1 => String::new(),
+            depth => (depth + 1).to_string(),
+        };
+
+        match notation {
+            Notation::Wca => write!(f, "{layers}{face}w{amount}"),
+            Notation::Sign => write!(f, "{layers}{}{amount}", face.to_string().to_lowercase()),
+        }
+    }
 }
 
 impl<const N: u32> std::fmt::Display for WideAxisMove<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.depth {
-            0 => write!(f, "{}", self.axis_move),
-            1 => write!(f, "{}w{}", self.axis_move.face, self.axis_move.amount),
-            i => write!(
-                f,
-                "{}{}w{}",
-                i + 1,
-                self.axis_move.face,
-                self.axis_move.amount
-            ),
-        }
+        self.fmt_notation(f, Notation::default())
     }
 }
 
@@ -135,7 +197,7 @@ macro_rules! impl_movable_wide_move_inductively {
     ($cube:ty, $max_width:literal, 0) => {
         impl crate::generic::Movable<crate::cube_n::AxisMove> for $cube {
             fn apply(&mut self, m: &crate::cube_n::AxisMove) {
-                <$cube as crate::generic::Movable<WideAxisMove<$max_width>>>::apply(self, &m.clone().widen($max_width).unwrap());
+                <$cube as crate::generic::Movable<WideAxisMove<$max_width>>>::apply(self, &m.clone().widen(0).unwrap());
             }
         }
     };
@@ -182,6 +244,20 @@ impl Alg<AxisMove> {
     }
 }
 
+/// Outer layer moves, which do the same on any cube. This lets the solutions of a 3x3x3 be used on a reduced big
+/// cube (see [`crate::cube_n::reduction`]).
+impl<const N: u32> From<Alg<AxisMove>> for Alg<WideAxisMove<N>> {
+    fn from(alg: Alg<AxisMove>) -> Self {
+        alg.widen(0).expect("Depth 0 is always valid")
+    }
+}
+
+impl From<Alg<WideAxisMove<0>>> for Alg<AxisMove> {
+    fn from(alg: Alg<WideAxisMove<0>>) -> Self {
+        alg.moves.into_iter().map(|m| m.axis_move).collect()
+    }
+}
+
 /// A piece that can be moved by a wide move
 pub trait DepthPiece: Piece {
     /// Whether the piece is in the wide move if it has the given normal and tangent depth.
@@ -242,3 +318,31 @@ impl<P: DepthPiece + PieceSetDescriptor<N>, const N: usize, const ND: u32, const
         &self.set
     }
 }
+
+impl<
+        P: DepthPiece + OrientedPiece + PieceSetDescriptor<N>,
+        const N: usize,
+        const ND: u32,
+        const TD: u32,
+    > StateAlgebra for DepthPieceSet<P, N, ND, TD>
+{
+    fn compose(&self, other: &Self) -> Self {
+        Self {
+            set: self.set.compose(&other.set),
+        }
+    }
+
+    fn inverse(&self) -> Self {
+        Self {
+            set: self.set.inverse(),
+        }
+    }
+
+    fn order(&self) -> usize {
+        self.set.order()
+    }
+
+    fn period(&self) -> usize {
+        self.set.period()
+    }
+}
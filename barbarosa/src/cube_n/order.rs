@@ -0,0 +1,76 @@
+//! Orders of `NxNxN` states and algs, split by piece type.
+//!
+//! See [`StateAlgebra`] for the order and period of a whole cube.
+
+use crate::generic::{moves::AsMove, Alg, Movable, StateAlgebra};
+
+use super::CubeN;
+
+/// The order of each type of piece of an `NxNxN` state. That is, how many times it has to be
+/// repeated for that type of piece to get back to solved.
+///
+/// Pieces that come in multiple orbits (such as the wings in a 6x6) are combined into a single
+/// order. If the cube doesn't have a piece type, its order is `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PieceOrders {
+    /// The order of the corners
+    pub corners: usize,
+
+    /// The order of the (middle) edges. Only on odd cubes.
+    pub edges: Option<usize>,
+
+    /// The order of the wings, across all orbits. Only on 4x4x4 and up.
+    pub wings: Option<usize>,
+
+    /// The order of the centers, across all types of centers. Only on 4x4x4 and up.
+    ///
+    /// Centers of the same color are indistinguishable, so this is the smallest number of repetitions
+    /// that leaves every center on its face, and not necessarily on its original position.
+    pub centers: Option<usize>,
+}
+
+impl<T: AsMove> Alg<T> {
+    /// The number of times the alg has to be repeated on the cube `C` to get back to solved. That is, the
+    /// [period](StateAlgebra::period) of the state it leads to.
+    ///
+    /// This is computed through the state algebra (see [`StateAlgebra`]), so it doesn't actually
+    /// apply the alg more than once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use barbarosa::prelude::*;
+    /// use barbarosa::cube_n::{moves::perms::{self, pll}, Cube4};
+    ///
+    /// assert_eq!(perms::SEXY_MOVE.order::<Cube3>(), 6);
+    /// assert_eq!(pll::T.order::<Cube3>(), 2);
+    /// assert_eq!(pll::T.clone().widen::<1>(1).unwrap().order::<Cube4>(), 2);
+    /// ```
+    pub fn order<C>(&self) -> usize
+    where
+        C: CubeN + StateAlgebra + Movable<T::Move> + 'static,
+    {
+        C::SOLVED.moved(self).period()
+    }
+
+    /// The order of each type of piece after doing the alg on the cube `C`. See [`PieceOrders`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use barbarosa::prelude::*;
+    /// use barbarosa::cube_n::moves::perms;
+    ///
+    /// let orders = perms::SEXY_MOVE.piece_orders::<Cube3>();
+    ///
+    /// assert_eq!(orders.corners, 6);
+    /// assert_eq!(orders.edges, Some(3));
+    /// assert_eq!(orders.wings, None);
+    /// ```
+    pub fn piece_orders<C>(&self) -> PieceOrders
+    where
+        C: CubeN + Movable<T::Move> + 'static,
+    {
+        C::SOLVED.moved(self).piece_orders()
+    }
+}
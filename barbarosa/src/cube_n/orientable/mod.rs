@@ -4,19 +4,16 @@ mod test;
 
 pub use orientation::Orientation;
 
-use crate::generic::{self, moves::AsMove, Movable, Move};
+use crate::generic::{self, moves::AsMove, Movable};
 
 use super::{
     moves::{rotation::Rotatable, ExtendedAxisMove},
-    CubeN,
+    AxisMove, CubeN,
 };
 
 /// A cube that can be oriented. This means that you can rotate it, do slice moves and such.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Orientable<C: CubeN>
-where
-    Self: Movable<ExtendedAxisMove>,
-{
+pub struct Orientable<C: CubeN> {
     /// The original cube
     pub base_cube: C,
 
@@ -24,10 +21,7 @@ where
     pub orientation: Orientation,
 }
 
-impl<C: CubeN + 'static> Orientable<C>
-where
-    Self: Movable<ExtendedAxisMove>,
-{
+impl<C: CubeN + 'static> Orientable<C> {
     /// Creates a new orientable cube with the default orientation.
     pub const fn new(cube: C) -> Self {
         Self {
@@ -37,52 +31,34 @@ where
     }
 }
 
+/// Convenience trait to wrap a cube into an [`Orientable`] with method syntax.
 // Trait because otherwise we can't implement this method
-trait IntoOrientable
-where
-    Self: CubeN + 'static,
-    Orientable<Self>: Movable<ExtendedAxisMove>,
-{
+pub trait IntoOrientable: CubeN + 'static {
+    /// Wraps the cube into an [`Orientable`] with the default orientation.
     fn orientable(self) -> Orientable<Self> {
         Orientable::new(self)
     }
 }
 
-impl<C: CubeN + 'static> IntoOrientable for C where Orientable<C>: Movable<ExtendedAxisMove> {}
+impl<C: CubeN + 'static> IntoOrientable for C {}
 
-impl<M: Move + Rotatable, C: CubeN + Movable<M>> Movable<M> for Orientable<C>
-where
-    Orientable<C>: Movable<ExtendedAxisMove>,
-{
-    fn apply(&mut self, m: &M) {
-        let mut m = m.clone();
-
-        for rotation in self.orientation.rotations() {
-            m.rotate(&rotation);
-        }
-
-        self.base_cube.apply(&m);
+/// Moves are reoriented, so that they turn the face that is in their direction now.
+impl<C: CubeN + Movable<AxisMove>> Movable<AxisMove> for Orientable<C> {
+    fn apply(&mut self, m: &AxisMove) {
+        self.base_cube
+            .apply(&m.clone().reoriented(&self.orientation));
     }
 }
 
-impl<C: CubeN + 'static> generic::Cube for Orientable<C>
-where
-    Orientable<C>: Movable<ExtendedAxisMove>,
-{
+impl<C: CubeN + 'static> generic::Cube for Orientable<C> {
     const SOLVED: Self = Orientable::new(C::SOLVED);
 }
 
-impl<C: CubeN> AsMove for Orientable<C>
-where
-    Orientable<C>: Movable<ExtendedAxisMove>,
-{
+impl<C: CubeN> AsMove for Orientable<C> {
     type Move = ExtendedAxisMove;
 }
 
-impl<C: CubeN + 'static> From<C> for Orientable<C>
-where
-    Orientable<C>: Movable<ExtendedAxisMove>,
-{
+impl<C: CubeN + 'static> From<C> for Orientable<C> {
     fn from(value: C) -> Self {
         Self::new(value)
     }
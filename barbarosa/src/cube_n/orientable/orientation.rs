@@ -38,22 +38,17 @@ fn connecting_rotation(from: &Face, to: &Face) -> Option<AxisRotation> {
     Some(output)
 }
 
-/// Gives incorrect results if the faces cannot be reached by a rotation around the axis of `face`.
+/// Finds the rotation around the axis of `face` that takes `from` to `to`.
+///
+/// Returns `None` if `from` and `to` are equal or if they can't be connected by such a rotation.
 fn connecting_rotation_with_face(from: &Face, to: &Face, face: &Face) -> Option<AxisRotation> {
     debug_assert_ne!(from.axis, face.axis);
     debug_assert_ne!(to.axis, face.axis);
 
-    if from == to {
-        return None;
-    }
-
-    let amount = match from.axis.get_handedness(&to.axis) {
-        Ok(Direction::Positive) => Amount::Single,
-        Ok(Direction::Negative) => Amount::Inverse,
-        Err(ParallelAxesError::SameAxis(_)) => Amount::Double,
-    };
-
-    Some(AxisRotation::new(face.axis, amount))
+    [Amount::Single, Amount::Double, Amount::Inverse]
+        .into_iter()
+        .map(|amount| AxisRotation::new(face.axis, amount))
+        .find(|rotation| from.clone().rotated(rotation) == *to)
 }
 
 impl Orientation {
@@ -69,19 +64,15 @@ impl Orientation {
     }
 
     /// Iterator of rotations that get you from the default orientation to the current one
-    pub fn rotations(&self) -> impl Iterator<Item = AxisRotation> {
+    pub fn rotations(&self) -> impl DoubleEndedIterator<Item = AxisRotation> {
         let r_rot = connecting_rotation(&Face::R, &self.r_face);
         let new_u = r_rot
             .as_ref()
             .map(|rot| Face::U.rotated(rot))
             .unwrap_or(Face::U);
 
-        let r_face = r_rot.as_ref().map(|rot| Face::R.rotated(rot));
-
-        let u_rot = match r_face {
-            Some(r_face) => connecting_rotation_with_face(&new_u, &self.u_face(), &r_face),
-            None => connecting_rotation(&new_u, &self.u_face()),
-        };
+        // The second rotation has to keep the R face in place
+        let u_rot = connecting_rotation_with_face(&new_u, &self.u_face(), &self.r_face);
 
         use std::iter::{empty, once};
         use Either::*;
@@ -93,6 +84,20 @@ impl Orientation {
         }
     }
 
+    /// Iterator of rotations that get you from the current orientation back to the default one.
+    ///
+    /// Rotating a move with these translates it from the current orientation to the base cube.
+    pub fn inverse_rotations(&self) -> impl Iterator<Item = AxisRotation> {
+        self.rotations().rev().map(|rotation| rotation.inverse())
+    }
+
+    /// Iterator over the 24 possible orientations of the cube
+    pub fn all() -> impl Iterator<Item = Self> {
+        Face::iter().flat_map(|r_face| {
+            Face::iter().filter_map(move |u_face| Self::try_from([r_face.clone(), u_face]).ok())
+        })
+    }
+
     pub(super) const fn const_default() -> Self {
         Self {
             r_face: Face::R,
@@ -3,7 +3,12 @@
 use crate::{
     cube3::Cube3,
     cube_n::{
-        moves::{perms::SEXY_MOVE, rotation::AxisRotation, wide::Parsable, Amount},
+        moves::{
+            perms::SEXY_MOVE,
+            rotation::{AxisRotation, Rotatable},
+            wide::Parsable,
+            Amount,
+        },
         space::{Axis, Face},
         AxisMove,
     },
@@ -64,3 +69,109 @@ fn moves_rotated() {
 
     assert_eq!(cube.base_cube, expected)
 }
+
+#[test]
+fn rotations_reach_orientation() {
+    for r_face in Face::iter() {
+        for u_face in Face::iter() {
+            let Ok(orientation) = Orientation::try_from([r_face.clone(), u_face]) else {
+                continue;
+            };
+
+            let mut rotated = Orientation::default();
+            for rotation in orientation.rotations() {
+                rotated.rotate(&rotation);
+            }
+
+            assert_eq!(rotated, orientation);
+        }
+    }
+}
+
+#[test]
+fn slices_are_wide_moves_and_rotations() {
+    use crate::cube_n::moves::ExtendedAxisMove;
+
+    for (slice, equivalent) in [
+        ("M", "R L' x'"),
+        ("E", "U D' y'"),
+        ("S", "F' B z"),
+        ("y M U", "y R L' x' U"),
+        ("x2 R", "R x2"),
+        ("y Rw", "y L x"),
+    ] {
+        let slice = Alg::<ExtendedAxisMove>::parse(slice).unwrap();
+        let equivalent = Alg::<ExtendedAxisMove>::parse(equivalent).unwrap();
+
+        assert_eq!(
+            Cube3::SOLVED.orientable().moved(&slice),
+            Cube3::SOLVED.orientable().moved(&equivalent),
+        );
+    }
+}
+
+#[test]
+fn rotations_conjugate_moves() {
+    use crate::cube_n::moves::ExtendedAxisMove;
+
+    for (conjugate, mov) in [
+        ("x U x'", "F"),
+        ("y R y'", "B"),
+        ("z U z'", "L"),
+        ("y x U x' y'", "R"),
+        ("x y R y' x'", "U"),
+        ("y' x U x' y", "L"),
+    ] {
+        let conjugate = Alg::<ExtendedAxisMove>::parse(conjugate).unwrap();
+        let mov = Alg::<AxisMove>::parse(mov).unwrap();
+
+        assert_eq!(
+            Cube3::SOLVED.orientable().moved(&conjugate),
+            Cube3::SOLVED.orientable().moved(&mov),
+        );
+    }
+}
+
+#[test]
+fn every_cube_is_orientable() {
+    use crate::cube_n::{moves::ExtendedAxisMove, Cube2, Cube4, Cube5, Cube6, Cube7};
+
+    fn assert_orientable<C: CubeN + IntoOrientable + std::fmt::Debug>()
+    where
+        Orientable<C>: Movable<Alg<ExtendedAxisMove>>,
+    {
+        let alg = Alg::<ExtendedAxisMove>::parse("x R U' y M2 3Rw' 2-3Uw E' z2 r2 S").unwrap();
+
+        let cube = C::SOLVED.orientable().moved(&alg);
+        assert!(cube != C::SOLVED.orientable(), "{}x{}", C::N, C::N);
+        assert_eq!(cube.moved(&alg.reversed()), C::SOLVED.orientable());
+
+        // Rotating the whole cube with a wide move only changes the orientation
+        let wide = Alg::<ExtendedAxisMove>::parse(&format!("{}Rw", C::N)).unwrap();
+        let rotated = C::SOLVED.orientable().moved(&wide);
+        assert_eq!(rotated.base_cube, C::SOLVED);
+        assert_eq!(
+            rotated.orientation,
+            Orientation::try_from([Face::R, Face::B]).unwrap()
+        );
+    }
+
+    assert_orientable::<Cube2>();
+    assert_orientable::<Cube3>();
+    assert_orientable::<Cube4>();
+    assert_orientable::<Cube5>();
+    assert_orientable::<Cube6>();
+    assert_orientable::<Cube7>();
+}
+
+#[test]
+fn base_cube_moves_are_reoriented() {
+    use crate::cube_n::{moves::ExtendedAxisMove, Cube4};
+
+    let mut cube = Cube4::SOLVED.orientable();
+    cube.apply(&Alg::<ExtendedAxisMove>::parse("y").unwrap());
+    cube.apply(&Alg::<AxisMove>::parse("R U'").unwrap());
+
+    let expected = Alg::<ExtendedAxisMove>::parse("y R U'").unwrap();
+    assert_eq!(cube, Cube4::SOLVED.orientable().moved(&expected));
+}
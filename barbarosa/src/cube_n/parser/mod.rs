@@ -8,7 +8,9 @@ use crate::generic::parse::{FromPest, IntoParseErr, Parsable, ParseError};
 type Result<T> = std::result::Result<T, ParseError<Rule>>;
 
 use super::{
-    moves::{rotation::AxisRotation, wide::WideMoveCreationError, Amount, ExtendedAxisMove},
+    moves::{
+        rotation::AxisRotation, wide::WideMoveCreationError, Amount, ExtendedAxisMove, SliceMove,
+    },
     space::{Axis, Direction, Face},
     AxisMove, WideAxisMove,
 };
@@ -76,10 +78,26 @@ impl_with_current_rule! {
 
 impl_with_current_rule! {
     u32;
-    Rule::depth;
+    Rule::layer;
 
     |pair| {
-        if pair.as_str().is_empty() { Ok(0) } else { Ok(pair.as_str().parse::<u32>()?) }
+        match pair.as_str().parse::<u32>()? {
+            0 => Err(ParseError::uknown(WideMoveCreationError::ZeroLayer(0))),
+            layer => Ok(layer),
+        }
+    }
+}
+
+/// Parses the depth of a wide move, which is written as the amount of layers (so `3Rw` has depth 2).
+///
+/// If the number of layers is omitted, the move is two layers wide (`Rw` or `r`).
+fn parse_depth(pair: Pair<Rule>) -> Result<u32> {
+    match pair.as_str() {
+        "" => Ok(1),
+        layers => match layers.parse::<u32>()? {
+            0 => Err(ParseError::uknown(WideMoveCreationError::ZeroLayer(0))),
+            layers => Ok(layers - 1),
+        },
     }
 }
 
@@ -114,7 +132,7 @@ impl<const N: u32> FromPest for WideAxisMove<N> {
             Rule::face => (Face::from_pest(first)?, 0),
             Rule::depth => (
                 parse_face_wide(inner.next().into_err()?)?,
-                u32::from_pest(first)?.max(1),
+                parse_depth(first)?,
             ),
             _ => return Err(ParseError::Unreachable(first.as_str().to_string())),
         };
@@ -182,6 +200,58 @@ fn parse_slice_move(pair: Pair<Rule>) -> Result<(AxisRotation, bool)> {
     Ok((AxisRotation::new(axis, amount), wide))
 }
 
+/// Parses a `SiGN` layer move (`2R` or `2-3Rw`), returning the face, the amount and the range of depths.
+fn parse_layer_move(pair: Pair<Rule>) -> Result<(Face, Amount, u32, u32)> {
+    let mut inner = pair.into_inner();
+    let first = inner.next().into_err()?;
+
+    let (face, start, end) = match first.as_rule() {
+        Rule::layer => {
+            let layer = u32::from_pest(first)?;
+            (Face::from_pest(inner.next().into_err()?)?, layer, layer)
+        }
+        Rule::layer_range => {
+            let mut range = first.into_inner();
+            let start = u32::from_pest(range.next().into_err()?)?;
+            let end = u32::from_pest(range.next().into_err()?)?;
+
+            if start > end {
+                return Err(ParseError::uknown(
+                    WideMoveCreationError::InvalidLayerRange(start, end),
+                ));
+            }
+
+            (parse_face_wide(inner.next().into_err()?)?, start, end)
+        }
+        _ => return Err(ParseError::Unreachable(first.as_str().to_string())),
+    };
+
+    let amount = Amount::from_pest(inner.next().into_err()?)?;
+
+    Ok((face, amount, start - 1, end - 1))
+}
+
+impl<const N: u32> FromPest for SliceMove<N> {
+    type Rule = Rule;
+    type Parser = CubeNParser;
+
+    fn rule() -> Self::Rule {
+        Rule::layer_move
+    }
+
+    fn from_pest(pair: Pair<Self::Rule>) -> Result<Self> {
+        let (face, amount, start, end) = parse_layer_move(pair)?;
+
+        if start != end {
+            return Err(ParseError::uknown(
+                WideMoveCreationError::InvalidLayerRange(start + 1, end + 1),
+            ));
+        }
+
+        SliceMove::new(AxisMove::new(face, amount), start).map_err(ParseError::uknown)
+    }
+}
+
 impl_with_current_rule! {
     ExtendedAxisMove;
     Rule::extended_move;
@@ -198,6 +268,10 @@ impl_with_current_rule! {
                     _ => ExtendedAxisMove::Wide(mov),
                 }
             },
+            Rule::layer_move => {
+                let (face, amount, start, end) = parse_layer_move(pair)?;
+                ExtendedAxisMove::layers(AxisMove::new(face, amount), start, end)
+            },
             Rule::rotation => ExtendedAxisMove::Rotation(AxisRotation::from_pest(pair)?),
             Rule::slice_move => {
                 let (rot, wide) = parse_slice_move(pair)?;
@@ -3,7 +3,11 @@
 use pest::Parser;
 
 use crate::cube_n::{
-    moves::{rotation::AxisRotation, wide::Parsable, Amount, ExtendedAxisMove},
+    moves::{
+        rotation::AxisRotation,
+        wide::{Parsable, WideMoveCreationError},
+        Amount, ExtendedAxisMove, SliceMove,
+    },
     parser::{parse_slice_move, CubeNParser, Rule},
     space::Face,
     AxisMove, WideAxisMove,
@@ -68,12 +72,73 @@ fn wide_moves() {
 
     assert_move("r'", Face::R, Amount::Inverse, 1);
 
-    assert_move("2r'", Face::R, Amount::Inverse, 2);
-    assert_move("2Rw'", Face::R, Amount::Inverse, 2);
+    // In WCA notation the number is the amount of layers that turn, so `2Rw` turns 2 layers like `Rw`
+    assert_move("2r'", Face::R, Amount::Inverse, 1);
+    assert_move("2Rw'", Face::R, Amount::Inverse, 1);
+    assert_move("3Rw", Face::R, Amount::Single, 2);
+    assert_move("3u2", Face::U, Amount::Double, 2);
 
     assert!(WideAxisMove::<2>::parse("Rw3").is_err());
     assert!(WideAxisMove::<2>::parse("rw").is_err());
-    assert!(WideAxisMove::<2>::parse("3Rw").is_err());
+    assert!(WideAxisMove::<2>::parse("4Rw").is_err());
+    assert!(WideAxisMove::<1>::parse("3Rw").is_err());
+    assert!(WideAxisMove::<2>::parse("0Rw").is_err());
+    assert!(WideAxisMove::<2>::parse("2R").is_err());
+}
+
+#[test]
+fn layer_moves() {
+    let assert_layers = |s: &str, face: Face, amount: Amount, start: u32, end: u32| {
+        assert_eq!(
+            ExtendedAxisMove::parse(s).unwrap(),
+            ExtendedAxisMove::Layers {
+                axis_move: AxisMove::new(face, amount),
+                start,
+                end
+            }
+        )
+    };
+
+    assert_layers("2R", Face::R, Amount::Single, 1, 1);
+    assert_layers("3U'", Face::U, Amount::Inverse, 2, 2);
+    assert_layers("2-3Rw", Face::R, Amount::Single, 1, 2);
+    assert_layers("2-3r2", Face::R, Amount::Double, 1, 2);
+    assert_layers("3-4f'", Face::F, Amount::Inverse, 2, 3);
+    assert_layers("10-12Lw", Face::L, Amount::Single, 9, 11);
+
+    // Ranges starting at the outer layer are just wide moves
+    assert_eq!(
+        ExtendedAxisMove::parse("1-3Rw").unwrap(),
+        ExtendedAxisMove::parse("3Rw").unwrap()
+    );
+    assert_eq!(
+        ExtendedAxisMove::parse("1R2").unwrap(),
+        ExtendedAxisMove::parse("R2").unwrap()
+    );
+
+    assert!(ExtendedAxisMove::parse("3-2Rw").is_err());
+    assert!(ExtendedAxisMove::parse("0R").is_err());
+    assert!(ExtendedAxisMove::parse("2-3R").is_err());
+    assert!(ExtendedAxisMove::parse("2Rw3").is_err());
+}
+
+#[test]
+fn slice_moves() {
+    let slice = SliceMove::<2>::parse("3U'").unwrap();
+    assert_eq!(slice.axis_move, AxisMove::new(Face::U, Amount::Inverse));
+    assert_eq!(slice.depth(), 2);
+    assert_eq!(SliceMove::<2>::parse("2R2").unwrap().depth(), 1);
+
+    // Only single inner layers
+    assert!(SliceMove::<2>::parse("1R").is_err());
+    assert!(SliceMove::<2>::parse("2-3Rw").is_err());
+    assert!(SliceMove::<2>::parse("4R").is_err());
+    assert!(SliceMove::<2>::parse("R").is_err());
+
+    assert!(matches!(
+        SliceMove::<2>::new(AxisMove::new(Face::R, Amount::Single), 0),
+        Err(WideMoveCreationError::OuterLayer(0))
+    ));
 }
 
 #[test]
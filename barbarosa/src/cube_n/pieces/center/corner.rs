@@ -12,7 +12,10 @@ use crate::{
         space::{Axis, Direction},
         Vec3, WideAxisMove,
     },
-    generic::{self, piece::PieceSetDescriptor},
+    generic::{
+        self,
+        piece::{OrientedPiece, PieceSetDescriptor},
+    },
 };
 
 /// A center corner piece of the cube. There are 4 of these in each face of a cube.
@@ -59,6 +62,18 @@ impl PieceSetDescriptor<24> for CenterCorner {
     const REFERENCE_POSITIONS: [Self::Position; 24] = Self::SOLVED;
 }
 
+impl OrientedPiece for CenterCorner {
+    const ORIENTATION_COUNT: u8 = 1;
+
+    fn orientation(&self) -> u8 {
+        0
+    }
+
+    fn with_orientation(position: Self::Position, _orientation: u8) -> Self {
+        position
+    }
+}
+
 impl Rotatable for CenterCorner {
     fn rotate(&mut self, rotation: &AxisRotation) {
         self.position.rotate(rotation);
@@ -14,7 +14,10 @@ use crate::{
         space::{faces, Axis, Direction, Face},
         WideAxisMove,
     },
-    generic::{self, piece::PieceSetDescriptor},
+    generic::{
+        self,
+        piece::{OrientedPiece, PieceSetDescriptor},
+    },
 };
 
 /// A center edge piece of the cube. There are 4 of these in each face of a cube.
@@ -62,6 +65,18 @@ impl PieceSetDescriptor<24> for CenterEdge {
     const SOLVED: [Self; 24] = Self::REFERENCE_POSITIONS;
 }
 
+impl OrientedPiece for CenterEdge {
+    const ORIENTATION_COUNT: u8 = 1;
+
+    fn orientation(&self) -> u8 {
+        0
+    }
+
+    fn with_orientation(position: Self::Position, _orientation: u8) -> Self {
+        position
+    }
+}
+
 impl Rotatable for CenterEdge {
     fn rotate(&mut self, rotation: &AxisRotation) {
         let side_face = self.side_face().rotated(rotation);
@@ -133,7 +148,7 @@ impl DepthPiece for CenterEdge {
             return true;
         }
 
-        if m.face() == &self.side_face() && normal_depth <= M {
+        if m.face() == &self.side_face() && normal_depth <= m.depth() {
             return true;
         }
 
@@ -13,7 +13,10 @@ use crate::{
         space::{faces, Axis, Direction, Face},
         WideAxisMove,
     },
-    generic::{self, piece::PieceSetDescriptor},
+    generic::{
+        self,
+        piece::{OrientedPiece, PieceSetDescriptor},
+    },
 };
 
 use super::edge::CenterEdge;
@@ -57,6 +60,18 @@ impl PieceSetDescriptor<48> for CenterWing {
     const SOLVED: [Self; 48] = Self::REFERENCE_POSITIONS;
 }
 
+impl OrientedPiece for CenterWing {
+    const ORIENTATION_COUNT: u8 = 1;
+
+    fn orientation(&self) -> u8 {
+        0
+    }
+
+    fn with_orientation(position: Self::Position, _orientation: u8) -> Self {
+        position
+    }
+}
+
 impl Rotatable for CenterWing {
     fn rotate(&mut self, rotation: &crate::cube_n::moves::rotation::AxisRotation) {
         self.corresponding_center_edge.rotate(rotation);
@@ -128,6 +143,19 @@ impl CenterWing {
             self.pseudo_oriented,
         )
     }
+
+    /// Whether the piece is left or right-handed. Center-wings can only move to positions with the same
+    /// chirality, so each [`CenterWingSet`] is actually two separate orbits of 24 pieces.
+    pub fn chirality(&self) -> Direction {
+        let main = self.main_face().coords();
+        let side = self.side_face().coords();
+        let normal = Face::new(self.normal_axis(), self.normal_direction()).coords();
+
+        match main.cross(&side).dot(&normal) > 0 {
+            true => Direction::Positive,
+            false => Direction::Negative,
+        }
+    }
 }
 
 impl std::fmt::Debug for CenterWing {
@@ -12,7 +12,10 @@ use crate::{
         AxisMove, Vec3,
     },
     generic::{
-        self, moves::impl_movable_array, piece::PieceSetDescriptor, utils::map_array_const,
+        self,
+        moves::impl_movable_array,
+        piece::{OrientedPiece, PieceNotation, PieceSetDescriptor},
+        utils::map_array_const,
         PieceSet,
     },
 };
@@ -71,6 +74,32 @@ impl generic::Movable<AxisMove> for Corner {
 
 impl_movable_array!(Corner, AxisMove);
 
+impl OrientedPiece for Corner {
+    const ORIENTATION_COUNT: u8 = 3;
+
+    fn orientation(&self) -> u8 {
+        self.orientation_index() as u8
+    }
+
+    fn with_orientation(position: Self::Position, orientation: u8) -> Self {
+        let mut corner = Corner::oriented(position);
+
+        for _ in 0..orientation {
+            corner.twist();
+        }
+
+        corner
+    }
+}
+
+impl PieceNotation for Corner {
+    fn notation(position: &Self::Position) -> String {
+        [Axis::Y, Axis::Z, Axis::X]
+            .map(|axis| Face::new(axis, position[axis]).to_string())
+            .concat()
+    }
+}
+
 impl generic::piece::Coordinates for Corner {
     fn coordinates_pos(position: Self::Position) -> Vector3<f32> {
         position.map(|dir| dir.scalar() as f32)
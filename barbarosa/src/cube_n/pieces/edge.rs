@@ -13,8 +13,11 @@ use crate::{
         AxisMove, Vec2,
     },
     generic::{
-        self, moves::impl_movable_array, piece::PieceSetDescriptor, utils::map_array_const, Piece,
-        PieceSet,
+        self,
+        moves::impl_movable_array,
+        piece::{OrientedPiece, PieceNotation, PieceSetDescriptor},
+        utils::map_array_const,
+        Piece, PieceSet,
     },
 };
 
@@ -132,6 +135,31 @@ impl generic::Movable<AxisMove> for Edge {
 
 impl_movable_array!(Edge, AxisMove);
 
+impl OrientedPiece for Edge {
+    const ORIENTATION_COUNT: u8 = 2;
+
+    fn orientation(&self) -> u8 {
+        match self.oriented {
+            true => 0,
+            false => 1,
+        }
+    }
+
+    fn with_orientation((normal_axis, slice_position): Self::Position, orientation: u8) -> Self {
+        Edge::new(normal_axis, slice_position, orientation == 0)
+    }
+}
+
+impl PieceNotation for Edge {
+    fn notation(position: &Self::Position) -> String {
+        format!(
+            "{}{}",
+            Edge::orientation_face(*position),
+            Edge::non_orientation_face(*position)
+        )
+    }
+}
+
 impl generic::piece::Coordinates for Edge {
     fn coordinates_pos((normal_axis, slice_position): Self::Position) -> Vector3<f32> {
         normal_axis.map_on_slice(Vector3::zeros(), |_| {
@@ -14,7 +14,11 @@ use crate::{
         space::{Axis, Direction, Face},
         Vec2, WideAxisMove,
     },
-    generic::{self, piece::PieceSetDescriptor},
+    generic::{
+        self,
+        piece::{OrientedPiece, PieceNotation, PieceSetDescriptor},
+        Piece,
+    },
 };
 
 use super::{edge::ParallelAxesError, Edge};
@@ -67,6 +71,31 @@ impl PieceSetDescriptor<24> for Wing {
     const REFERENCE_POSITIONS: [Self::Position; 24] = Self::SOLVED;
 }
 
+impl OrientedPiece for Wing {
+    const ORIENTATION_COUNT: u8 = 1;
+
+    fn orientation(&self) -> u8 {
+        0
+    }
+
+    fn with_orientation(position: Self::Position, _orientation: u8) -> Self {
+        position
+    }
+}
+
+/// Wings are named like their corresponding edge, followed by the face they are closest to in lowercase (e.g.: `UFr`).
+impl PieceNotation for Wing {
+    fn notation(position: &Self::Position) -> String {
+        let side = Face::new(position.normal_axis(), position.normal_direction());
+
+        format!(
+            "{}{}",
+            Edge::notation(&position.corresponding_edge.position()),
+            side.to_string().to_lowercase()
+        )
+    }
+}
+
 impl Rotatable for Wing {
     fn rotate(&mut self, rotation: &AxisRotation) {
         self.corresponding_edge.rotate(rotation);
@@ -97,6 +126,31 @@ impl Wing {
     pub fn faces(&self) -> [Face; 2] {
         self.corresponding_edge.faces()
     }
+
+    /// The state of an edge that corresponds to the wing.
+    ///
+    /// When two partner wings are next to each other they behave like an edge, which is in the state corresponding
+    /// to the wing that was originally pseudo-oriented.
+    pub fn corresponding_edge(&self) -> &Edge {
+        &self.corresponding_edge
+    }
+
+    /// The wing whose [corresponding edge](Self::corresponding_edge) is `edge`.
+    pub fn from_corresponding_edge(edge: Edge) -> Self {
+        Self {
+            corresponding_edge: edge,
+        }
+    }
+
+    /// The other wing on the same edge.
+    ///
+    /// Two wings are paired when each one is at the partner position of the other. Outer layer moves keep
+    /// paired wings together, only inner slices can split them.
+    pub fn partner(&self) -> Wing {
+        Wing {
+            corresponding_edge: self.corresponding_edge.clone().flipped(),
+        }
+    }
 }
 
 /// Gets the wing normal direction based on a normal axis, the slice position and the pseudo orientation.
@@ -0,0 +1,558 @@
+//! Solving big cubes by reduction.
+//!
+//! Reduction turns a big cube into a "virtual" 3x3x3: first the centers get solved, then the wings get paired
+//! into edges (on odd cubes, matched to the middle edges, which is known as edge tredging). After that, outer
+//! layer moves never split the groups of pieces, so the cube can be solved like a 3x3x3 with any
+//! [`Solver`](crate::generic::search::Solver) for [`Cube3`].
+//!
+//! A reduced cube can be converted into its virtual 3x3x3 with [`TryFrom`], which fails with a
+//! [`NotReducedError`] otherwise. Any alg of outer layer moves can be turned into an alg of wide moves with
+//! [`From`], and it does the same on the big cube as on the virtual 3x3x3.
+//!
+//! The centers and the wings are solved with pure 3-cycles (see [`ThreeCycles`]). The solutions are far from
+//! optimal, but they don't need any search.
+//!
+//! # Parity
+//!
+//! The virtual 3x3x3 of an even cube can end up in states that are impossible on a real 3x3x3, since the wings
+//! can be permuted with an odd permutation and the centers don't restrict anything. Such states are called parities:
+//!
+//! - OLL parity: an odd number of edges are flipped.
+//! - PLL parity: the parity of the edge permutation is different from the corner permutation.
+//!
+//! Both get fixed before solving the 3x3x3, with the algs in [`perms::parity`](super::moves::perms::parity) or,
+//! for PLL parity on cubes bigger than the 4x4x4, with 3-cycles of the wings.
+//! A cube with either parity isn't reduced yet, so converting it into a [`Cube3`] fails with
+//! [`NotReducedError::Parity`].
+//!
+//! Odd cubes don't have these parities, since the middle edges behave like the ones of a real 3x3x3. However the
+//! wings of each depth still have their own permutation parity, so matching them to the middle edges can take a
+//! single [flip](super::moves::perms::parity::flip) of a pair.
+//!
+//! # Supercubes
+//!
+//! Reduction only puts the centers in the right face. [`Super`](super::Super) cubes get an extra stage at the
+//! end that puts every center back in its original position, with 3-cycles that don't move any other piece.
+
+mod test;
+
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
+
+use thiserror::Error;
+
+use crate::generic::{
+    alg_tree::AlgTree,
+    piece::{PieceSet, PieceSetDescriptor},
+    Alg, Movable, Move, Parsable, Piece,
+};
+
+use super::{
+    invariants::is_odd_permutation,
+    moves::{
+        perms::parity,
+        rotation::{AxisRotation, Rotatable},
+        wide::{DepthPiece, DepthPieceSet},
+        ExtendedAxisMove,
+    },
+    pieces::{
+        edge::{Edge, EdgeSet},
+        wing::{Wing, WingSet},
+    },
+    space::Axis,
+    AxisMove, Cube3, Vec2, WideAxisMove,
+};
+
+/// The pure 3-cycles of a set of pieces, that is, algs that cycle three pieces and don't move anything else.
+///
+/// They all come from a commutator that cycles three pieces of each orbit of the set. Any other three pieces of
+/// the orbit can be brought to where the commutator cycles them with some setup moves, which get undone
+/// afterwards. The shortest setups are found with a BFS over the positions of the three pieces.
+pub struct ThreeCycles<P, const W: u32> {
+    algs: HashMap<[P; 3], Alg<WideAxisMove<W>>>,
+}
+
+impl<P, const W: u32> ThreeCycles<P, W>
+where
+    P: DepthPiece<Position = P> + Rotatable + Eq + Hash,
+{
+    /// Finds the 3-cycles of the pieces of a `DepthPieceSet<P, N, ND, TD>`, given `commutators` that cycle three
+    /// pieces of each of its orbits.
+    ///
+    /// # Panics
+    ///
+    /// If a commutator doesn't cycle exactly three pieces of the set.
+    pub fn new<const N: usize, const ND: u32, const TD: u32>(
+        commutators: impl IntoIterator<Item = Alg<WideAxisMove<W>>>,
+    ) -> Self
+    where
+        P: PieceSetDescriptor<N>,
+    {
+        let setup_moves: Vec<WideAxisMove<W>> = (0..=W)
+            .flat_map(|depth| {
+                AxisMove::all()
+                    .into_iter()
+                    .map(move |mov| mov.widen(depth).expect("Depth is at most W"))
+            })
+            .collect();
+
+        let mut algs = HashMap::new();
+
+        for commutator in commutators {
+            let cycled = DepthPieceSet::<P, N, ND, TD>::SOLVED.moved(&commutator);
+
+            let moved: Vec<_> = cycled
+                .iter_with_pos()
+                .filter(|(original, piece)| original != *piece)
+                .collect();
+
+            assert_eq!(moved.len(), 3, "{commutator} should cycle three pieces");
+
+            // The commutator moves the piece at `a` to `b`, the one at `b` to `c` and the one at `c` to `a`.
+            let a = moved[0].0.clone();
+            let b = cycled.piece_originally_at(&a).clone();
+            let c = cycled.piece_originally_at(&b).clone();
+
+            // Doing the setup `S` before the commutator and undoing it after cycles the pieces that `S` takes to
+            // `[a, b, c]`. So prepending a move `m` to the setup cycles the positions that `m'` takes them to.
+            let mut setups = HashMap::from([([a, b, c], Vec::new())]);
+            let mut queue = VecDeque::from([setups.keys().next().unwrap().clone()]);
+
+            while let Some(cycle) = queue.pop_front() {
+                for mov in &setup_moves {
+                    let inverse = mov.inverse();
+                    let next = cycle
+                        .clone()
+                        .map(|piece| moved_piece::<P, W, ND, TD>(piece, &inverse));
+
+                    if setups.contains_key(&next) {
+                        continue;
+                    }
+
+                    let setup = std::iter::once(mov.clone())
+                        .chain(setups[&cycle].iter().cloned())
+                        .collect();
+
+                    setups.insert(next.clone(), setup);
+                    queue.push_back(next);
+                }
+            }
+
+            algs.extend(setups.into_iter().map(|(cycle, setup)| {
+                let setup = Alg::<WideAxisMove<W>>::new(setup);
+                let moves = setup
+                    .moves
+                    .iter()
+                    .chain(&commutator.moves)
+                    .chain(&setup.reversed().moves)
+                    .cloned()
+                    .collect();
+
+                (cycle, Alg::new(moves))
+            }));
+        }
+
+        Self { algs }
+    }
+
+    /// Returns an alg that moves the piece at `a` to `b`, the one at `b` to `c` and the one at `c` to `a`.
+    ///
+    /// Returns `None` if the positions are not in the orbit of the pieces cycled by the commutator.
+    pub fn cycle(&self, [a, b, c]: [P; 3]) -> Option<&Alg<WideAxisMove<W>>> {
+        self.algs
+            .get(&[a.clone(), b.clone(), c.clone()])
+            .or_else(|| self.algs.get(&[b.clone(), c.clone(), a.clone()]))
+            .or_else(|| self.algs.get(&[c, a, b]))
+    }
+
+    /// Returns the 3-cycles that move every piece of `set` to its target.
+    ///
+    /// `targets[i]` is where the piece originally at `REFERENCE_POSITIONS[i]` should go. The permutation from
+    /// the current positions to the targets has to be even (see [`is_odd_permutation_to`]).
+    ///
+    /// # Panics
+    ///
+    /// If the permutation is odd or there's a piece that can't be cycled to its target.
+    pub fn solve<const N: usize, const ND: u32, const TD: u32>(
+        &self,
+        set: &DepthPieceSet<P, N, ND, TD>,
+        targets: &[P; N],
+    ) -> Alg<WideAxisMove<W>>
+    where
+        P: PieceSetDescriptor<N>,
+    {
+        let mut set = set.clone();
+        let mut solution = Alg::empty();
+
+        loop {
+            let pieces = set.pieces();
+            let misplaced: Vec<_> = (0..N).filter(|&i| pieces[i] != targets[i]).collect();
+
+            let Some(&first) = misplaced.first() else {
+                return solution;
+            };
+
+            // Move the piece to its target, and the piece at the target to where it should go...
+            let p = &pieces[first];
+            let q = &targets[first];
+            let at_q = pieces
+                .iter()
+                .position(|piece| piece == q)
+                .expect("There should be a piece at each position");
+
+            // ...unless it should go to `p`, in which case it goes to some other misplaced position.
+            let alg = std::iter::once(&targets[at_q])
+                .filter(|r| *r != p)
+                .chain(misplaced.iter().map(|&i| &pieces[i]))
+                .filter(|r| *r != p && *r != q)
+                .find_map(|r| self.cycle([p.clone(), q.clone(), r.clone()]))
+                .expect("The permutation should be even and in the orbit")
+                .clone();
+
+            set.apply(&alg);
+            solution.moves.extend(alg.moves);
+        }
+    }
+
+    /// Solves `set` like [`ThreeCycles::solve`], for pieces that aren't all different, such as centers. The
+    /// targets are the [`identical_piece_targets`] with the given `same_orbit`.
+    pub fn solve_identical<const N: usize, const ND: u32, const TD: u32>(
+        &self,
+        set: &DepthPieceSet<P, N, ND, TD>,
+        same_orbit: impl Fn(&P, &P) -> bool,
+    ) -> Alg<WideAxisMove<W>>
+    where
+        P: PieceSetDescriptor<N>,
+    {
+        self.solve(set, &identical_piece_targets(set.pieces(), same_orbit))
+    }
+}
+
+/// Moves a single piece of a `DepthPieceSet<P, _, ND, TD>`.
+fn moved_piece<P: DepthPiece + Rotatable, const W: u32, const ND: u32, const TD: u32>(
+    piece: P,
+    mov: &WideAxisMove<W>,
+) -> P {
+    match piece.is_in_wide_move(ND, TD, mov) {
+        true => piece.rotated(&AxisRotation::from(&mov.axis_move)),
+        false => piece,
+    }
+}
+
+/// Whether the permutation that takes the pieces of `set` that are in `orbit` to `targets` is odd.
+///
+/// Both `set` and `targets` keep the pieces of each orbit in the orbit, so that's the parity of going back to the
+/// reference positions and then to the targets.
+pub fn is_odd_permutation_to<P: PieceSetDescriptor<N, Position = P>, const N: usize>(
+    set: &[P; N],
+    targets: &[P; N],
+    orbit: impl Fn(&P) -> bool,
+) -> bool {
+    let as_set = |pieces: &[P; N]| {
+        PieceSet::new(pieces.clone()).expect("The pieces should be a permutation of the positions")
+    };
+
+    is_odd_permutation(&as_set(set), &orbit) != is_odd_permutation(&as_set(targets), &orbit)
+}
+
+/// Targets that solve every piece of `set` (in the sense of [`Piece::is_solved`]), for pieces that aren't all
+/// different, such as centers. Solved pieces stay in place.
+///
+/// Pieces only go to positions of their own orbit, as told by `same_orbit` (which is only needed for sets made
+/// of more than one orbit, such as [`CenterWingSet`](super::pieces::center::wing::CenterWingSet)s). Since
+/// identical pieces can be swapped freely, the targets are chosen such that the permutation of each orbit is even.
+///
+/// # Panics
+///
+/// If there are no two identical pieces in an orbit to swap to make its permutation even.
+pub fn identical_piece_targets<P: PieceSetDescriptor<N, Position = P>, const N: usize>(
+    set: &[P; N],
+    same_orbit: impl Fn(&P, &P) -> bool,
+) -> [P; N] {
+    let reference_positions = P::REFERENCE_POSITIONS;
+    let solved = |i: usize, position: &P| {
+        position.is_solved(&reference_positions[i]) && same_orbit(&reference_positions[i], position)
+    };
+
+    let mut targets: [Option<P>; N] =
+        std::array::from_fn(|i| solved(i, &set[i]).then(|| set[i].clone()));
+
+    for i in 0..N {
+        if targets[i].is_some() {
+            continue;
+        }
+
+        let free = reference_positions
+            .iter()
+            .find(|position| solved(i, position) && !targets.contains(&Some((*position).clone())))
+            .expect("Each piece should have a free position where it's solved");
+
+        targets[i] = Some(free.clone());
+    }
+
+    let mut targets = targets.map(|target| target.expect("Every piece has a target"));
+
+    // The first piece of each orbit represents it
+    let representatives = (0..N)
+        .filter(|&i| (0..i).all(|j| !same_orbit(&reference_positions[j], &reference_positions[i])));
+
+    for orbit in representatives {
+        let in_orbit = |position: &P| same_orbit(&reference_positions[orbit], position);
+
+        if is_odd_permutation_to(set, &targets, in_orbit) {
+            let (i, j) = (0..N)
+                .filter(|&i| in_orbit(&reference_positions[i]))
+                .flat_map(|i| (i + 1..N).map(move |j| (i, j)))
+                .find(|&(i, j)| solved(i, &targets[j]) && solved(j, &targets[i]))
+                .expect("There should be two identical pieces");
+
+            targets.swap(i, j);
+        }
+    }
+
+    targets
+}
+
+/// Targets that pair every wing of `wings` with its [partner](Wing::partner), keeping the pairs that are already
+/// formed. Which edge goes in each slot doesn't matter.
+///
+/// The permutation to the targets might be odd, in which case one of the pairs gets flipped. This flipped pair
+/// is what causes [OLL parity](self#parity).
+pub fn paired_wing_targets<const K: u32>(wings: &WingSet<K>) -> [Wing; 24] {
+    let pieces = wings.pieces();
+    let original_index = |wing: &Wing| {
+        Wing::REFERENCE_POSITIONS
+            .iter()
+            .position(|position| position == wing)
+            .expect("There should be a reference position for each wing")
+    };
+
+    let mut targets: [Option<Wing>; 24] = std::array::from_fn(|_| None);
+
+    // Each slot gets identified by the position of the wing that corresponds to the oriented edge.
+    let slots = || {
+        Wing::REFERENCE_POSITIONS
+            .into_iter()
+            .filter(|slot| slot.corresponding_edge().oriented)
+    };
+
+    let mut free_slots = Vec::new();
+
+    for slot in slots() {
+        let wing = wings.original_position_of_piece_at(&slot);
+        let (i, j) = (original_index(&wing), original_index(&wing.partner()));
+
+        match targets[i].is_none() && targets[j].is_none() {
+            true => {
+                targets[j] = Some(slot.partner());
+                targets[i] = Some(slot);
+            }
+            false => free_slots.push(slot),
+        }
+    }
+
+    for slot in free_slots {
+        let i = (0..24)
+            .find(|&i| targets[i].is_none())
+            .expect("There should be as many free slots as unpaired edges");
+        let j = original_index(&Wing::REFERENCE_POSITIONS[i].partner());
+
+        // Keep the wing in place if it's already in the slot
+        let (i, j) = match pieces[j] == slot {
+            true => (j, i),
+            false => (i, j),
+        };
+
+        targets[j] = Some(slot.partner());
+        targets[i] = Some(slot);
+    }
+
+    let mut targets = targets.map(|target| target.expect("Every wing has a target"));
+
+    if is_odd_permutation_to(pieces, &targets, |_| true) {
+        let i = (0..24)
+            .find(|&i| pieces[i] != targets[i])
+            .unwrap_or_default();
+        let j = original_index(&Wing::REFERENCE_POSITIONS[i].partner());
+
+        targets.swap(i, j);
+    }
+
+    targets
+}
+
+/// Targets that put every wing next to the edge it belongs to, given the `edges` of the virtual 3x3x3: the middle
+/// edges on odd cubes, or the [paired edges](paired_edges) of another depth on even ones.
+///
+/// Unlike when [pairing the wings](paired_wing_targets), the targets are fixed, so the permutation to them might be
+/// odd. If so, a pair has to be flipped with [`parity::flip`] first.
+pub fn matching_wing_targets(edges: &EdgeSet) -> [Wing; 24] {
+    Wing::REFERENCE_POSITIONS.map(|wing| {
+        let original = wing.corresponding_edge();
+        let edge = edges.piece_originally_at(&original.position()).clone();
+
+        match original.oriented {
+            true => Wing::from_corresponding_edge(edge),
+            false => Wing::from_corresponding_edge(edge.flipped()),
+        }
+    })
+}
+
+/// Moves the wings of depth `K` of an `n`x`n`x`n` to fixed `targets`, such as the [`matching_wing_targets`], with
+/// the 3-cycles of the wings of that depth. If the permutation to the targets is odd, a pair gets [flipped](parity::flip) first.
+pub fn pair_wings<const W: u32, const K: u32>(
+    cycles: &ThreeCycles<Wing, W>,
+    wings: &WingSet<K>,
+    targets: &[Wing; 24],
+    n: u32,
+) -> Alg<WideAxisMove<W>> {
+    let mut wings = wings.clone();
+    let mut alg = Alg::empty();
+
+    if is_odd_permutation_to(wings.pieces(), targets, |_| true) {
+        alg = parity::flip(n, K);
+        wings.apply(&alg);
+    }
+
+    alg.moves.extend(cycles.solve(&wings, targets).moves);
+    alg
+}
+
+/// Targets that swap the wings of the parallel edges at `a` and `b`, leaving everything else in place.
+///
+/// Swapping two edges is an even permutation of the wings, so [PLL parity](self#parity) can also be fixed by
+/// solving the wings of each depth to these targets.
+pub fn swapped_wing_targets<const K: u32>(
+    wings: &WingSet<K>,
+    [a, b]: [(Axis, Vec2); 2],
+) -> [Wing; 24] {
+    wings.pieces().clone().map(|wing| {
+        let edge = wing.corresponding_edge();
+
+        let (normal_axis, slice_position) = match edge.position() {
+            position if position == a => b,
+            position if position == b => a,
+            position => position,
+        };
+
+        Wing::from_corresponding_edge(Edge::new(normal_axis, slice_position, edge.oriented))
+    })
+}
+
+/// The edges of the virtual 3x3x3 of a cube whose wings at depth `K` are paired.
+///
+/// Each edge is in the state [corresponding](Wing::corresponding_edge) to the position of the wing that was
+/// originally pseudo-oriented, so that it behaves like a 3x3x3 edge with outer layer moves. Returns `None` if
+/// the wings are not paired.
+pub fn paired_edges<const K: u32>(wings: &WingSet<K>) -> Option<EdgeSet> {
+    let paired = wings
+        .iter_with_pos()
+        .all(|(original, wing)| *wings.piece_originally_at(&original.partner()) == wing.partner());
+
+    if !paired {
+        return None;
+    }
+
+    let edges = EdgeSet::REFERENCE_POSITIONS.map(|position| {
+        let wing = wings.piece_originally_at(
+            &Wing::REFERENCE_POSITIONS
+                .into_iter()
+                .find(|wing| {
+                    let edge = wing.corresponding_edge();
+                    edge.oriented && edge.position() == position
+                })
+                .expect("There's a wing for each oriented edge"),
+        );
+
+        wing.corresponding_edge().clone()
+    });
+
+    Some(EdgeSet::new(edges).expect("Paired wings should be in different slots"))
+}
+
+/// An error if a big cube can't be seen as a 3x3x3, because it's not reduced.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum NotReducedError {
+    /// Some center isn't solved
+    #[error("The centers are not solved")]
+    UnsolvedCenters,
+
+    /// Some wing isn't next to the ones of its edge
+    #[error("The edges are not paired")]
+    UnpairedEdges,
+
+    /// The edges have [OLL or PLL parity](self#parity), which can't happen on a 3x3x3
+    #[error("The edges have OLL or PLL parity")]
+    Parity,
+}
+
+/// Whether the virtual 3x3x3 of an even cube has [OLL or PLL parity](self#parity).
+pub(crate) fn has_parity(cube: &Cube3) -> bool {
+    let flipped_edges = cube.edges.iter().filter(|edge| !edge.oriented).count();
+
+    flipped_edges % 2 == 1
+        || is_odd_permutation(&cube.edges, |_| true) != is_odd_permutation(&cube.corners, |_| true)
+}
+
+/// A solution made of named stages, that can be turned into an [`AlgTree`] with a comment after each stage.
+pub(crate) struct Stages<M: Move> {
+    stages: Vec<AlgTree<M>>,
+}
+
+impl<M: Move> Stages<M> {
+    pub fn new() -> Self {
+        Self { stages: Vec::new() }
+    }
+
+    /// Adds a stage, unless it's empty.
+    pub fn push(&mut self, name: &str, alg: Alg<M>) {
+        if alg.moves.is_empty() {
+            return;
+        }
+
+        self.stages.push(AlgTree::from(alg));
+        self.stages.push(AlgTree::Comment(name.to_string()));
+    }
+
+    /// All the moves of the stages so far.
+    pub fn alg(&self) -> Alg<M> {
+        self.stages.iter().flat_map(AlgTree::moves).collect()
+    }
+}
+
+impl<M: Move> From<Stages<M>> for AlgTree<M> {
+    fn from(stages: Stages<M>) -> Self {
+        AlgTree::Sequence(stages.stages)
+    }
+}
+
+/// A step of a stage, which depends on where the previous steps left the cube.
+pub(crate) type Step<'a, C, const W: u32> = &'a dyn Fn(&C) -> Alg<WideAxisMove<W>>;
+
+/// Applies each of the `steps` to `cube`, one after the other, and returns all of them as a single alg.
+pub(crate) fn apply_steps<C: Movable<Alg<WideAxisMove<W>>>, const W: u32>(
+    cube: &mut C,
+    steps: &[Step<C, W>],
+) -> Alg<WideAxisMove<W>> {
+    let mut alg = Alg::empty();
+
+    for step in steps {
+        let step = step(cube);
+        cube.apply(&step);
+        alg.moves.extend(step.moves);
+    }
+
+    alg
+}
+
+/// Parses an alg for the `n`x`n`x`n`, which can have slice moves such as `3R`.
+pub(crate) fn parse<const W: u32>(alg: &str, n: u32) -> Alg<WideAxisMove<W>> {
+    let (alg, _orientation) = Alg::<ExtendedAxisMove>::parse(alg)
+        .expect("The alg should be valid")
+        .without_rotations(n)
+        .expect("The alg should fit in the cube");
+
+    alg
+}
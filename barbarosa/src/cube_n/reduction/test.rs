@@ -0,0 +1,88 @@
+#![cfg(test)]
+
+use pretty_assertions::assert_eq;
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{
+    cube_n::{
+        moves::perms::parity, pieces::wing::WingSet, AxisMove, Cube3, Cube4, Cube5, Cube6, Cube7,
+    },
+    generic::{Alg, Cube, Movable},
+};
+
+use super::*;
+
+#[test]
+fn paired_wings_move_like_edges() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..20 {
+        let alg = Alg::<AxisMove>::random_unnormalized_with_rng(20, &mut rng);
+        let wings = WingSet::<1>::SOLVED.moved(&alg.clone().widen::<1>(0).unwrap());
+
+        assert_eq!(
+            paired_edges(&wings),
+            Some(Cube3::SOLVED.moved(&alg).edges),
+            "{alg}"
+        );
+    }
+}
+
+#[test]
+fn reduced_cubes_convert_to_cube3() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    macro_rules! assert_converts {
+        ($cube:ty, $n:literal, $w:literal) => {
+            assert_eq!(Cube3::try_from(&<$cube>::SOLVED), Ok(Cube3::SOLVED));
+
+            for _ in 0..5 {
+                let alg = Alg::<AxisMove>::random_unnormalized_with_rng(20, &mut rng);
+                let cube = <$cube>::SOLVED.moved(&Alg::<WideAxisMove<$w>>::from(alg.clone()));
+
+                assert_eq!(Cube3::try_from(&cube), Ok(Cube3::SOLVED.moved(&alg)));
+            }
+
+            assert_eq!(
+                Cube3::try_from(&<$cube>::SOLVED.moved(&parse::<$w>("Rw", $n))),
+                Err(NotReducedError::UnsolvedCenters)
+            );
+            assert_eq!(
+                Cube3::try_from(&<$cube>::SOLVED.moved(&parse::<$w>("R U R' 2U R U' R' 2U'", $n))),
+                Err(NotReducedError::UnpairedEdges)
+            );
+        };
+    }
+
+    assert_converts!(Cube4, 4, 1);
+    assert_converts!(Cube5, 5, 1);
+    assert_converts!(Cube6, 6, 2);
+    assert_converts!(Cube7, 7, 2);
+}
+
+#[test]
+fn cubes_with_parity_dont_convert_to_cube3() {
+    // The 4x4x4 PLL parity alg, turning both inner layers
+    let pll_6 = parse::<2>("2-3Rw2 U2 2-3Rw2 3Uw2 2-3Rw2 2-3Uw2", 6);
+
+    assert_eq!(
+        Cube3::try_from(&Cube4::SOLVED.moved(&*parity::OLL)),
+        Err(NotReducedError::Parity)
+    );
+    assert_eq!(
+        Cube3::try_from(&Cube4::SOLVED.moved(&*parity::PLL)),
+        Err(NotReducedError::Parity)
+    );
+    assert_eq!(
+        Cube3::try_from(
+            &Cube6::SOLVED
+                .moved(&parity::flip::<2>(6, 1))
+                .moved(&parity::flip::<2>(6, 2))
+        ),
+        Err(NotReducedError::Parity)
+    );
+    assert_eq!(
+        Cube3::try_from(&Cube6::SOLVED.moved(&pll_6)),
+        Err(NotReducedError::Parity)
+    );
+}
@@ -8,7 +8,10 @@ use crate::{
     },
 };
 
-use super::AxisMove;
+use super::{
+    moves::{metric::TurnMetric, QuarterAxisMove},
+    AxisMove,
+};
 
 impl Cube3 {
     /// Solves the cube using the specified heuristic
@@ -17,4 +20,30 @@ impl Cube3 {
 
         searcher.solve(self)
     }
+
+    /// Solves the cube using the specified heuristic, finding the shortest solution in the given metric.
+    ///
+    /// The heuristic has to be admissible in that metric for the solution to be optimal. Heuristics for
+    /// [HTM](TurnMetric::Htm) are admissible in [QTM](TurnMetric::Qtm) too, since no alg is shorter in QTM.
+    pub fn solve_with_heuristic_in(
+        &self,
+        heuristic: impl Fn(&Self) -> f32,
+        metric: TurnMetric,
+    ) -> Option<Alg<AxisMove>> {
+        let searcher = IDASearcher::new(heuristic, Cube3::successors, 200).with_metric(metric);
+
+        searcher.solve(self)
+    }
+
+    /// Solves the cube with quarter turns, using the specified heuristic. The solution is optimal in
+    /// [QTM](TurnMetric::Qtm) if the heuristic is admissible in QTM, such as
+    /// [`mus_in(TurnMetric::Qtm)`](crate::cube3::heuristics::mus_in).
+    pub fn solve_quarter_turns_with_heuristic(
+        &self,
+        heuristic: impl Fn(&Self) -> f32,
+    ) -> Option<Alg<QuarterAxisMove>> {
+        let searcher = IDASearcher::new(heuristic, Cube3::quarter_successors, 200);
+
+        searcher.solve(self)
+    }
 }
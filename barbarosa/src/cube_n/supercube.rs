@@ -0,0 +1,155 @@
+//! Supercubes, which are cubes whose centers have to be solved in a specific way.
+//!
+//! On a regular cube the centers of each face are interchangeable, but picture cubes and supercubes have
+//! stickers that tell them apart. So on top of being in the right face, each center has to be turned the right
+//! way (on the 3x3x3) or be the exact piece that was there when the cube was solved (on bigger cubes).
+//!
+//! See [`Super`] and [`SuperCube`] for more info.
+
+mod test;
+
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+
+use crate::generic::{self, moves::AsMove, Movable};
+
+use super::{
+    invariants::is_odd_permutation, pieces::corner::CornerSet, AxisMove, CubeN, WideAxisMove,
+};
+
+/// A cube that can be turned into a supercube, with [`Super`].
+pub trait SuperCube: CubeN {
+    /// What needs to be tracked on top of the cube to know whether its centers are solved. Most cubes already
+    /// track the position of each center, so they don't need anything else.
+    type Centers: Clone + PartialEq + Eq + std::fmt::Debug;
+
+    /// The centers of a solved cube.
+    const SOLVED_CENTERS: Self::Centers;
+
+    /// Updates `centers` after doing `m` on the cube.
+    fn move_centers(centers: &mut Self::Centers, m: &Self::Move);
+
+    /// Whether the centers are solved, including their orientation or which piece is where.
+    fn are_centers_solved(&self, centers: &Self::Centers) -> bool;
+
+    /// Random centers for the cube, such that the supercube can be solved.
+    fn random_centers<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::Centers;
+}
+
+/// A supercube, that is, a cube where the orientation and the identity of the centers matter.
+///
+/// For the 3x3x3 this means tracking how each center is turned. Bigger cubes already track where each center
+/// piece is, but a regular cube is solved as long as the centers are in the right face (see
+/// [`Piece::is_solved`](crate::generic::Piece::is_solved)), while a supercube needs every center back in its
+/// original position.
+///
+/// The middle centers of odd cubes can only turn in place, so bigger odd cubes track their orientation just like
+/// the 3x3x3.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Super<C: SuperCube> {
+    /// The original cube
+    pub base_cube: C,
+
+    /// The extra information about the centers
+    pub centers: C::Centers,
+}
+
+impl<C: SuperCube> Super<C> {
+    /// Creates a new supercube with solved centers.
+    pub const fn new(cube: C) -> Self {
+        Self {
+            base_cube: cube,
+            centers: C::SOLVED_CENTERS,
+        }
+    }
+}
+
+/// Convenience trait to wrap a cube into a [`Super`] with method syntax.
+// Trait because otherwise we can't implement this method
+pub trait IntoSuper: SuperCube {
+    /// Wraps the cube into a [`Super`] with solved centers.
+    fn into_super(self) -> Super<Self> {
+        Super::new(self)
+    }
+}
+
+impl<C: SuperCube> IntoSuper for C {}
+
+impl<C: SuperCube> AsMove for Super<C> {
+    type Move = C::Move;
+}
+
+impl<C: SuperCube<Move = AxisMove>> Movable<AxisMove> for Super<C> {
+    fn apply(&mut self, m: &AxisMove) {
+        self.base_cube.apply(m);
+        C::move_centers(&mut self.centers, m);
+    }
+}
+
+/// Implements [`Movable`] for the [`Super`] version of the cubes that move with `WideAxisMove<W>`, for each given
+/// `W`. Not generic over `W` since the ones with `W = 0` already are movable through [`AxisMove`].
+macro_rules! impl_movable_wide_move {
+    ($($width:literal),*) => {
+        $(
+            impl<C: SuperCube<Move = WideAxisMove<$width>>> Movable<WideAxisMove<$width>> for Super<C> {
+                fn apply(&mut self, m: &WideAxisMove<$width>) {
+                    self.base_cube.apply(m);
+                    C::move_centers(&mut self.centers, m);
+                }
+            }
+        )*
+    };
+}
+
+impl_movable_wide_move!(1, 2, 3, 4, 5);
+
+impl<C: SuperCube + 'static> generic::Cube for Super<C>
+where
+    Self: Movable<C::Move>,
+{
+    const SOLVED: Self = Super::new(C::SOLVED);
+
+    fn is_solved(&self) -> bool
+    where
+        Self: 'static,
+    {
+        self.base_cube.is_solved() && self.base_cube.are_centers_solved(&self.centers)
+    }
+}
+
+impl<C: SuperCube> From<C> for Super<C> {
+    fn from(value: C) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<C: SuperCube> Distribution<Super<C>> for Standard
+where
+    Standard: Distribution<C>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Super<C> {
+        let base_cube: C = rng.gen();
+        let centers = base_cube.random_centers(rng);
+
+        Super { base_cube, centers }
+    }
+}
+
+/// Turns the middle center of the face of `m`. Middle centers are tracked as the number of clockwise quarter
+/// turns of each one, indexed by [`Face::index`](super::space::Face::index).
+pub(crate) fn turn_middle_center(centers: &mut [u8; 6], m: &AxisMove) {
+    let center = &mut centers[m.face.index()];
+    *center = (*center + m.amount as u8) % 4;
+}
+
+/// Random turns of the middle centers of a cube with the given `corners`, such that the supercube can be solved.
+pub(crate) fn random_middle_centers<R: Rng + ?Sized>(corners: &CornerSet, rng: &mut R) -> [u8; 6] {
+    let mut centers = [(); 6].map(|_| rng.gen_range(0..4));
+
+    // Each quarter turn turns one center and swaps the corners an odd number of times
+    let turns: u8 = centers.iter().sum();
+    if (turns % 2 == 1) != is_odd_permutation(corners, |_| true) {
+        centers[0] = (centers[0] + 1) % 4;
+    }
+
+    centers
+}
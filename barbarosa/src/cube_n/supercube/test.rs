@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+use pretty_assertions::assert_eq;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    cube3::heuristics,
+    cube_n::{invariants::is_odd_permutation, Cube3, Cube4, Cube5, Cube7},
+    generic::{search::ida::IDASearcher, Alg, Cube, Movable, Parsable},
+};
+
+use super::*;
+
+#[test]
+fn turned_centers_are_not_solved() {
+    let alg = Alg::<AxisMove>::parse("R U R' U R U R' U R U R' U R U R' U R U R' U").unwrap();
+
+    let cube = Cube3::SOLVED.into_super().moved(&alg);
+    assert!(cube.base_cube.is_solved());
+    assert!(!cube.is_solved());
+    assert_eq!(cube.centers, [0, 2, 0, 0, 0, 0]);
+    assert!(cube.moved(&alg).is_solved());
+
+    let cube = Cube4::SOLVED
+        .into_super()
+        .moved(&Alg::<WideAxisMove<1>>::from(alg.clone()));
+    assert!(cube.base_cube.is_solved());
+    assert!(!cube.is_solved());
+
+    let cube = Cube5::SOLVED
+        .into_super()
+        .moved(&Alg::<WideAxisMove<1>>::from(alg));
+    assert!(cube.base_cube.is_solved());
+    assert!(!cube.is_solved());
+    assert_eq!(cube.centers, [0, 2, 0, 0, 0, 0]);
+}
+
+#[test]
+fn random_supercubes_are_solvable() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..50 {
+        let cube: Super<Cube3> = rng.gen();
+        let turns: u8 = cube.centers.iter().sum();
+
+        assert!(cube.centers.iter().all(|&center| center < 4));
+        assert_eq!(
+            turns % 2 == 1,
+            is_odd_permutation(&cube.base_cube.corners, |_| true)
+        );
+    }
+
+    let a: Super<Cube7> = StdRng::seed_from_u64(1).gen();
+    let b: Super<Cube7> = StdRng::seed_from_u64(1).gen();
+    assert_eq!(a, b);
+    assert!(!a.is_solved());
+}
+
+#[test]
+fn solves_turned_centers() {
+    let solver = IDASearcher::new(heuristics::zero, Cube3::successors, 5);
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..10 {
+        let base_cube = Cube3::SOLVED.moved(&Alg::<AxisMove>::parse("R U F'").unwrap());
+        let centers = base_cube.random_centers(&mut rng);
+        let cube = Super { base_cube, centers };
+
+        let solution = cube.solve_with(&solver).unwrap();
+
+        let comments: Vec<_> = solution
+            .comments()
+            .into_iter()
+            .map(|(_, comment)| comment)
+            .collect();
+        assert_eq!(comments[0], "3x3x3");
+        assert!(cube.moved(&solution.flatten()).is_solved());
+    }
+}
@@ -9,7 +9,8 @@ use strum::IntoEnumIterator;
 use thiserror::Error;
 
 use super::{
-    moves::AsMove,
+    alg_tree::AlgTree,
+    moves::{AsMove, Mergeable, Metric},
     parse::{self, ParseError},
     Movable, Move, Parsable,
 };
@@ -40,6 +41,88 @@ impl<T: AsMove> Alg<T> {
     pub fn reversed(&self) -> Self {
         Self::new(self.moves.iter().rev().map(Move::inverse).collect())
     }
+
+    /// Counts the moves of the alg using the given [`Metric`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use barbarosa::prelude::*;
+    /// use barbarosa::cube_n::moves::metric::TurnMetric;
+    /// use barbarosa::generic::Alg;
+    ///
+    /// let alg = Alg::<AxisMove>::parse("R U2 R'").unwrap();
+    ///
+    /// assert_eq!(alg.count(TurnMetric::Htm), 3);
+    /// assert_eq!(alg.count(TurnMetric::Qtm), 4);
+    /// ```
+    pub fn count(&self, metric: impl Metric<T::Move>) -> u32 {
+        self.moves.iter().map(|mov| metric.cost(mov)).sum()
+    }
+}
+
+impl<T: AsMove> Alg<T>
+where
+    T::Move: Mergeable,
+{
+    /// Simplifies the alg in-place, merging and cancelling moves.
+    ///
+    /// Moves get merged with moves on the same [axis](Mergeable::axis), even if they are not adjacent, as long as
+    /// the moves in between are on the same axis too (since they commute). So, for example:
+    /// - `R R` becomes `R2`
+    /// - `R R'` gets cancelled completely
+    /// - `R L R'` becomes `L`
+    /// - `R U U' R'` also gets cancelled completely, since cancelling `U U'` makes both `R`s adjacent.
+    ///
+    /// See also [`Self::simplified`] for the owning version.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use barbarosa::prelude::*;
+    /// use barbarosa::generic::Alg;
+    ///
+    /// let mut alg = Alg::<AxisMove>::parse("R L2 U U' R L2 F").unwrap();
+    /// alg.simplify();
+    ///
+    /// assert_eq!(alg.to_string(), "R2 F");
+    /// ```
+    pub fn simplify(&mut self) {
+        let mut output: Vec<T::Move> = Vec::with_capacity(self.moves.len());
+
+        for mov in self.moves.drain(..) {
+            let axis = mov.axis();
+
+            // Moves at the end with the same axis commute with `mov`, so we can try to merge with any of them
+            let run_length = output
+                .iter()
+                .rev()
+                .take_while(|other| other.axis() == axis)
+                .count();
+            let run_start = output.len() - run_length;
+
+            let merged = output[run_start..]
+                .iter()
+                .enumerate()
+                .find_map(|(i, other)| Some((run_start + i, other.merge(&mov)?)));
+
+            match merged {
+                Some((i, Some(merged))) => output[i] = merged,
+                Some((i, None)) => {
+                    output.remove(i);
+                }
+                None => output.push(mov),
+            }
+        }
+
+        self.moves = output;
+    }
+
+    /// Returns a simplified version of the alg. See [`Self::simplify`] for more info.
+    pub fn simplified(mut self) -> Self {
+        self.simplify();
+        self
+    }
 }
 
 impl<T: AsMove> Parsable for Alg<T>
@@ -48,11 +131,13 @@ where
 {
     type Rule = <T::Move as Parsable>::Rule;
 
+    /// Parses an alg, expanding any commutators, conjugates and repeated groups.
+    ///
+    /// Use [`AlgTree`] to keep the structure of the alg.
     fn parse(s: &str) -> parse::Result<Self> {
-        let moves: Result<Vec<_>, ParseError<Self::Rule>> =
-            s.split_whitespace().map(T::Move::parse).collect();
+        let tree = AlgTree::<T::Move>::parse(s)?;
 
-        Ok(Self::new(moves?))
+        Ok(Self::new(tree.moves()))
     }
 }
 
@@ -0,0 +1,311 @@
+//! Algs that keep their structure, such as commutators, conjugates and repeated groups.
+//!
+//! See [`AlgTree`] for more info.
+
+mod parser;
+mod test;
+
+use std::{fmt::Display, ops::Range};
+
+use super::{Alg, Move};
+
+/// An alg that keeps the structure it was written in.
+///
+/// Algs are often written with commutators (`[R U R', D]`), conjugates (`[F: R U R' U']`)
+/// and repeated groups (`(R U R' U')3`), which can be nested arbitrarily. An [`AlgTree`] keeps
+/// that structure, which can be expanded into a regular [`Alg`] with [`AlgTree::flatten`].
+///
+/// # Example
+///
+/// ```rust
+/// use barbarosa::prelude::*;
+/// use barbarosa::generic::{alg_tree::AlgTree, Alg};
+///
+/// let tree = AlgTree::<AxisMove>::parse("[R, U] (R U')2").unwrap();
+///
+/// assert_eq!(tree.flatten(), Alg::parse("R U R' U' R U' R U'").unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlgTree<M: Move> {
+    /// A single move.
+    Move(M),
+
+    /// Algs one after the other.
+    Sequence(Vec<AlgTree<M>>),
+
+    /// An alg repeated some number of times, written `(A)n`.
+    Group {
+        /// The repeated alg
+        alg: Box<AlgTree<M>>,
+        /// The number of times the alg gets repeated
+        repetitions: u32,
+    },
+
+    /// A commutator, written `[A, B]`, which expands to `A B A' B'`.
+    Commutator(Box<AlgTree<M>>, Box<AlgTree<M>>),
+
+    /// A conjugate, written `[A: B]`, which expands to `A B A'`.
+    Conjugate(Box<AlgTree<M>>, Box<AlgTree<M>>),
+
+    /// A pause, written `.`. It doesn't do anything, it's just there for readability.
+    Pause,
+
+    /// A comment, written `// text`. Like pauses, it doesn't do anything.
+    ///
+    /// The comment lasts until the end of the line. See [`AlgTree::comments`] for the moves it refers to.
+    Comment(String),
+
+    /// A block comment, written `/* text */`. Unlike [`AlgTree::Comment`], it can be placed mid-line.
+    BlockComment(String),
+}
+
+impl<M: Move> AlgTree<M> {
+    /// Expands the tree into the sequence of moves it represents.
+    pub fn moves(&self) -> Vec<M> {
+        let mut output = Vec::new();
+        self.extend_moves(&mut output);
+        output
+    }
+
+    fn extend_moves(&self, output: &mut Vec<M>) {
+        match self {
+            AlgTree::Move(mov) => output.push(mov.clone()),
+            AlgTree::Sequence(algs) => {
+                for alg in algs {
+                    alg.extend_moves(output);
+                }
+            }
+            AlgTree::Group { alg, repetitions } => {
+                let moves = alg.moves();
+                for _ in 0..*repetitions {
+                    output.extend_from_slice(&moves);
+                }
+            }
+            AlgTree::Commutator(a, b) => {
+                let (a, b) = (a.moves(), b.moves());
+                output.extend_from_slice(&a);
+                output.extend_from_slice(&b);
+                output.extend(a.iter().rev().map(Move::inverse));
+                output.extend(b.iter().rev().map(Move::inverse));
+            }
+            AlgTree::Conjugate(a, b) => {
+                let a = a.moves();
+                output.extend_from_slice(&a);
+                b.extend_moves(output);
+                output.extend(a.iter().rev().map(Move::inverse));
+            }
+            AlgTree::Pause | AlgTree::Comment(_) | AlgTree::BlockComment(_) => (),
+        }
+    }
+
+    /// The comments of the alg, with the range of moves (as indices of [`AlgTree::moves`]) they refer to.
+    ///
+    /// A comment refers to the moves before it in the same sequence, up to the previous comment. For example,
+    /// in `R U R' // insert pair` the comment refers to the first three moves. Comments inside repeated
+    /// groups refer to the first repetition.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use barbarosa::prelude::*;
+    /// use barbarosa::generic::alg_tree::AlgTree;
+    ///
+    /// let tree = AlgTree::<AxisMove>::parse("
+    ///     R U R' // insert pair
+    ///     U2 R U' R' // insert another pair
+    /// ").unwrap();
+    ///
+    /// assert_eq!(tree.comments(), vec![(0..3, "insert pair"), (3..7, "insert another pair")]);
+    /// ```
+    pub fn comments(&self) -> Vec<(Range<usize>, &str)> {
+        let mut output = Vec::new();
+        self.collect_comments(0, &mut output);
+        output
+    }
+
+    /// Collects the comments of the tree, returning the number of moves it expands to.
+    fn collect_comments<'a>(
+        &'a self,
+        offset: usize,
+        output: &mut Vec<(Range<usize>, &'a str)>,
+    ) -> usize {
+        match self {
+            AlgTree::Move(_) => 1,
+            AlgTree::Sequence(algs) => {
+                let mut current = offset;
+                let mut commented_until = offset;
+
+                for alg in algs {
+                    match alg {
+                        AlgTree::Comment(comment) | AlgTree::BlockComment(comment) => {
+                            output.push((commented_until..current, comment.as_str()));
+                            commented_until = current;
+                        }
+                        alg => current += alg.collect_comments(current, output),
+                    }
+                }
+
+                current - offset
+            }
+            AlgTree::Group { alg, repetitions } => {
+                alg.collect_comments(offset, output) * *repetitions as usize
+            }
+            AlgTree::Commutator(a, b) => {
+                let a_length = a.collect_comments(offset, output);
+                let b_length = b.collect_comments(offset + a_length, output);
+                2 * (a_length + b_length)
+            }
+            AlgTree::Conjugate(a, b) => {
+                let a_length = a.collect_comments(offset, output);
+                let b_length = b.collect_comments(offset + a_length, output);
+                2 * a_length + b_length
+            }
+            AlgTree::Pause => 0,
+            AlgTree::Comment(comment) | AlgTree::BlockComment(comment) => {
+                output.push((offset..offset, comment.as_str()));
+                0
+            }
+        }
+    }
+
+    /// Expands the tree into a flat [`Alg`].
+    pub fn flatten(&self) -> Alg<M> {
+        Alg::new(self.moves())
+    }
+
+    /// Whether the tree ends with a line comment, which would comment out anything after it in the same line.
+    fn ends_with_comment(&self) -> bool {
+        match self {
+            AlgTree::Comment(_) => true,
+            AlgTree::Sequence(algs) => algs.last().is_some_and(AlgTree::ends_with_comment),
+            _ => false,
+        }
+    }
+}
+
+impl<M: Move + PartialEq> AlgTree<M> {
+    /// Tries to write a flat alg as a commutator `[A, B]`, possibly with setup moves (`[S: [A, B]]`).
+    ///
+    /// The longest setup gets chosen, which makes the commutator as short as possible. Only exact
+    /// matches are found, so the alg shouldn't have any cancellations. Returns `None` if the alg
+    /// can't be written as a commutator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use barbarosa::prelude::*;
+    /// use barbarosa::generic::{alg_tree::AlgTree, Alg};
+    ///
+    /// let alg = Alg::<AxisMove>::parse("F R U R' U' F'").unwrap();
+    /// let tree = AlgTree::find_commutator(&alg).unwrap();
+    ///
+    /// assert_eq!(tree.to_string(), "[F: [R, U]]");
+    /// ```
+    pub fn find_commutator(alg: &Alg<M>) -> Option<Self> {
+        let moves = &alg.moves;
+
+        (0..=moves.len() / 2).rev().find_map(|setup_length| {
+            let (setup, rest) = moves.split_at(setup_length);
+            let (inner, undo) = rest.split_at(rest.len() - setup_length);
+
+            if !is_inverse(setup, undo) {
+                return None;
+            }
+
+            let commutator = find_pure_commutator(inner)?;
+
+            Some(match setup_length {
+                0 => commutator,
+                _ => AlgTree::Conjugate(Box::new(sequence(setup)), Box::new(commutator)),
+            })
+        })
+    }
+}
+
+/// Whether `b` undoes `a`
+fn is_inverse<M: Move + PartialEq>(a: &[M], b: &[M]) -> bool {
+    a.len() == b.len() && a.iter().zip(b.iter().rev()).all(|(a, b)| a.inverse() == *b)
+}
+
+fn find_pure_commutator<M: Move + PartialEq>(moves: &[M]) -> Option<AlgTree<M>> {
+    if moves.len() % 2 == 1 {
+        return None;
+    }
+
+    let half = moves.len() / 2;
+    (1..half).find_map(|a_length| {
+        let (ab, undo) = moves.split_at(half);
+        let (a, b) = ab.split_at(a_length);
+        let (undo_a, undo_b) = undo.split_at(a_length);
+
+        (is_inverse(a, undo_a) && is_inverse(b, undo_b))
+            .then(|| AlgTree::Commutator(Box::new(sequence(a)), Box::new(sequence(b))))
+    })
+}
+
+fn sequence<M: Move>(moves: &[M]) -> AlgTree<M> {
+    AlgTree::Sequence(moves.iter().cloned().map(AlgTree::Move).collect())
+}
+
+impl<M: Move + Display> Display for AlgTree<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlgTree::Move(mov) => write!(f, "{mov}"),
+            AlgTree::Sequence(algs) => {
+                for (i, alg) in algs.iter().enumerate() {
+                    if i > 0 {
+                        // Comments last until the end of the line
+                        match algs[i - 1] {
+                            AlgTree::Comment(_) => writeln!(f)?,
+                            _ => write!(f, " ")?,
+                        }
+                    }
+
+                    write!(f, "{alg}")?;
+                }
+
+                Ok(())
+            }
+            AlgTree::Group { alg, repetitions } => {
+                let alg = Enclosed(alg);
+                match repetitions {
+                    1 => write!(f, "({alg})"),
+                    n => write!(f, "({alg}){n}"),
+                }
+            }
+            AlgTree::Commutator(a, b) => write!(f, "[{}, {}]", Enclosed(a), Enclosed(b)),
+            AlgTree::Conjugate(a, b) => write!(f, "[{}: {}]", Enclosed(a), Enclosed(b)),
+            AlgTree::Pause => write!(f, "."),
+            AlgTree::Comment(comment) => write!(f, "// {comment}"),
+            AlgTree::BlockComment(comment) => write!(f, "/* {comment} */"),
+        }
+    }
+}
+
+/// Displays an alg inside brackets or parenthesis, making sure a trailing comment doesn't comment out the
+/// closing delimiter.
+struct Enclosed<'a, M: Move>(&'a AlgTree<M>);
+
+impl<M: Move + Display> Display for Enclosed<'_, M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)?;
+
+        if self.0.ends_with_comment() {
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<M: Move> From<AlgTree<M>> for Alg<M> {
+    fn from(tree: AlgTree<M>) -> Self {
+        tree.flatten()
+    }
+}
+
+impl<M: Move> From<Alg<M>> for AlgTree<M> {
+    fn from(alg: Alg<M>) -> Self {
+        sequence(&alg.moves)
+    }
+}
@@ -0,0 +1,139 @@
+use pest::{error::InputLocation, iterators::Pair, Parser, RuleType};
+use pest_derive::Parser;
+
+use crate::generic::{
+    parse::{self, AlgParseError, AlgParseErrorKind, ParseError},
+    Move, Parsable,
+};
+
+use super::AlgTree;
+
+#[derive(Parser)]
+#[grammar = "grammar/alg.pest"]
+pub(super) struct AlgParser;
+
+type Result<T, M> = std::result::Result<T, ParseError<<M as Parsable>::Rule>>;
+
+fn next<'i, M: Parsable>(
+    pairs: &mut pest::iterators::Pairs<'i, Rule>,
+) -> Result<Pair<'i, Rule>, M> {
+    pairs.next().ok_or(ParseError::UnexpectedEndOfTokens)
+}
+
+/// Parses a sequence whose moves get repeated `repetitions` times by the nodes around it.
+fn parse_sequence<M: Move + Parsable>(pair: Pair<Rule>, repetitions: u64) -> Result<AlgTree<M>, M> {
+    let nodes = pair
+        .into_inner()
+        .map(|node| parse_node(node, repetitions))
+        .collect::<Result<Vec<_>, M>>()?;
+
+    Ok(AlgTree::Sequence(nodes))
+}
+
+fn comment_text(pair: Pair<Rule>) -> String {
+    pair.into_inner()
+        .next()
+        .map_or("", |text| text.as_str())
+        .trim()
+        .to_string()
+}
+
+/// Creates an error located at the given pair.
+fn located<R: RuleType>(pair: &Pair<Rule>, kind: AlgParseErrorKind<R>) -> ParseError<R> {
+    let span = pair.as_span();
+    AlgParseError::new(pair.get_input(), span.start()..span.end(), kind).into()
+}
+
+/// Multiplies the repetitions of the enclosing nodes by `times`, or errors at `pair` if the moves inside would be
+/// repeated more than [`parse::MAX_REPETITIONS`] times.
+fn repeat<R: RuleType>(
+    pair: &Pair<Rule>,
+    repetitions: u64,
+    times: u32,
+) -> std::result::Result<u64, ParseError<R>> {
+    let total = repetitions * u64::from(times);
+
+    match total > u64::from(parse::MAX_REPETITIONS) {
+        true => Err(located(pair, AlgParseErrorKind::TooManyRepetitions(total))),
+        false => Ok(total),
+    }
+}
+
+fn parse_node<M: Move + Parsable>(pair: Pair<Rule>, repetitions: u64) -> Result<AlgTree<M>, M> {
+    match pair.as_rule() {
+        Rule::move_token => match M::parse(pair.as_str()) {
+            Ok(mov) => Ok(AlgTree::Move(mov)),
+            Err(err) => Err(located(
+                &pair,
+                AlgParseErrorKind::InvalidMove(pair.as_str().to_string(), err),
+            )),
+        },
+        Rule::pause => Ok(AlgTree::Pause),
+        Rule::line_comment => Ok(AlgTree::Comment(comment_text(pair))),
+        Rule::block_comment => Ok(AlgTree::BlockComment(comment_text(pair))),
+        Rule::group => {
+            let mut inner = pair.into_inner();
+            let sequence = next::<M>(&mut next::<M>(&mut inner)?.into_inner())?;
+
+            let (count, total) = match inner.next() {
+                Some(count) => {
+                    let parsed = count.as_str().parse().map_err(|err| {
+                        located(
+                            &count,
+                            AlgParseErrorKind::InvalidRepetitions(count.as_str().to_string(), err),
+                        )
+                    })?;
+
+                    (parsed, repeat(&count, repetitions, parsed)?)
+                }
+                None => (1, repetitions),
+            };
+
+            Ok(AlgTree::Group {
+                alg: Box::new(parse_sequence(sequence, total)?),
+                repetitions: count,
+            })
+        }
+        Rule::bracket => {
+            // The first part is undone at the end, so its moves are done twice
+            let doubled = repeat(&pair, repetitions, 2)?;
+
+            let mut inner = pair.into_inner();
+            let a = Box::new(parse_sequence(next::<M>(&mut inner)?, doubled)?);
+            let kind = next::<M>(&mut inner)?;
+            let b = next::<M>(&mut inner)?;
+
+            match kind.as_str() {
+                "," => Ok(AlgTree::Commutator(
+                    a,
+                    Box::new(parse_sequence(b, doubled)?),
+                )),
+                ":" => Ok(AlgTree::Conjugate(
+                    a,
+                    Box::new(parse_sequence(b, repetitions)?),
+                )),
+                other => Err(ParseError::Unreachable(other.to_string())),
+            }
+        }
+        _ => Err(ParseError::Unreachable(pair.as_str().to_string())),
+    }
+}
+
+impl<M: Move + Parsable> Parsable for AlgTree<M> {
+    type Rule = M::Rule;
+
+    fn parse(s: &str) -> parse::Result<Self> {
+        let mut pairs = AlgParser::parse(Rule::alg, s).map_err(|err| {
+            let span = match err.location {
+                InputLocation::Pos(pos) => pos..pos,
+                InputLocation::Span((start, end)) => start..end,
+            };
+
+            let message = err.variant.message().to_string();
+            AlgParseError::new(s, span, AlgParseErrorKind::Syntax(message))
+        })?;
+        let sequence = next::<M>(&mut next::<M>(&mut pairs)?.into_inner())?;
+
+        parse_sequence(sequence, 1)
+    }
+}
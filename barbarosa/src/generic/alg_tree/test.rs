@@ -0,0 +1,315 @@
+#![cfg(test)]
+
+use crate::{
+    cube3::Cube3,
+    cube_n::{moves::ExtendedAxisMove, AxisMove},
+    generic::{
+        parse::{AlgParseError, AlgParseErrorKind, ParseError},
+        Alg, Cube, Movable, Parsable,
+    },
+};
+
+use super::AlgTree;
+
+fn expanded(alg: &str) -> String {
+    Alg::<AxisMove>::parse(alg).unwrap().to_string()
+}
+
+#[test]
+fn expands_commutators_and_conjugates() {
+    assert_eq!(expanded("[R U R', D]"), "R U R' D R U' R' D'");
+    assert_eq!(expanded("[F: R U R' U']"), "F R U R' U' F'");
+    assert_eq!(expanded("[R, U] [U, R]"), "R U R' U' U R U' R'");
+}
+
+#[test]
+fn expands_groups() {
+    assert_eq!(expanded("(R U R' U')3"), "R U R' U' R U R' U' R U R' U'");
+    assert_eq!(expanded("(R U)"), "R U");
+    assert_eq!(expanded("( R U )2"), "R U R U");
+    assert_eq!(expanded("(R)0 U"), "U");
+}
+
+#[test]
+fn expands_nested() {
+    assert_eq!(expanded("[F: [R, U]]"), "F R U R' U' F'");
+    assert_eq!(expanded("[[R, U], D]"), "R U R' U' D U R U' R' D'");
+    assert_eq!(
+        expanded("[R: [(U D)2, F]]"),
+        "R U D U D F D' U' D' U' F' R'"
+    );
+    assert_eq!(expanded("([R, U])6"), expanded("(R U R' U')6"));
+}
+
+#[test]
+fn handles_whitespace() {
+    assert_eq!(expanded("  R\tU\n R'  "), "R U R'");
+    assert_eq!(expanded("[ R U R' , D ]"), "R U R' D R U' R' D'");
+    assert_eq!(expanded("[F:R]"), "F R F'");
+    assert_eq!(expanded(""), "");
+}
+
+#[test]
+fn errors_on_malformed_algs() {
+    for alg in [
+        "[R, U",
+        "[R U]",
+        "(R U",
+        "R U)",
+        "[R, U, F]",
+        "[R: U: F]",
+        "(R U) 2",
+        "(R U)2'",
+        "[R, Q]",
+    ] {
+        assert!(Alg::<AxisMove>::parse(alg).is_err(), "{alg} should error");
+    }
+}
+
+#[test]
+fn keeps_structure() {
+    let tree = AlgTree::<AxisMove>::parse("[F: (R U)2]").unwrap();
+
+    let AlgTree::Sequence(nodes) = &tree else {
+        panic!("top level should be a sequence, got {tree:?}");
+    };
+
+    let [AlgTree::Conjugate(setup, inner)] = nodes.as_slice() else {
+        panic!("expected a single conjugate, got {nodes:?}");
+    };
+
+    assert_eq!(setup.flatten().to_string(), "F");
+    assert!(matches!(
+        inner.as_ref(),
+        AlgTree::Sequence(nodes) if matches!(nodes.as_slice(), [AlgTree::Group { repetitions: 2, .. }])
+    ));
+}
+
+#[test]
+fn works_with_other_move_types() {
+    let alg = Alg::<ExtendedAxisMove>::parse("[M', U2] [x: R]").unwrap();
+
+    assert_eq!(alg.to_string(), "M' U2 M U2 x R x'");
+}
+
+#[test]
+fn commutators_have_expected_effect() {
+    // A pure commutator of disjoint moves does nothing
+    let alg = Alg::<AxisMove>::parse("[R, L]").unwrap();
+    assert!(Cube3::SOLVED.moved(&alg).is_solved());
+
+    let sexy = Alg::<AxisMove>::parse("([R, U])6").unwrap();
+    assert!(Cube3::SOLVED.moved(&sexy).is_solved());
+}
+
+#[test]
+fn displays_original_notation() {
+    for alg in [
+        "R U R' U'",
+        "[R U R', D]",
+        "[F: R U R' U']",
+        "(R U R' U')3",
+        "(R U)",
+        "[F: [R, U]] . (R2)2 [R U: [R', D2]]",
+        "",
+    ] {
+        assert_eq!(AlgTree::<AxisMove>::parse(alg).unwrap().to_string(), alg);
+    }
+
+    assert_eq!(
+        AlgTree::<AxisMove>::parse("  [ R,U ]\t( R )2 ")
+            .unwrap()
+            .to_string(),
+        "[R, U] (R)2"
+    );
+}
+
+#[test]
+fn displays_comments() {
+    let tree = AlgTree::Sequence(vec![
+        AlgTree::Move(AxisMove::parse("R").unwrap()),
+        AlgTree::Comment("insert".to_string()),
+        AlgTree::Move(AxisMove::parse("U").unwrap()),
+    ]);
+
+    assert_eq!(tree.to_string(), "R // insert\nU");
+    assert_eq!(tree.flatten().to_string(), "R U");
+}
+
+#[test]
+fn pauses_do_nothing() {
+    assert_eq!(expanded("R . U . . R'"), "R U R'");
+}
+
+#[test]
+fn finds_commutators() {
+    let find = |alg: &str| {
+        let alg = Alg::<AxisMove>::parse(alg).unwrap();
+        AlgTree::find_commutator(&alg).map(|tree| {
+            assert_eq!(tree.flatten(), alg);
+            tree.to_string()
+        })
+    };
+
+    for notation in [
+        "[R, U]",
+        "[R U R', D]",
+        "[F: [R, U]]",
+        "[R U: [R', D2]]",
+        "[R2, U]",
+    ] {
+        assert_eq!(find(&expanded(notation)).as_deref(), Some(notation));
+    }
+
+    assert_eq!(find("R U F"), None);
+    assert_eq!(find("F R U R' F'"), None);
+    assert_eq!(find(""), None);
+}
+
+#[test]
+fn parses_comments() {
+    let reconstruction = "
+        y' // inspection
+        R U R' /* pair */ U2 // insert pair
+        [R: U]\t// last slot
+
+        /*
+            Multi-line block comment
+        */
+        (R U R' U')2
+    ";
+
+    let tree = AlgTree::<ExtendedAxisMove>::parse(reconstruction).unwrap();
+    let expected =
+        Alg::<ExtendedAxisMove>::parse("y' R U R' U2 R U R' R U R' U' R U R' U'").unwrap();
+    assert_eq!(tree.flatten(), expected);
+
+    assert_eq!(
+        tree.comments(),
+        vec![
+            (0..1, "inspection"),
+            (1..4, "pair"),
+            (4..5, "insert pair"),
+            (5..8, "last slot"),
+            (8..8, "Multi-line block comment"),
+        ]
+    );
+}
+
+#[test]
+fn comments_round_trip() {
+    for alg in [
+        "R U R' // insert pair\nU2",
+        "R /* setup */ U",
+        "[R: U // inside\n]",
+        "// only a comment",
+    ] {
+        assert_eq!(AlgTree::<AxisMove>::parse(alg).unwrap().to_string(), alg);
+    }
+}
+
+#[test]
+fn comments_dont_swallow_moves() {
+    assert_eq!(expanded("R//comment\nU"), "R U");
+    assert_eq!(expanded("R/*comment*/U"), "R U");
+    assert_eq!(
+        expanded("R U // comment with [brackets], (parens) and R moves"),
+        "R U"
+    );
+    assert!(Alg::<AxisMove>::parse("R /* unclosed").is_err());
+}
+
+fn alg_error(alg: &str) -> AlgParseError<<AxisMove as Parsable>::Rule> {
+    match Alg::<AxisMove>::parse(alg) {
+        Err(ParseError::Alg(error)) => *error,
+        other => panic!("expected an alg error, got {other:?}"),
+    }
+}
+
+#[test]
+fn locates_invalid_moves() {
+    let error = alg_error("R U R2' U'");
+    assert_eq!(error.span, 4..7);
+    assert_eq!((error.line, error.column), (1, 5));
+    assert!(matches!(error.kind, AlgParseErrorKind::InvalidMove(ref token, _) if token == "R2'"));
+
+    let error = alg_error("R U // comment\n\t[F: Q]");
+    assert_eq!(error.span, 20..21);
+    assert_eq!((error.line, error.column), (2, 6));
+
+    // Columns count characters, not bytes
+    let error = alg_error("/* µ */ P");
+    assert_eq!((error.line, error.column), (1, 9));
+}
+
+#[test]
+fn locates_syntax_errors() {
+    let error = alg_error("[R, U");
+    assert_eq!((error.line, error.column), (1, 6));
+    assert!(matches!(error.kind, AlgParseErrorKind::Syntax(_)));
+
+    let error = alg_error("R\n(U)99999999999");
+    assert_eq!((error.line, error.column), (2, 4));
+    assert!(matches!(
+        error.kind,
+        AlgParseErrorKind::InvalidRepetitions(..)
+    ));
+
+    let error = alg_error("(R)4000000000");
+    assert_eq!((error.line, error.column), (1, 4));
+    assert!(matches!(
+        error.kind,
+        AlgParseErrorKind::TooManyRepetitions(4000000000)
+    ));
+
+    assert!(Alg::<AxisMove>::parse("(R)1000").is_ok());
+}
+
+#[test]
+fn nested_repetitions_multiply() {
+    let error = alg_error("(((R)1000)1000)100");
+    assert_eq!((error.line, error.column), (1, 11));
+    assert!(matches!(
+        error.kind,
+        AlgParseErrorKind::TooManyRepetitions(100000)
+    ));
+
+    let error = alg_error("((((R)1000)1000)1000)1000");
+    assert!(matches!(
+        error.kind,
+        AlgParseErrorKind::TooManyRepetitions(1000000)
+    ));
+
+    // The first part of commutators and conjugates is done twice
+    let error = alg_error("[(R)600, U]");
+    assert_eq!((error.line, error.column), (1, 5));
+    assert!(matches!(
+        error.kind,
+        AlgParseErrorKind::TooManyRepetitions(1200)
+    ));
+    let error = alg_error("([U, F])600");
+    assert_eq!((error.line, error.column), (1, 2));
+    assert!(matches!(
+        error.kind,
+        AlgParseErrorKind::TooManyRepetitions(1200)
+    ));
+
+    assert_eq!(
+        Alg::<AxisMove>::parse("((R U)10)50").unwrap().moves.len(),
+        1000
+    );
+    assert_eq!(
+        Alg::<AxisMove>::parse("([R: U])500").unwrap().moves.len(),
+        1500
+    );
+}
+
+#[test]
+fn renders_error_snippets() {
+    let error = alg_error("R U\nF R2' U");
+
+    assert_eq!(
+        error.to_string().lines().take(5).collect::<Vec<_>>(),
+        [" --> 2:3", "  |", "2 | F R2' U", "  |   ^^^", "  |"]
+    );
+}
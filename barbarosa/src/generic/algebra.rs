@@ -0,0 +1,80 @@
+//! Group operations on cube states.
+//!
+//! The states of a cube form a group: every state can be thought of as the transformation that takes
+//! a solved cube to said state. Two states can then be composed, inverted, and repeated until they get
+//! back to solved. See [`StateAlgebra`] for more info.
+
+/// A state that can be composed with other states, inverted and whose order and period can be computed.
+///
+/// A state is identified with the transformation that takes the solved state to it. So, composing
+/// the state you get after doing an alg `A` with the state you get after doing an alg `B` gives the
+/// state you get after doing `A` and then `B`.
+///
+/// # Example
+///
+/// ```rust
+/// use barbarosa::prelude::*;
+/// use barbarosa::generic::{Alg, StateAlgebra};
+///
+/// let a = Cube3::SOLVED.moved(&Alg::<AxisMove>::parse("R U R' U'").unwrap());
+/// let b = Cube3::SOLVED.moved(&Alg::<AxisMove>::parse("F2 D").unwrap());
+///
+/// assert_eq!(a.compose(&b), a.clone().moved(&Alg::<AxisMove>::parse("F2 D").unwrap()));
+/// assert!(a.compose(&a.inverse()).is_solved());
+/// assert_eq!(a.order(), 6);
+/// ```
+pub trait StateAlgebra: Sized {
+    /// Returns the state of doing `self` and then `other`.
+    fn compose(&self, other: &Self) -> Self;
+
+    /// Returns the state that undoes `self`, such that `self.compose(&self.inverse())` is solved.
+    fn inverse(&self) -> Self;
+
+    /// The number of times the state has to be composed with itself for every piece to get back to its
+    /// original position. That is, the order of the state as a permutation.
+    fn order(&self) -> usize;
+
+    /// The number of times the state has to be composed with itself to be [solved](crate::generic::Cube::is_solved).
+    ///
+    /// This is the same as the [order](Self::order) unless some pieces are indistinguishable from one
+    /// another (such as the centers of big cubes), in which case it can be smaller (but it always divides it).
+    fn period(&self) -> usize {
+        self.order()
+    }
+}
+
+/// Implements [`StateAlgebra`] for a cube made out of (something that derefs to) [`PieceSet`](crate::generic::PieceSet)s,
+/// given the names of the fields.
+///
+/// Both the order and the period are the least common multiple of the ones of each field.
+macro_rules! impl_state_algebra {
+    ($cube:ty, [$($field:ident),* $(,)?]) => {
+        impl crate::generic::StateAlgebra for $cube {
+            fn compose(&self, other: &Self) -> Self {
+                Self {
+                    $($field: crate::generic::StateAlgebra::compose(&self.$field, &other.$field),)*
+                }
+            }
+
+            fn inverse(&self) -> Self {
+                Self {
+                    $($field: crate::generic::StateAlgebra::inverse(&self.$field),)*
+                }
+            }
+
+            fn order(&self) -> usize {
+                [$(crate::generic::StateAlgebra::order(&self.$field)),*]
+                    .into_iter()
+                    .fold(1, crate::generic::utils::lcm)
+            }
+
+            fn period(&self) -> usize {
+                [$(crate::generic::StateAlgebra::period(&self.$field)),*]
+                    .into_iter()
+                    .fold(1, crate::generic::utils::lcm)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_state_algebra;
@@ -3,6 +3,7 @@
 use self::moves::AsMove;
 pub use self::{
     alg::Alg,
+    algebra::StateAlgebra,
     moves::{Movable, Move},
     parse::Parsable,
     piece::{Piece, PieceSet},
@@ -10,6 +11,8 @@ pub use self::{
 };
 
 pub mod alg;
+pub mod alg_tree;
+pub mod algebra;
 pub mod moves;
 pub mod parse;
 pub mod piece;
@@ -40,11 +43,9 @@ mod visualization;
 pub trait Cube:
     Sized + Clone + PartialEq + Eq + std::fmt::Debug + AsMove + Movable<Self::Move>
 {
-    /// Returns a static reference to a solved cube.
+    /// A solved cube.
     ///
     /// It's nice when implementing this to make the reference `const`, if possible.
-    // fn solved() -> &'static Self;
-
     const SOLVED: Self;
 
     /// Creates a new solved cube
@@ -8,6 +8,46 @@ pub trait Move: Sized + Clone {
     fn inverse(&self) -> Self;
 }
 
+/// A move that can be merged with other moves, used to simplify [`Alg`](super::Alg)s.
+///
+/// Every move has an axis, and moves on the same axis have to commute with each other. That
+/// is, doing `a` and then `b` has to be the same as doing `b` and then `a` if `a.axis() == b.axis()`.
+/// For example, `R` and `L` commute (and so do `R`, `Rw` and `x`), so they'd have the same axis.
+///
+/// See [`Alg::simplify`](super::Alg::simplify) for more info.
+pub trait Mergeable: Move {
+    /// The type of the axis of the move. Moves with the same axis commute.
+    type Axis: PartialEq;
+
+    /// The axis of the move.
+    fn axis(&self) -> Self::Axis;
+
+    /// Tries to merge two moves into a single one, such that doing `self` and `other` is the same as
+    /// doing the result.
+    ///
+    /// Returns `None` if the moves can't be merged (for example, `R` and `L`), `Some(None)` if the moves cancel
+    /// each other out (`R` and `R'`) and `Some(Some(merged))` if the moves can be merged (`R` and `R` into `R2`).
+    fn merge(&self, other: &Self) -> Option<Option<Self>>;
+}
+
+/// A way of counting the moves of an [`Alg`](super::Alg), such as the half turn metric of `NxN` cubes.
+///
+/// See [`Alg::count`](super::Alg::count) for more info.
+pub trait Metric<M: Move> {
+    /// The amount of moves that `mov` counts as.
+    fn cost(&self, mov: &M) -> u32;
+}
+
+/// The simplest [`Metric`], where every move counts as one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MoveCount;
+
+impl<M: Move> Metric<M> for MoveCount {
+    fn cost(&self, _mov: &M) -> u32 {
+        1
+    }
+}
+
 /// Something that can be used as a move. Basically, [`Move`]s and [`Cube`](super::Cube)s implement [`AsMove`].
 pub trait AsMove {
     /// The move type of this object
@@ -2,11 +2,18 @@
 //!
 //! See [Parsable] for more info.
 
-use std::num::ParseIntError;
+use std::{fmt::Display, num::ParseIntError, ops::Range};
 
 use pest::{iterators::Pair, Parser, RuleType};
 use thiserror::Error;
 
+/// The most times a move can be repeated once an alg is expanded, as in `(R U)1000` or `((R U)10)100`.
+///
+/// Groups are expanded when the alg is parsed, so without a limit a short string could take all the memory. The
+/// repetitions of nested groups multiply, and commutators and conjugates count as repeating the moves that they
+/// undo twice, so the limit bounds the length of the expanded alg.
+pub const MAX_REPETITIONS: u32 = 1000;
+
 // /// A result type alias for parsing.
 // pub type Result<T, Rule> = std::result::Result<T, Error<Rule>>;
 
@@ -95,6 +102,9 @@ where
 
     #[error("Statically uknown error: {0}")]
     Uknown(Box<dyn std::error::Error + Send + Sync>),
+
+    #[error("{0}")]
+    Alg(Box<AlgParseError<R>>),
 }
 
 /// Type alias for a `pest` result, just much more concise.
@@ -130,3 +140,122 @@ impl<T: RuleType> ParseError<T> {
         Self::Uknown(Box::new(err))
     }
 }
+
+/// An error in a specific part of an alg, such as an invalid move or a missing bracket.
+///
+/// It keeps the position of the error in the original string, and its [`Display`] implementation
+/// renders a snippet of the offending line with the token underlined, similar to how `pest` does it.
+///
+/// # Example
+///
+/// ```rust
+/// use barbarosa::prelude::*;
+/// use barbarosa::generic::{parse::ParseError, Alg};
+///
+/// let Err(ParseError::Alg(error)) = Alg::<AxisMove>::parse("R U\nR2' U") else {
+///     panic!("should error in a specific move");
+/// };
+///
+/// assert_eq!(error.span, 4..7);
+/// assert_eq!((error.line, error.column), (2, 1));
+/// ```
+#[derive(Debug)]
+pub struct AlgParseError<R: RuleType>
+where
+    Self: 'static,
+{
+    /// The byte range of the offending token in the original string
+    pub span: Range<usize>,
+
+    /// The line of the error, starting at 1
+    pub line: usize,
+
+    /// The column of the error, in characters and starting at 1
+    pub column: usize,
+
+    /// What went wrong
+    pub kind: AlgParseErrorKind<R>,
+
+    line_text: String,
+    underline_length: usize,
+}
+
+/// The kind of [`AlgParseError`].
+#[derive(Debug, Error)]
+#[allow(missing_docs)]
+pub enum AlgParseErrorKind<R: RuleType>
+where
+    Self: 'static,
+{
+    #[error("Invalid move `{0}`: {1}")]
+    InvalidMove(String, ParseError<R>),
+
+    #[error("Invalid repetition count `{0}`: {1}")]
+    InvalidRepetitions(String, ParseIntError),
+
+    #[error("Too many repetitions ({0}), counting nested groups a move can be repeated at most {MAX_REPETITIONS} times")]
+    TooManyRepetitions(u64),
+
+    #[error("{0}")]
+    Syntax(String),
+}
+
+impl<R: RuleType> AlgParseError<R> {
+    /// Creates a new error at the given byte range of `source`.
+    pub fn new(source: &str, span: Range<usize>, kind: AlgParseErrorKind<R>) -> Self {
+        let line_start = source[..span.start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[span.start..]
+            .find('\n')
+            .map_or(source.len(), |i| span.start + i);
+
+        let line = source[..span.start].matches('\n').count() + 1;
+        let column = source[line_start..span.start].chars().count() + 1;
+
+        // The underline can't go past the end of the line
+        let underline_length = source[span.start..span.end.min(line_end)]
+            .chars()
+            .count()
+            .max(1);
+
+        Self {
+            span,
+            line,
+            column,
+            kind,
+            line_text: source[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            underline_length,
+        }
+    }
+}
+
+impl<R: RuleType> Display for AlgParseError<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        writeln!(f, "{gutter}--> {}:{}", self.line, self.column)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.line_text)?;
+        writeln!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.underline_length)
+        )?;
+        writeln!(f, "{gutter} |")?;
+        write!(f, "{gutter} = {}", self.kind)
+    }
+}
+
+impl<R: RuleType> std::error::Error for AlgParseError<R> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.kind)
+    }
+}
+
+impl<R: RuleType> From<AlgParseError<R>> for ParseError<R> {
+    fn from(value: AlgParseError<R>) -> Self {
+        Self::Alg(Box::new(value))
+    }
+}
@@ -0,0 +1,67 @@
+//! Cycle decomposition of [`PieceSet`](super::PieceSet)s.
+
+use std::fmt;
+
+use itertools::Itertools;
+
+use super::{OrientedPiece, Piece, PieceNotation};
+
+/// A cycle of pieces, as found in the cycle decomposition of a [`PieceSet`](super::PieceSet).
+///
+/// The piece that was originally at `positions[i]` is currently at `positions[i + 1]` (and the last
+/// one is at `positions[0]`). This is the usual cycle notation, so a U perm would be something like
+/// `(UF UL UR)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cycle<P: Piece> {
+    /// The positions in the cycle, in order.
+    pub positions: Vec<P::Position>,
+
+    /// The orientation the pieces accumulate by going once around the cycle, modulo
+    /// [`OrientedPiece::ORIENTATION_COUNT`].
+    ///
+    /// For example, a cycle of length 1 with a non-zero twist is a piece that is flipped (or twisted) in place.
+    pub twist: u8,
+}
+
+impl<P: Piece> Cycle<P> {
+    /// The number of pieces in the cycle
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Whether the cycle has no pieces. Cycles returned by [`PieceSet::cycles`](super::PieceSet::cycles) are never empty.
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Whether the cycle leaves every piece untouched. That is, it's a single piece that is not twisted.
+    pub fn is_trivial(&self) -> bool {
+        self.len() <= 1 && self.twist == 0
+    }
+}
+
+impl<P: OrientedPiece> Cycle<P> {
+    /// The number of times the cycle has to be applied to get every piece back to where it was, with the same orientation.
+    pub fn order(&self) -> usize {
+        let count = P::ORIENTATION_COUNT as usize;
+        let twist_order = count / crate::generic::utils::gcd(self.twist as usize, count);
+
+        self.len() * twist_order
+    }
+}
+
+/// Displays the cycle in the usual notation, such as `(UF UR UB)`.
+///
+/// If the cycle has some twist, a `+` or a `-` is added at the end, depending on whether the twist is closer
+/// to `0` going forwards or backwards (e.g.: `(UFR)+` for a corner twisted once, `(UFR)-` for a corner twisted twice).
+impl<P: OrientedPiece + PieceNotation> fmt::Display for Cycle<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({})", self.positions.iter().map(P::notation).join(" "))?;
+
+        match self.twist {
+            0 => Ok(()),
+            twist if twist * 2 <= P::ORIENTATION_COUNT => write!(f, "+"),
+            _ => write!(f, "-"),
+        }
+    }
+}
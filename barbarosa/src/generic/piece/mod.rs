@@ -1,10 +1,14 @@
 //! Traits and structs for pieces on the cube.
 
+mod cycle;
+mod orbit;
 mod set;
 mod traits;
 
 use std::fmt::Debug;
 
+pub use cycle::Cycle;
+pub use orbit::{fix_orbit_parity, is_odd_permutation, shuffle_orbit};
 pub use set::{PieceSet, PieceSetDescriptor};
 pub use traits::*;
 
@@ -22,7 +26,7 @@ pub trait Piece: Sized + PartialEq + Clone + Debug {
     ///
     /// It should have finite different possible values, such that you
     /// can implement [`PieceSetDescriptor`] for it with a specific `N`.
-    type Position: PartialEq + Debug;
+    type Position: PartialEq + Clone + Debug;
 
     /// Returns the current position of the piece
     fn position(&self) -> Self::Position;
@@ -0,0 +1,80 @@
+//! Permutations of the orbits of a [`PieceSet`], that is, groups of positions that the pieces in them never leave.
+
+use rand::Rng;
+
+use super::{PieceSet, PieceSetDescriptor};
+
+/// Whether the permutation of the pieces that are originally in `orbit` is odd.
+///
+/// Pieces in an orbit can only move to positions of the same orbit, so the permutation of the orbit is a
+/// permutation by itself.
+pub fn is_odd_permutation<P: PieceSetDescriptor<N>, const N: usize>(
+    set: &PieceSet<P, N>,
+    orbit: impl Fn(&P::Position) -> bool,
+) -> bool {
+    let permutation = set.permutation();
+    let mut visited = [false; N];
+    let mut swaps = 0;
+
+    for start in (0..N).filter(|&i| orbit(&P::REFERENCE_POSITIONS[i])) {
+        if visited[start] {
+            continue;
+        }
+
+        let mut current = start;
+
+        while !visited[current] {
+            visited[current] = true;
+            current = permutation[current];
+            swaps += 1;
+        }
+
+        // A cycle of length `n` takes `n - 1` swaps
+        swaps -= 1;
+    }
+
+    swaps % 2 == 1
+}
+
+/// Swaps the first two pieces of `orbit` if the parity of its permutation isn't `odd`.
+pub fn fix_orbit_parity<P: PieceSetDescriptor<N>, const N: usize>(
+    set: &mut PieceSet<P, N>,
+    orbit: impl Fn(&P::Position) -> bool,
+    odd: bool,
+) {
+    if is_odd_permutation(set, &orbit) == odd {
+        return;
+    }
+
+    let reference_positions = P::REFERENCE_POSITIONS;
+    let mut positions = reference_positions.iter().filter(|pos| orbit(pos));
+    let (Some(a), Some(b)) = (positions.next(), positions.next()) else {
+        panic!("An orbit with an odd permutation has at least two pieces");
+    };
+
+    set.swap(a, b);
+}
+
+/// Shuffles the pieces that are originally in `orbit` among the positions of the orbit.
+pub fn shuffle_orbit<P: PieceSetDescriptor<N>, const N: usize>(
+    set: &mut PieceSet<P, N>,
+    orbit: impl Fn(&P::Position) -> bool,
+    rng: &mut (impl Rng + ?Sized),
+) {
+    let in_orbit: Vec<_> = P::REFERENCE_POSITIONS.iter().map(orbit).collect();
+
+    let mut pieces: Vec<P> = set
+        .iter()
+        .zip(&in_orbit)
+        .filter(|(_, in_orbit)| **in_orbit)
+        .map(|(piece, _)| piece.clone())
+        .collect();
+
+    rand::seq::SliceRandom::shuffle(pieces.as_mut_slice(), rng);
+
+    set.iter_mut_unchecked()
+        .zip(&in_orbit)
+        .filter(|(_, in_orbit)| **in_orbit)
+        .zip(pieces)
+        .for_each(|((piece, _), shuffled)| *piece = shuffled);
+}
@@ -4,9 +4,9 @@ use std::fmt::Debug;
 use rand::{seq::SliceRandom, Rng};
 use thiserror::Error;
 
-use crate::generic::{Movable, Move};
+use crate::generic::{utils, Movable, Move, StateAlgebra};
 
-use super::Piece;
+use super::{Cycle, OrientedPiece, Piece};
 
 /// A trait to be able to use [`PieceSet`] with different types of pieces.
 ///
@@ -151,6 +151,132 @@ impl<P: PieceSetDescriptor<N>, const N: usize> PieceSet<P, N> {
     }
 }
 
+impl<P: PieceSetDescriptor<N>, const N: usize> PieceSet<P, N> {
+    fn reference_index(position: &P::Position) -> usize {
+        P::REFERENCE_POSITIONS
+            .iter()
+            .position(|pos| pos == position)
+            .expect("There should be a reference position for each position")
+    }
+
+    /// Returns the permutation of the set, as indices into [`PieceSetDescriptor::REFERENCE_POSITIONS`].
+    ///
+    /// That is, `permutation()[i] == j` means that the piece that was originally at
+    /// `REFERENCE_POSITIONS[i]` is currently at `REFERENCE_POSITIONS[j]`.
+    pub fn permutation(&self) -> [usize; N] {
+        std::array::from_fn(|i| Self::reference_index(&self.pieces[i].position()))
+    }
+}
+
+impl<P: PieceSetDescriptor<N> + OrientedPiece, const N: usize> PieceSet<P, N> {
+    /// Returns the cycle decomposition of the set, ignoring trivial cycles (pieces that are solved).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use barbarosa::prelude::*;
+    /// use barbarosa::cube_n::moves::perms::pll;
+    ///
+    /// let cube = Cube3::SOLVED.moved(&pll::U);
+    /// let cycles = cube.edges.cycles();
+    ///
+    /// assert_eq!(cycles.len(), 1);
+    /// assert_eq!(cycles[0].len(), 3);
+    /// assert!(cube.corners.cycles().is_empty());
+    /// ```
+    pub fn cycles(&self) -> Vec<Cycle<P>> {
+        let permutation = self.permutation();
+        let mut visited = [false; N];
+        let mut output = Vec::new();
+
+        for start in 0..N {
+            if visited[start] {
+                continue;
+            }
+
+            let mut positions = Vec::new();
+            let mut twist = 0;
+            let mut current = start;
+
+            while !visited[current] {
+                visited[current] = true;
+                positions.push(P::REFERENCE_POSITIONS[current].clone());
+                twist = (twist + self.pieces[current].orientation()) % P::ORIENTATION_COUNT;
+                current = permutation[current];
+            }
+
+            let cycle = Cycle { positions, twist };
+
+            if !cycle.is_trivial() {
+                output.push(cycle);
+            }
+        }
+
+        output
+    }
+
+    /// Composes the set with itself `exponent` times. `set.pow(0)` is [`Self::SOLVED`].
+    pub fn pow(&self, mut exponent: usize) -> Self {
+        let mut base = self.clone();
+        let mut output = Self::SOLVED;
+
+        // Exponentiation by squaring
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                output = output.compose(&base);
+            }
+
+            base = base.compose(&base);
+            exponent /= 2;
+        }
+
+        output
+    }
+}
+
+impl<P: PieceSetDescriptor<N> + OrientedPiece, const N: usize> StateAlgebra for PieceSet<P, N> {
+    fn compose(&self, other: &Self) -> Self {
+        let pieces = std::array::from_fn(|i| {
+            let piece = &self.pieces[i];
+            let moved = &other.pieces[Self::reference_index(&piece.position())];
+            let orientation = (piece.orientation() + moved.orientation()) % P::ORIENTATION_COUNT;
+
+            P::with_orientation(moved.position(), orientation)
+        });
+
+        Self { pieces }
+    }
+
+    fn inverse(&self) -> Self {
+        let mut pieces = self.pieces.clone();
+
+        for (original_pos, piece) in self.iter_with_pos() {
+            let orientation = (P::ORIENTATION_COUNT - piece.orientation()) % P::ORIENTATION_COUNT;
+            pieces[Self::reference_index(&piece.position())] =
+                P::with_orientation(original_pos, orientation);
+        }
+
+        Self { pieces }
+    }
+
+    fn order(&self) -> usize {
+        self.cycles().iter().map(Cycle::order).fold(1, utils::lcm)
+    }
+
+    /// Pieces that are indistinguishable (such as the centers of big cubes) can be solved without being in
+    /// their original position, so the period is found by trying the divisors of the order.
+    // `usize::is_multiple_of` is too recent for the versions of Rust this crate builds with
+    #[allow(clippy::manual_is_multiple_of)]
+    fn period(&self) -> usize {
+        let order = self.order();
+
+        (1..=order)
+            .filter(|divisor| order % divisor == 0)
+            .find(|divisor| self.pow(*divisor).is_solved())
+            .expect("A set composed `order` times is always solved")
+    }
+}
+
 fn find_duplicates<T: PartialEq>(iter: impl IntoIterator<Item = T>) -> Option<T> {
     let mut visited = Vec::new();
 
@@ -12,3 +12,28 @@ pub trait Coordinates: Piece {
         Self::coordinates_pos(self.position())
     }
 }
+
+/// A piece whose orientation can be described by a number modulo [`Self::ORIENTATION_COUNT`].
+///
+/// The orientation is relative to the position the piece is at, such that doing a move adds the
+/// same amount of orientation to whichever piece is at a given position. This is what allows
+/// composing and inverting [`PieceSet`](super::PieceSet)s (see
+/// [`StateAlgebra::compose`](crate::generic::algebra::StateAlgebra::compose)).
+///
+/// Pieces that don't have orientation (such as wings or centers) just have an [`Self::ORIENTATION_COUNT`] of 1.
+pub trait OrientedPiece: Piece {
+    /// The number of distinct orientations the piece can have at a given position.
+    const ORIENTATION_COUNT: u8;
+
+    /// The orientation of the piece, between `0` and [`Self::ORIENTATION_COUNT`]. `0` means oriented.
+    fn orientation(&self) -> u8;
+
+    /// Creates a piece at the given position with the given orientation.
+    fn with_orientation(position: Self::Position, orientation: u8) -> Self;
+}
+
+/// A piece whose positions have a name in the usual cubing notation (such as `UFR` or `UF`)
+pub trait PieceNotation: Piece {
+    /// The name of the given position.
+    fn notation(position: &Self::Position) -> String;
+}
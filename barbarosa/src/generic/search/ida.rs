@@ -2,21 +2,30 @@
 
 use std::hash::Hash;
 
-use crate::generic::{Alg, Cube, Movable, Move};
+use crate::generic::{
+    moves::{Metric, MoveCount},
+    Alg, Cube, Movable, Move,
+};
 
 use super::Searcher;
 
 /// An IDA* searcher
-pub struct IDASearcher<C, M, Heuristic, Successors, Iter>
+///
+/// By default every move costs the same, so solutions are optimal in the amount of moves. Use
+/// [`IDASearcher::with_metric`] to count moves differently. Either way, the heuristic has to be admissible
+/// with respect to the metric to get optimal solutions.
+pub struct IDASearcher<C, M, Heuristic, Successors, Iter, Met = MoveCount>
 where
     C: Cube + Movable<M>,
     M: Move,
     Heuristic: Fn(&C) -> f32,
     Successors: Fn(&C) -> Iter,
     Iter: IntoIterator<Item = (C, M)>,
+    Met: Metric<M>,
 {
     heuristic: Heuristic,
     successors: Successors,
+    metric: Met,
     max_depth: i32,
     _cube_marker: std::marker::PhantomData<C>,
 }
@@ -34,10 +43,35 @@ where
         Self {
             heuristic,
             successors,
+            metric: MoveCount,
             max_depth,
             _cube_marker: std::marker::PhantomData,
         }
     }
+}
+
+impl<C, M, Heuristic, Successors, Iter, Met> IDASearcher<C, M, Heuristic, Successors, Iter, Met>
+where
+    C: Cube + Movable<M> + Hash,
+    M: Move,
+    Heuristic: Fn(&C) -> f32,
+    Successors: Fn(&C) -> Iter,
+    Iter: IntoIterator<Item = (C, M)>,
+    Met: Metric<M>,
+{
+    /// Uses the given [`Metric`] for the cost of the moves, instead of every move costing one.
+    pub fn with_metric<NewMet: Metric<M>>(
+        self,
+        metric: NewMet,
+    ) -> IDASearcher<C, M, Heuristic, Successors, Iter, NewMet> {
+        IDASearcher {
+            heuristic: self.heuristic,
+            successors: self.successors,
+            metric,
+            max_depth: self.max_depth,
+            _cube_marker: std::marker::PhantomData,
+        }
+    }
 
     fn search_impl(
         &self,
@@ -71,7 +105,7 @@ where
 
             path.push(successor);
 
-            let new_cost = current_cost + 1.0;
+            let new_cost = current_cost + self.metric.cost(&mov) as f32;
             let new_search = self.search_impl(path, is_target, new_cost, bound, min_exceeded);
 
             if let Some(mut solution) = new_search {
@@ -86,20 +120,21 @@ where
     }
 }
 
-impl<C, M, Heuristic, Successors, Iter> Searcher<C, M>
-    for IDASearcher<C, M, Heuristic, Successors, Iter>
+impl<C, M, Heuristic, Successors, Iter, Met> Searcher<C, M>
+    for IDASearcher<C, M, Heuristic, Successors, Iter, Met>
 where
     C: Cube + Movable<M> + Hash,
     M: Move,
     Heuristic: Fn(&C) -> f32,
     Successors: Fn(&C) -> Iter,
     Iter: IntoIterator<Item = (C, M)>,
+    Met: Metric<M>,
 {
     fn search(&self, cube: &C, is_target: impl Fn(&C) -> bool) -> Option<(Alg<M>, C)> {
         let mut bound = (self.heuristic)(cube);
 
         for _ in 0..=self.max_depth {
-            let mut min_exceeded = bound + 1.0;
+            let mut min_exceeded = f32::INFINITY;
             let mut path = Vec::with_capacity(20);
 
             path.push(cube.clone());
@@ -111,7 +146,8 @@ where
                     solution.moves.reverse();
                     return Some((solution, path.into_iter().last()?));
                 }
-                None => bound = min_exceeded,
+                None if min_exceeded.is_finite() => bound = min_exceeded,
+                None => return None,
             }
         }
 
@@ -4,7 +4,10 @@ use rand::{rngs::StdRng, SeedableRng};
 
 use crate::{
     cube3::{heuristics, Cube3},
-    cube_n::{moves::wide::Parsable, AxisMove},
+    cube_n::{
+        moves::{metric::TurnMetric, wide::Parsable, QuarterAxisMove},
+        AxisMove,
+    },
     generic::{Alg, Cube, Movable},
 };
 
@@ -49,6 +52,50 @@ fn solves_lengths() {
     }
 }
 
+#[test]
+fn solves_optimally_in_quarter_turns() {
+    let alg: Alg<AxisMove> = Alg::parse("R2 U' F").unwrap();
+    let cube = Cube3::SOLVED.moved(&alg);
+
+    let solution = cube
+        .solve_with_heuristic_in(heuristics::zero, TurnMetric::Qtm)
+        .expect("Cube should be solvable");
+
+    assert!(cube.moved(&solution).is_solved());
+    assert_eq!(solution.count(TurnMetric::Qtm), 4);
+}
+
+#[test]
+fn solves_with_quarter_turns() {
+    let alg: Alg<AxisMove> = Alg::parse("R2 U'").unwrap();
+    let cube = Cube3::SOLVED.moved(&alg);
+
+    let solution = cube
+        .solve_quarter_turns_with_heuristic(heuristics::zero)
+        .expect("Cube should be solvable");
+
+    assert!(cube.moved(&solution).is_solved());
+    assert_eq!(solution.moves.len(), 3);
+}
+
+#[test]
+#[ignore = "MUS takes too long to build"]
+fn solves_optimally_in_quarter_turns_with_mus() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for move_amount in 0..=8 {
+        let alg = Alg::<QuarterAxisMove>::from(Alg::random_with_rng(move_amount, &mut rng));
+        let cube = Cube3::SOLVED.moved(&alg);
+
+        let solution = cube
+            .solve_quarter_turns_with_heuristic(heuristics::mus_in(TurnMetric::Qtm))
+            .expect("Cube should be solvable");
+
+        assert!(cube.moved(&solution).is_solved());
+        assert!(solution.moves.len() <= alg.moves.len());
+    }
+}
+
 // #[test]
 // fn test_scramble() {
 //     let cube: Cube3 = rand::random();
@@ -34,3 +34,19 @@ pub fn slice_coords<T: Copy>(vector: Vector3<T>, normal: Axis) -> Vector2<T> {
 
     Vector2::new(vector[x], vector[y])
 }
+
+/// Greatest common divisor of two numbers
+pub fn gcd(a: usize, b: usize) -> usize {
+    match b {
+        0 => a,
+        b => gcd(b, a % b),
+    }
+}
+
+/// Least common multiple of two numbers
+pub fn lcm(a: usize, b: usize) -> usize {
+    match (a, b) {
+        (0, _) | (_, 0) => 0,
+        (a, b) => a / gcd(a, b) * b,
+    }
+}
@@ -2,8 +2,13 @@
 #![warn(clippy::doc_markdown)]
 #![doc = include_str!("../README.md")]
 
+pub mod clock;
 pub mod cube_n;
 pub mod generic;
+pub mod megaminx;
 pub mod prelude;
+pub mod pyraminx;
+pub mod skewb;
+pub mod square1;
 
 pub use cube_n::cube3;
@@ -0,0 +1,104 @@
+//! The faces of the Megaminx and how they are arranged.
+
+use strum::EnumIter;
+
+/// One of the 12 faces of the Megaminx.
+///
+/// The upper half has `U` in the middle, `F` in front and then `R`, `BR`, `BL` and `L` going around it. The
+/// lower half is the same upside down: `D` in the middle, `B` in the back and then `DBL`, `DL`, `DR` and `DBR`.
+/// Each face is opposite to the one with the "mirrored" name, except that `R` and `L` are opposite to `DBL` and
+/// `DBR`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, EnumIter)]
+#[allow(missing_docs)]
+pub enum Face {
+    U,
+    F,
+    R,
+    BR,
+    BL,
+    L,
+    DL,
+    DR,
+    DBR,
+    B,
+    DBL,
+    D,
+}
+
+impl Face {
+    /// All the faces, in order.
+    pub const ALL: [Face; 12] = {
+        use Face::*;
+
+        [U, F, R, BR, BL, L, DL, DR, DBR, B, DBL, D]
+    };
+
+    /// The index of the face in [`Face::ALL`].
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The 5 faces next to `self`, in clockwise order when looking at `self`.
+    pub const fn neighbours(self) -> [Face; 5] {
+        use Face::*;
+
+        match self {
+            U => [F, L, BL, BR, R],
+            F => [U, R, DR, DL, L],
+            R => [U, BR, DBR, DR, F],
+            BR => [U, BL, B, DBR, R],
+            BL => [U, L, DBL, B, BR],
+            L => [U, F, DL, DBL, BL],
+            DL => [DBL, L, F, DR, D],
+            DR => [DL, F, R, DBR, D],
+            DBR => [DR, R, BR, B, D],
+            B => [DBR, BR, BL, DBL, D],
+            DBL => [B, BL, L, DL, D],
+            D => [DBL, DL, DR, DBR, B],
+        }
+    }
+
+    /// The face on the other side of the Megaminx.
+    pub const fn opposite(self) -> Face {
+        use Face::*;
+
+        match self {
+            U => D,
+            F => B,
+            R => DBL,
+            BR => DL,
+            BL => DR,
+            L => DBR,
+            DL => BR,
+            DR => BL,
+            DBR => L,
+            B => F,
+            DBL => R,
+            D => U,
+        }
+    }
+
+    /// Where each face ends up after turning the whole puzzle `fifths` fifths of a turn clockwise, looking at
+    /// `self`. Indexed with [`Face::index`].
+    pub fn rotation(self, fifths: u8) -> [Face; 12] {
+        let mut output = Face::ALL;
+        let fifths = fifths as usize % 5;
+
+        let upper = self.neighbours();
+        // Looking from the opposite face, the rotation is counterclockwise
+        let lower = self.opposite().neighbours();
+
+        for i in 0..5 {
+            output[upper[i].index()] = upper[(i + fifths) % 5];
+            output[lower[i].index()] = lower[(i + 5 - fifths) % 5];
+        }
+
+        output
+    }
+}
+
+impl std::fmt::Display for Face {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
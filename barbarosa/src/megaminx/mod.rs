@@ -0,0 +1,94 @@
+//! The Megaminx, a dodecahedron where each of the 12 faces turns by fifths of a turn.
+//!
+//! The Megaminx has 20 [corners](Corner), 30 [edges](Edge) and 12 [centers](Center). Its faces don't fit the
+//! axes of the cubes, so they have their own [`Face`] type. Moves can be face turns like `BL2'` or the big
+//! moves of Pochmann notation like `R++` (see [`MegaminxMove`]):
+//!
+//! ```rust
+//! use barbarosa::generic::{Alg, Cube, Movable, Parsable};
+//! use barbarosa::megaminx::{Megaminx, MegaminxMove};
+//!
+//! let alg = Alg::<MegaminxMove>::parse("R++ D-- R-- D++ U' F2 BL").unwrap();
+//! let megaminx = Megaminx::SOLVED.moved(&alg);
+//!
+//! assert!(!megaminx.is_solved());
+//! assert!(megaminx.moved(&alg.reversed()).is_solved());
+//! ```
+//!
+//! See [`WcaScramble`] for WCA scrambles.
+
+mod face;
+mod moves;
+mod parser;
+mod pieces;
+mod scramble;
+mod test;
+
+use crate::generic::{self, moves::AsMove, Movable};
+
+pub use face::Face;
+pub use moves::{Amount, MegaminxMove, PochmannAxis};
+pub use pieces::{Center, CenterSet, Corner, CornerSet, Edge, EdgeSet};
+pub use scramble::WcaScramble;
+
+/// A Megaminx, stored as where each corner, edge and center is. The centers are tracked so that a rotated
+/// Megaminx still counts as solved.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Megaminx {
+    /// The corners
+    pub corners: CornerSet,
+    /// The edges
+    pub edges: EdgeSet,
+    /// The centers, which only move when turning the whole puzzle
+    pub centers: CenterSet,
+}
+
+impl Megaminx {
+    /// Where each face ended up, indexed with [`Face::index`]. It's only a rotation of the whole puzzle if
+    /// the Megaminx is solved.
+    fn face_map(&self) -> [Face; 12] {
+        Face::ALL.map(|face| self.centers.piece_originally_at(&[face]).faces[0])
+    }
+}
+
+impl generic::Cube for Megaminx {
+    const SOLVED: Self = Self {
+        corners: CornerSet::SOLVED,
+        edges: EdgeSet::SOLVED,
+        centers: CenterSet::SOLVED,
+    };
+
+    /// Checks whether the Megaminx is solved, no matter how the whole puzzle is rotated.
+    fn is_solved(&self) -> bool
+    where
+        Self: 'static,
+    {
+        let map = self.face_map();
+        let moved = |faces: &[Face]| {
+            faces
+                .iter()
+                .map(|face| map[face.index()])
+                .collect::<Vec<_>>()
+        };
+
+        self.corners
+            .iter_with_pos()
+            .all(|(pos, corner)| corner.faces.as_slice() == moved(&pos))
+            && self
+                .edges
+                .iter_with_pos()
+                .all(|(pos, edge)| edge.faces.as_slice() == moved(&pos))
+    }
+}
+
+impl AsMove for Megaminx {
+    type Move = MegaminxMove;
+}
+
+impl Movable<MegaminxMove> for Megaminx {
+    fn apply(&mut self, m: &MegaminxMove) {
+        self.corners.apply(m);
+        self.edges.apply(m);
+        self.centers.apply(m);
+    }
+}
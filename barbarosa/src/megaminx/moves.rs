@@ -0,0 +1,196 @@
+//! Moves of the Megaminx.
+
+use strum::EnumIter;
+
+use crate::generic::{self, moves::Mergeable};
+
+use super::face::Face;
+
+/// How much a face turns, in fifths of a turn clockwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+#[allow(missing_docs)]
+pub enum Amount {
+    Single = 1,
+    Double = 2,
+    DoubleInverse = 3,
+    Inverse = 4,
+}
+
+impl Amount {
+    /// The amount that turns `fifths` fifths of a turn clockwise, or `None` if it's a multiple of a full turn.
+    pub const fn from_fifths(fifths: u8) -> Option<Self> {
+        match fifths % 5 {
+            1 => Some(Amount::Single),
+            2 => Some(Amount::Double),
+            3 => Some(Amount::DoubleInverse),
+            4 => Some(Amount::Inverse),
+            _ => None,
+        }
+    }
+
+    /// The amount of clockwise fifths of a turn.
+    pub const fn fifths(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl std::ops::Add for Amount {
+    type Output = Option<Amount>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Amount::from_fifths(self.fifths() + rhs.fifths())
+    }
+}
+
+impl std::fmt::Display for Amount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Amount::Single => write!(f, ""),
+            Amount::Double => write!(f, "2"),
+            Amount::DoubleInverse => write!(f, "2'"),
+            Amount::Inverse => write!(f, "'"),
+        }
+    }
+}
+
+/// The faces that the big moves of Pochmann notation turn around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+#[allow(missing_docs)]
+pub enum PochmannAxis {
+    R,
+    D,
+}
+
+impl PochmannAxis {
+    /// The face the moves turn around, looking at it.
+    pub const fn face(&self) -> Face {
+        match self {
+            PochmannAxis::R => Face::R,
+            PochmannAxis::D => Face::D,
+        }
+    }
+}
+
+/// A move of the Megaminx.
+///
+/// WCA scrambles use Pochmann notation, which only has `R++`, `R--`, `D++`, `D--`, `U` and `U'`. The big moves
+/// turn the whole puzzle except the opposite face, so the pieces are scrambled even though most of the puzzle
+/// moves at once.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MegaminxMove {
+    /// Turns a single face, like `U'` or `F2`
+    Turn {
+        /// The face being turned
+        face: Face,
+        /// How much the face turns
+        amount: Amount,
+    },
+
+    /// Turns everything except the face opposite to the axis two fifths of a turn, like `R++` or `D--`
+    Pochmann {
+        /// The face the move turns around
+        axis: PochmannAxis,
+        /// Whether the move is clockwise (`++`) or counterclockwise (`--`)
+        clockwise: bool,
+    },
+}
+
+impl MegaminxMove {
+    /// Creates a new move that turns a single face.
+    pub const fn turn(face: Face, amount: Amount) -> Self {
+        Self::Turn { face, amount }
+    }
+
+    /// Creates a new move in Pochmann notation.
+    pub const fn pochmann(axis: PochmannAxis, clockwise: bool) -> Self {
+        Self::Pochmann { axis, clockwise }
+    }
+
+    /// Where the move sends each face, indexed with [`Face::index`]. Only applies to the pieces that
+    /// [are moved](MegaminxMove::moves_piece).
+    pub fn face_permutation(&self) -> [Face; 12] {
+        match self {
+            Self::Turn { face, amount } => face.rotation(amount.fifths()),
+            Self::Pochmann { axis, clockwise } => {
+                let fifths = match clockwise {
+                    true => 2,
+                    false => 3,
+                };
+
+                axis.face().rotation(fifths)
+            }
+        }
+    }
+
+    /// Whether the move moves the piece with stickers on `faces`.
+    pub fn moves_piece(&self, faces: &[Face]) -> bool {
+        match self {
+            Self::Turn { face, .. } => faces.contains(face),
+            Self::Pochmann { axis, .. } => !faces.contains(&axis.face().opposite()),
+        }
+    }
+}
+
+impl generic::Move for MegaminxMove {
+    fn inverse(&self) -> Self {
+        match *self {
+            Self::Turn { face, amount } => {
+                let amount = Amount::from_fifths(5 - amount.fifths())
+                    .expect("Amounts are never a multiple of a full turn");
+
+                Self::turn(face, amount)
+            }
+            Self::Pochmann { axis, clockwise } => Self::pochmann(axis, !clockwise),
+        }
+    }
+}
+
+impl Mergeable for MegaminxMove {
+    /// Moves commute if they turn around the same line, so this is the face with the smallest index out of the
+    /// face the move turns around and its opposite.
+    type Axis = Face;
+
+    fn axis(&self) -> Face {
+        let face = match self {
+            Self::Turn { face, .. } => *face,
+            Self::Pochmann { axis, .. } => axis.face(),
+        };
+
+        face.min(face.opposite())
+    }
+
+    fn merge(&self, other: &Self) -> Option<Option<Self>> {
+        match (self, other) {
+            (Self::Turn { face: a, amount: x }, Self::Turn { face: b, amount: y }) if a == b => {
+                Some((*x + *y).map(|amount| Self::turn(*a, amount)))
+            }
+            (
+                Self::Pochmann {
+                    axis: a,
+                    clockwise: x,
+                },
+                Self::Pochmann {
+                    axis: b,
+                    clockwise: y,
+                },
+            ) if a == b && x != y => Some(None),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for MegaminxMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Turn { face, amount } => write!(f, "{face}{amount}"),
+            Self::Pochmann { axis, clockwise } => {
+                let amount = match clockwise {
+                    true => "++",
+                    false => "--",
+                };
+
+                write!(f, "{}{amount}", axis.face())
+            }
+        }
+    }
+}
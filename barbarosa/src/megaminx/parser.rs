@@ -0,0 +1,96 @@
+//! Parsing of Megaminx notation.
+
+use pest::iterators::Pair;
+use pest_derive::Parser;
+
+use crate::generic::parse::{FromPest, IntoParseErr, ParseError};
+
+use super::{Amount, Face, MegaminxMove, PochmannAxis};
+
+type Result<T> = std::result::Result<T, ParseError<Rule>>;
+
+#[derive(Parser)]
+#[grammar = "grammar/megaminx.pest"]
+pub struct MegaminxParser;
+
+macro_rules! impl_with_current_rule {
+    ($implementor:ty; $rule:expr; |$arg:ident| $body:expr) => {
+        impl FromPest for $implementor {
+            type Rule = Rule;
+            type Parser = MegaminxParser;
+
+            fn rule() -> Self::Rule {
+                $rule
+            }
+
+            fn from_pest($arg: Pair<Self::Rule>) -> Result<Self> {
+                $body
+            }
+        }
+    };
+}
+
+impl_with_current_rule! {
+    Face;
+    Rule::face;
+
+    |pair| Face::ALL
+        .into_iter()
+        .find(|face| face.to_string() == pair.as_str())
+        .ok_or_else(|| ParseError::Unreachable(pair.as_str().to_string()))
+}
+
+impl_with_current_rule! {
+    Amount;
+    Rule::amount;
+
+    |pair| match pair.as_str() {
+        "" => Ok(Amount::Single),
+        "2" => Ok(Amount::Double),
+        "2'" => Ok(Amount::DoubleInverse),
+        "'" => Ok(Amount::Inverse),
+        other => Err(ParseError::Unreachable(other.to_string())),
+    }
+}
+
+fn parse_pochmann(pair: Pair<Rule>) -> Result<MegaminxMove> {
+    let mut inner = pair.into_inner();
+
+    let axis = match inner.next().into_err()?.as_str() {
+        "R" => PochmannAxis::R,
+        "D" => PochmannAxis::D,
+        other => return Err(ParseError::Unreachable(other.to_string())),
+    };
+
+    let clockwise = match inner.next().into_err()?.as_str() {
+        "++" => true,
+        "--" => false,
+        other => return Err(ParseError::Unreachable(other.to_string())),
+    };
+
+    Ok(MegaminxMove::pochmann(axis, clockwise))
+}
+
+fn parse_turn(pair: Pair<Rule>) -> Result<MegaminxMove> {
+    let mut inner = pair.into_inner();
+
+    let face = Face::from_pest(inner.next().into_err()?)?;
+    let amount = Amount::from_pest(inner.next().into_err()?)?;
+
+    Ok(MegaminxMove::turn(face, amount))
+}
+
+impl_with_current_rule! {
+    MegaminxMove;
+    Rule::megaminx_move;
+
+    |pair| {
+        let inner = pair.into_inner().next().into_err()?;
+
+        match inner.as_rule() {
+            Rule::pochmann => parse_pochmann(inner),
+            Rule::turn => parse_turn(inner),
+            _ => Err(ParseError::Unreachable(inner.as_str().to_string())),
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Pieces of the Megaminx.
+//!
+//! Every piece is described by the faces its stickers are on, so a move only needs to know where it sends each
+//! face (see [`MegaminxMove::face_permutation`]).
+
+use arr_macro::arr;
+use static_assertions::const_assert_eq;
+
+use crate::generic::{
+    moves::impl_movable_array, piece::PieceSetDescriptor, utils::map_array_const, Movable, Piece,
+    PieceSet,
+};
+
+use super::{face::Face, moves::MegaminxMove};
+
+/// Implements [`Piece`] and [`Movable`] for a piece made of `N` stickers.
+macro_rules! impl_sticker_piece {
+    ($piece:ident, $n:literal) => {
+        impl $piece {
+            /// Creates a new piece with the stickers on the given faces.
+            pub const fn new(faces: [Face; $n]) -> Self {
+                Self { faces }
+            }
+        }
+
+        impl Piece for $piece {
+            /// The faces the piece is on, sorted.
+            type Position = [Face; $n];
+
+            fn position(&self) -> [Face; $n] {
+                let mut position = self.faces;
+                position.sort();
+                position
+            }
+
+            fn is_solved(&self, original_pos: &[Face; $n]) -> bool {
+                self.faces == *original_pos
+            }
+        }
+
+        impl Movable<MegaminxMove> for $piece {
+            fn apply(&mut self, m: &MegaminxMove) {
+                if m.moves_piece(&self.faces) {
+                    let permutation = m.face_permutation();
+                    self.faces = self.faces.map(|face| permutation[face.index()]);
+                }
+            }
+        }
+
+        impl_movable_array!($piece, MegaminxMove);
+    };
+}
+
+/// A corner of the Megaminx, where three faces meet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Corner {
+    /// The face each sticker is on. The stickers are in the same order as the faces of the position where the
+    /// corner is when solved.
+    pub faces: [Face; 3],
+}
+
+impl_sticker_piece!(Corner, 3);
+
+impl PieceSetDescriptor<20> for Corner {
+    const REFERENCE_POSITIONS: [[Face; 3]; 20] = corner_positions();
+
+    const SOLVED: [Self; 20] = map_array_const!(Corner::REFERENCE_POSITIONS, 20, Corner::new);
+}
+
+/// An edge of the Megaminx, between two faces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge {
+    /// The face each sticker is on. The stickers are in the same order as the faces of the position where the
+    /// edge is when solved.
+    pub faces: [Face; 2],
+}
+
+impl_sticker_piece!(Edge, 2);
+
+impl PieceSetDescriptor<30> for Edge {
+    const REFERENCE_POSITIONS: [[Face; 2]; 30] = edge_positions();
+
+    const SOLVED: [Self; 30] = map_array_const!(Edge::REFERENCE_POSITIONS, 30, Edge::new);
+}
+
+/// The center of a face of the Megaminx. The centers only move when turning the whole puzzle, like with the
+/// `R++` and `D++` moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Center {
+    /// The face the center is on
+    pub faces: [Face; 1],
+}
+
+impl_sticker_piece!(Center, 1);
+
+impl PieceSetDescriptor<12> for Center {
+    const REFERENCE_POSITIONS: [[Face; 1]; 12] = {
+        let mut output = [[Face::U]; 12];
+        let mut i = 0;
+
+        while i < 12 {
+            output[i] = [Face::ALL[i]];
+            i += 1;
+        }
+
+        output
+    };
+
+    const SOLVED: [Self; 12] = map_array_const!(Center::REFERENCE_POSITIONS, 12, Center::new);
+}
+
+/// Every pair of neighbouring faces, sorted.
+const fn edge_positions() -> [[Face; 2]; 30] {
+    let mut output = [[Face::U; 2]; 30];
+    let mut count = 0;
+    let mut i = 0;
+
+    while i < 12 {
+        let face = Face::ALL[i];
+        let neighbours = face.neighbours();
+        let mut j = 0;
+
+        while j < 5 {
+            if neighbours[j].index() > i {
+                output[count] = [face, neighbours[j]];
+                count += 1;
+            }
+
+            j += 1;
+        }
+
+        i += 1;
+    }
+
+    assert!(count == 30, "The Megaminx has 30 edges");
+
+    output
+}
+
+/// Every triple of faces that meet at a vertex, sorted.
+const fn corner_positions() -> [[Face; 3]; 20] {
+    let mut output = [[Face::U; 3]; 20];
+    let mut count = 0;
+    let mut i = 0;
+
+    while i < 12 {
+        let face = Face::ALL[i];
+        let neighbours = face.neighbours();
+        let mut j = 0;
+
+        while j < 5 {
+            let (a, b) = (neighbours[j], neighbours[(j + 1) % 5]);
+
+            // Each corner gets added from the face that comes first
+            if a.index() > i && b.index() > i {
+                output[count] = match a.index() < b.index() {
+                    true => [face, a, b],
+                    false => [face, b, a],
+                };
+                count += 1;
+            }
+
+            j += 1;
+        }
+
+        i += 1;
+    }
+
+    assert!(count == 20, "The Megaminx has 20 corners");
+
+    output
+}
+
+/// The 20 corners of the Megaminx.
+pub type CornerSet = PieceSet<Corner, 20>;
+
+/// The 30 edges of the Megaminx.
+pub type EdgeSet = PieceSet<Edge, 30>;
+
+/// The 12 centers of the Megaminx.
+pub type CenterSet = PieceSet<Center, 12>;
@@ -0,0 +1,78 @@
+//! Random-move scrambles in the WCA format.
+
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+
+use crate::generic::Alg;
+
+use super::{Amount, Face, MegaminxMove, PochmannAxis};
+
+/// The amount of lines of a WCA scramble.
+pub const LINE_COUNT: usize = 7;
+
+/// The amount of `R++`/`D++` moves in each line of a WCA scramble, before the final `U` move.
+pub const BIG_MOVES_PER_LINE: usize = 10;
+
+/// A random-move scramble in the standard WCA format.
+///
+/// Each of the 7 lines has 10 moves that alternate between `R` and `D` and go either `++` or `--` at random,
+/// followed by `U` if the last move was `D++` or `U'` if it was `D--`:
+///
+/// ```text
+/// R-- D++ R++ D-- R++ D-- R-- D++ R-- D++ U
+/// ```
+///
+/// Get one with [`rand::Rng::gen`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WcaScramble {
+    /// The lines of the scramble
+    pub lines: [Alg<MegaminxMove>; LINE_COUNT],
+}
+
+impl Distribution<WcaScramble> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> WcaScramble {
+        let lines = std::array::from_fn(|_| {
+            let mut moves = Vec::with_capacity(BIG_MOVES_PER_LINE + 1);
+            let mut clockwise = false;
+
+            for i in 0..BIG_MOVES_PER_LINE {
+                let axis = match i % 2 {
+                    0 => PochmannAxis::R,
+                    _ => PochmannAxis::D,
+                };
+
+                clockwise = rng.gen();
+                moves.push(MegaminxMove::pochmann(axis, clockwise));
+            }
+
+            let amount = match clockwise {
+                true => Amount::Single,
+                false => Amount::Inverse,
+            };
+            moves.push(MegaminxMove::turn(Face::U, amount));
+
+            Alg::new(moves)
+        });
+
+        WcaScramble { lines }
+    }
+}
+
+impl From<WcaScramble> for Alg<MegaminxMove> {
+    fn from(scramble: WcaScramble) -> Self {
+        Alg::new(
+            scramble
+                .lines
+                .into_iter()
+                .flat_map(|line| line.moves)
+                .collect(),
+        )
+    }
+}
+
+impl std::fmt::Display for WcaScramble {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lines: Vec<_> = self.lines.iter().map(ToString::to_string).collect();
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
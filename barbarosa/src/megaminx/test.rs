@@ -0,0 +1,138 @@
+#![cfg(test)]
+
+use pretty_assertions::assert_eq;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use strum::IntoEnumIterator;
+
+use crate::generic::{Alg, Cube, Movable, Move, Parsable, Piece};
+
+use super::*;
+
+fn all_moves() -> impl Iterator<Item = MegaminxMove> {
+    let turns = Face::iter()
+        .flat_map(|face| Amount::iter().map(move |amount| MegaminxMove::turn(face, amount)));
+    let pochmann = PochmannAxis::iter()
+        .flat_map(|axis| [true, false].map(|clockwise| MegaminxMove::pochmann(axis, clockwise)));
+
+    turns.chain(pochmann)
+}
+
+#[test]
+fn neighbours_are_consistent() {
+    for face in Face::iter() {
+        let neighbours = face.neighbours();
+
+        for i in 0..5 {
+            let (a, b) = (neighbours[i], neighbours[(i + 1) % 5]);
+
+            // Going clockwise around a vertex from any of its faces gives the same cycle
+            let position =
+                |of: Face, target: Face| of.neighbours().iter().position(|f| *f == target).unwrap();
+            assert_eq!(
+                a.neighbours()[(position(a, b) + 1) % 5],
+                face,
+                "{face} {a} {b}"
+            );
+            assert_eq!(
+                b.neighbours()[(position(b, face) + 1) % 5],
+                a,
+                "{face} {a} {b}"
+            );
+
+            assert!(!neighbours.contains(&face.opposite()));
+            assert_eq!(face.opposite().opposite(), face);
+        }
+    }
+}
+
+#[test]
+fn only_r_and_d_have_pochmann_moves() {
+    let notation = "R++ D-- U' F2 BL2' DBR DBL' D2";
+    assert_eq!(
+        Alg::<MegaminxMove>::parse(notation).unwrap().to_string(),
+        notation
+    );
+
+    for invalid in ["R+", "U++", "F3", "X", "R''"] {
+        assert!(
+            MegaminxMove::parse(invalid).is_err(),
+            "{invalid} should error"
+        );
+    }
+}
+
+#[test]
+fn five_turns_are_a_full_turn() {
+    for m in all_moves() {
+        let megaminx = Megaminx::SOLVED.moved(&m);
+        assert_eq!(megaminx.clone().moved(&m.inverse()), Megaminx::SOLVED);
+
+        let repeated = Alg::<MegaminxMove>::new(vec![m.clone(); 5]);
+        assert_eq!(Megaminx::SOLVED.moved(&repeated), Megaminx::SOLVED, "{m}");
+    }
+}
+
+#[test]
+fn face_turns_move_one_layer() {
+    for face in Face::iter() {
+        let megaminx = Megaminx::SOLVED.moved(&MegaminxMove::turn(face, Amount::Single));
+        assert!(!megaminx.is_solved());
+
+        let moved_corners = megaminx
+            .corners
+            .iter_with_pos()
+            .filter(|(pos, corner)| !corner.is_solved(pos));
+        let moved_edges = megaminx
+            .edges
+            .iter_with_pos()
+            .filter(|(pos, edge)| !edge.is_solved(pos));
+
+        assert_eq!(moved_corners.count(), 5);
+        assert_eq!(moved_edges.count(), 5);
+        assert!(megaminx.centers.is_solved());
+    }
+}
+
+#[test]
+fn rotations_are_solved() {
+    // Turning everything but `DBL` and then `DBL` itself turns the whole puzzle
+    let rotation = Megaminx::SOLVED.moved(&Alg::<MegaminxMove>::parse("R++ DBL2'").unwrap());
+    assert_ne!(rotation, Megaminx::SOLVED);
+    assert!(rotation.is_solved());
+
+    let rotation = Megaminx::SOLVED.moved(&Alg::<MegaminxMove>::parse("D-- U2").unwrap());
+    assert!(rotation.is_solved());
+    assert!(!rotation
+        .moved(&MegaminxMove::parse("F").unwrap())
+        .is_solved());
+}
+
+#[test]
+fn wca_scrambles_have_seven_lines_ending_in_u() {
+    let mut rng = StdRng::seed_from_u64(48);
+    let scramble: WcaScramble = rng.gen();
+    let text = scramble.to_string();
+
+    let lines: Vec<_> = text.lines().collect();
+    assert_eq!(lines.len(), 7);
+
+    for line in lines {
+        let moves = Alg::<MegaminxMove>::parse(line).unwrap().moves;
+        assert_eq!(moves.len(), 11);
+
+        let expected_u = match moves[9] {
+            MegaminxMove::Pochmann {
+                clockwise: true, ..
+            } => "U",
+            _ => "U'",
+        };
+        assert_eq!(moves[10].to_string(), expected_u);
+    }
+
+    let scramble = Alg::from(scramble);
+    assert_eq!(Alg::parse(&text.replace('\n', " ")).unwrap(), scramble);
+
+    let megaminx = Megaminx::SOLVED.moved(&scramble);
+    assert!(!megaminx.is_solved());
+    assert!(megaminx.moved(&scramble.reversed()).is_solved());
+}
@@ -0,0 +1,119 @@
+//! The Pyraminx, a tetrahedron with a layer and a tip on each vertex.
+//!
+//! The Pyraminx has 4 [tips](Tip), 4 [centers](Center) and 6 [edges](Edge). The tips and the centers never
+//! leave their vertex, so they can only be twisted. Moves use WCA notation (see [`PyraminxMove`]):
+//!
+//! ```rust
+//! use barbarosa::generic::{Alg, Cube, Movable, Parsable};
+//! use barbarosa::pyraminx::{Pyraminx, PyraminxMove};
+//!
+//! let alg = Alg::<PyraminxMove>::parse("R U' L B' u r'").unwrap();
+//! let pyraminx = Pyraminx::SOLVED.moved(&alg);
+//!
+//! assert!(!pyraminx.is_solved());
+//! assert!(pyraminx.moved(&alg.reversed()).is_solved());
+//! ```
+//!
+//! See [`OptimalSolver`] for solving it and [`random_state_scramble`] for WCA scrambles.
+
+mod moves;
+mod parser;
+mod pieces;
+mod solver;
+mod test;
+
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+
+use crate::generic::{
+    self,
+    moves::AsMove,
+    piece::{fix_orbit_parity, OrientedPiece, PieceSetDescriptor},
+    Cube, Movable, Piece, PieceSet,
+};
+
+pub use moves::{Amount, PyraminxMove};
+pub use pieces::{Center, CenterSet, Edge, EdgeSet, Tip, TipSet, Vertex};
+pub use solver::{random_state_scramble, OptimalSolver};
+
+/// A Pyraminx, stored as where each piece is and how it's twisted. Tips and centers stay at their vertex.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Pyraminx {
+    /// The tips, which can be turned by themselves
+    pub tips: TipSet,
+    /// The centers under each tip
+    pub centers: CenterSet,
+    /// The edges
+    pub edges: EdgeSet,
+}
+
+impl Pyraminx {
+    /// Returns the puzzles that can be reached from this one with a single layer move, and the move to reach
+    /// each of them. Tip moves are left out, since they are independent of everything else (see
+    /// [`OptimalSolver`]).
+    pub fn successors(&self) -> impl IntoIterator<Item = (Self, PyraminxMove)> {
+        let pyraminx = self.clone();
+
+        PyraminxMove::layer_moves().map(move |m| (pyraminx.clone().moved(&m), m))
+    }
+}
+
+impl generic::Cube for Pyraminx {
+    const SOLVED: Self = Self {
+        tips: TipSet::SOLVED,
+        centers: CenterSet::SOLVED,
+        edges: EdgeSet::SOLVED,
+    };
+}
+
+impl AsMove for Pyraminx {
+    type Move = PyraminxMove;
+}
+
+impl Movable<PyraminxMove> for Pyraminx {
+    fn apply(&mut self, m: &PyraminxMove) {
+        self.tips.apply(m);
+        self.centers.apply(m);
+        self.edges.apply(m);
+    }
+}
+
+/// Twists each piece of `set` randomly.
+fn twist_randomly<P, const N: usize>(set: &mut PieceSet<P, N>, rng: &mut (impl Rng + ?Sized))
+where
+    P: PieceSetDescriptor<N> + OrientedPiece,
+{
+    set.iter_mut_unchecked().for_each(|piece| {
+        let orientation = rng.gen_range(0..P::ORIENTATION_COUNT);
+        *piece = P::with_orientation(piece.position(), orientation);
+    });
+}
+
+impl Distribution<Pyraminx> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Pyraminx {
+        let mut pyraminx = Pyraminx::SOLVED;
+
+        twist_randomly(&mut pyraminx.tips, rng);
+        twist_randomly(&mut pyraminx.centers, rng);
+
+        // The edges can only be in an even permutation, with an even amount of them flipped
+        pyraminx.edges.shuffle(rng);
+        fix_orbit_parity(&mut pyraminx.edges, |_| true, false);
+        twist_randomly(&mut pyraminx.edges, rng);
+
+        let flipped = pyraminx
+            .edges
+            .iter()
+            .filter(|edge| edge.orientation() == 1)
+            .count();
+        if flipped % 2 == 1 {
+            let edge = pyraminx
+                .edges
+                .iter_mut_unchecked()
+                .next()
+                .expect("There are 6 edges");
+            *edge = Edge::with_orientation(edge.position(), 1 - edge.orientation());
+        }
+
+        pyraminx
+    }
+}
@@ -0,0 +1,156 @@
+//! Moves of the Pyraminx.
+
+use itertools::iproduct;
+use rand_derive2::RandGen;
+use strum::{EnumIter, IntoEnumIterator};
+
+use crate::generic::{self, moves::Mergeable};
+
+use super::pieces::Vertex;
+
+/// How much a [`PyraminxMove`] turns, either a third of a turn clockwise or counterclockwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, RandGen)]
+#[allow(missing_docs)]
+pub enum Amount {
+    Single,
+    Inverse,
+}
+
+impl Amount {
+    /// The amount of clockwise thirds of a turn.
+    pub const fn turns(&self) -> u8 {
+        match self {
+            Amount::Single => 1,
+            Amount::Inverse => 2,
+        }
+    }
+
+    /// The amount that turns `turns` thirds of a turn clockwise, or `None` if it's a multiple of a full turn.
+    pub const fn from_turns(turns: u8) -> Option<Self> {
+        match turns % 3 {
+            1 => Some(Amount::Single),
+            2 => Some(Amount::Inverse),
+            _ => None,
+        }
+    }
+}
+
+impl std::ops::Add for Amount {
+    type Output = Option<Amount>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Amount::from_turns(self.turns() + rhs.turns())
+    }
+}
+
+impl std::fmt::Display for Amount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Amount::Single => write!(f, ""),
+            Amount::Inverse => write!(f, "'"),
+        }
+    }
+}
+
+/// A move of the Pyraminx in WCA notation. Uppercase moves (`R`) turn the whole layer around a vertex, while
+/// lowercase ones (`r`) only turn the tip.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, RandGen)]
+pub struct PyraminxMove {
+    /// The vertex that the move turns around
+    pub vertex: Vertex,
+    /// Whether only the tip is turned
+    pub tip_only: bool,
+    /// How much the move turns, looking at the vertex
+    pub amount: Amount,
+}
+
+impl PyraminxMove {
+    const DISTINCT_MOVES: usize = 4 * 2 * 2;
+
+    /// Creates a new move that turns the layer around `vertex`.
+    pub const fn layer(vertex: Vertex, amount: Amount) -> Self {
+        Self {
+            vertex,
+            tip_only: false,
+            amount,
+        }
+    }
+
+    /// Creates a new move that only turns the tip at `vertex`.
+    pub const fn tip(vertex: Vertex, amount: Amount) -> Self {
+        Self {
+            vertex,
+            tip_only: true,
+            amount,
+        }
+    }
+
+    /// Returns an array of all moves, starting with the layer moves.
+    pub fn all() -> [Self; Self::DISTINCT_MOVES] {
+        iproduct!([false, true], Vertex::iter(), Amount::iter())
+            .map(|(tip_only, vertex, amount)| Self {
+                vertex,
+                tip_only,
+                amount,
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("There are exactly 16 moves")
+    }
+
+    /// The moves that turn a whole layer (that is, every move except the tips).
+    pub fn layer_moves() -> impl Iterator<Item = Self> {
+        iproduct!(Vertex::iter(), Amount::iter())
+            .map(|(vertex, amount)| Self::layer(vertex, amount))
+    }
+}
+
+impl generic::Move for PyraminxMove {
+    fn inverse(&self) -> Self {
+        Self {
+            amount: match self.amount {
+                Amount::Single => Amount::Inverse,
+                Amount::Inverse => Amount::Single,
+            },
+            ..self.clone()
+        }
+    }
+}
+
+impl Mergeable for PyraminxMove {
+    type Axis = Vertex;
+
+    fn axis(&self) -> Vertex {
+        self.vertex
+    }
+
+    fn merge(&self, other: &Self) -> Option<Option<Self>> {
+        if self.vertex != other.vertex || self.tip_only != other.tip_only {
+            return None;
+        }
+
+        Some((self.amount + other.amount).map(|amount| Self {
+            amount,
+            ..self.clone()
+        }))
+    }
+}
+
+impl std::fmt::Display for PyraminxMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let vertex = self.vertex.to_string();
+
+        match self.tip_only {
+            true => write!(f, "{}{}", vertex.to_lowercase(), self.amount),
+            false => write!(f, "{}{}", vertex, self.amount),
+        }
+    }
+}
+
+impl IntoEnumIterator for PyraminxMove {
+    type Iterator = core::array::IntoIter<Self, { Self::DISTINCT_MOVES }>;
+
+    fn iter() -> Self::Iterator {
+        Self::all().into_iter()
+    }
+}
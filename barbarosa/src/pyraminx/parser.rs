@@ -0,0 +1,65 @@
+//! Parsing of WCA Pyraminx notation.
+
+use pest::iterators::Pair;
+use pest_derive::Parser;
+
+use crate::generic::parse::{FromPest, IntoParseErr, ParseError};
+
+use super::{moves::Amount, pieces::Vertex, PyraminxMove};
+
+type Result<T> = std::result::Result<T, ParseError<Rule>>;
+
+#[derive(Parser)]
+#[grammar = "grammar/pyraminx.pest"]
+pub struct PyraminxParser;
+
+impl FromPest for Amount {
+    type Rule = Rule;
+    type Parser = PyraminxParser;
+
+    fn rule() -> Self::Rule {
+        Rule::amount
+    }
+
+    fn from_pest(pair: Pair<Self::Rule>) -> Result<Self> {
+        match pair.as_str() {
+            "" => Ok(Amount::Single),
+            "'" => Ok(Amount::Inverse),
+            other => Err(ParseError::Unreachable(other.to_string())),
+        }
+    }
+}
+
+fn parse_vertex(pair: Pair<Rule>) -> Result<Vertex> {
+    match pair.as_str().to_uppercase().as_str() {
+        "U" => Ok(Vertex::U),
+        "L" => Ok(Vertex::L),
+        "R" => Ok(Vertex::R),
+        "B" => Ok(Vertex::B),
+        other => Err(ParseError::Unreachable(other.to_string())),
+    }
+}
+
+impl FromPest for PyraminxMove {
+    type Rule = Rule;
+    type Parser = PyraminxParser;
+
+    fn rule() -> Self::Rule {
+        Rule::pyraminx_move
+    }
+
+    fn from_pest(pair: Pair<Self::Rule>) -> Result<Self> {
+        let mut inner = pair.into_inner();
+
+        let first = inner.next().into_err()?;
+        let tip_only = first.as_rule() == Rule::tip;
+        let vertex = parse_vertex(first)?;
+        let amount = Amount::from_pest(inner.next().into_err()?)?;
+
+        Ok(PyraminxMove {
+            vertex,
+            tip_only,
+            amount,
+        })
+    }
+}
@@ -0,0 +1,241 @@
+//! Pieces of the Pyraminx.
+
+use rand_derive2::RandGen;
+use strum::EnumIter;
+
+use crate::generic::{
+    moves::impl_movable_array,
+    piece::{OrientedPiece, PieceSetDescriptor},
+    Movable, Piece, PieceSet,
+};
+
+use super::moves::PyraminxMove;
+
+/// One of the four vertices of the Pyraminx, named as in WCA notation: `U` is the top one, and `L`, `R` and `B`
+/// are the left, right and back ones of the bottom face.
+///
+/// Every move turns the pieces around one of the vertices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, EnumIter, RandGen)]
+#[allow(missing_docs)]
+pub enum Vertex {
+    U,
+    L,
+    R,
+    B,
+}
+
+impl Vertex {
+    /// The other three vertices, in the order they move to each other when turning clockwise around `self`.
+    pub const fn neighbours(&self) -> [Vertex; 3] {
+        use Vertex::*;
+
+        match self {
+            U => [L, B, R],
+            L => [U, R, B],
+            R => [B, L, U],
+            B => [R, U, L],
+        }
+    }
+
+    /// Where `self` ends up after turning the layer around `axis` clockwise `turns` times.
+    pub fn turned(self, axis: Vertex, turns: u8) -> Vertex {
+        if self == axis {
+            return self;
+        }
+
+        let neighbours = axis.neighbours();
+        let index = neighbours
+            .iter()
+            .position(|&vertex| vertex == self)
+            .expect("Every other vertex is a neighbour");
+
+        neighbours[(index + turns as usize) % 3]
+    }
+}
+
+impl std::fmt::Display for Vertex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// Implements [`Piece`], [`OrientedPiece`] and [`PieceSetDescriptor`] for the pieces that are stuck at a vertex
+/// and can only be twisted.
+macro_rules! impl_vertex_piece {
+    ($piece:ident) => {
+        impl $piece {
+            /// Creates a new piece at `vertex`, twisted clockwise `twist` times.
+            pub const fn new(vertex: Vertex, twist: u8) -> Self {
+                Self {
+                    vertex,
+                    twist: twist % 3,
+                }
+            }
+        }
+
+        impl Piece for $piece {
+            type Position = Vertex;
+
+            fn position(&self) -> Vertex {
+                self.vertex
+            }
+
+            fn is_solved(&self, original_pos: &Vertex) -> bool {
+                self.vertex == *original_pos && self.twist == 0
+            }
+        }
+
+        impl OrientedPiece for $piece {
+            const ORIENTATION_COUNT: u8 = 3;
+
+            fn orientation(&self) -> u8 {
+                self.twist
+            }
+
+            fn with_orientation(position: Vertex, orientation: u8) -> Self {
+                Self::new(position, orientation)
+            }
+        }
+
+        impl PieceSetDescriptor<4> for $piece {
+            const REFERENCE_POSITIONS: [Vertex; 4] = [Vertex::U, Vertex::L, Vertex::R, Vertex::B];
+
+            const SOLVED: [Self; 4] = [
+                Self::new(Vertex::U, 0),
+                Self::new(Vertex::L, 0),
+                Self::new(Vertex::R, 0),
+                Self::new(Vertex::B, 0),
+            ];
+        }
+
+        impl_movable_array!($piece, PyraminxMove);
+    };
+}
+
+/// One of the tips of the Pyraminx, which can be turned by themselves with the lowercase moves.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tip {
+    /// The vertex where the tip is.
+    pub vertex: Vertex,
+
+    /// How many times the tip is twisted clockwise from its solved orientation, from 0 to 2.
+    pub twist: u8,
+}
+
+impl Movable<PyraminxMove> for Tip {
+    fn apply(&mut self, m: &PyraminxMove) {
+        if m.vertex == self.vertex {
+            self.twist = (self.twist + m.amount.turns()) % 3;
+        }
+    }
+}
+
+impl_vertex_piece!(Tip);
+
+/// The piece right under each tip (sometimes called an axial piece), which only the layer moves can twist.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Center {
+    /// The vertex where the center is.
+    pub vertex: Vertex,
+
+    /// How many times the center is twisted clockwise from its solved orientation, from 0 to 2.
+    pub twist: u8,
+}
+
+impl Movable<PyraminxMove> for Center {
+    fn apply(&mut self, m: &PyraminxMove) {
+        if m.vertex == self.vertex && !m.tip_only {
+            self.twist = (self.twist + m.amount.turns()) % 3;
+        }
+    }
+}
+
+impl_vertex_piece!(Center);
+
+/// An edge of the Pyraminx, between two vertices.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Edge {
+    /// Where each of the two stickers of the edge is. The first sticker is the one that's at the vertex that
+    /// comes first when solved (see [`Edge::position`]).
+    pub vertices: [Vertex; 2],
+}
+
+impl Edge {
+    /// Creates a new edge with its stickers at the given vertices.
+    pub const fn new(a: Vertex, b: Vertex) -> Self {
+        Self { vertices: [a, b] }
+    }
+}
+
+impl Piece for Edge {
+    /// The vertices the edge is between, sorted.
+    type Position = [Vertex; 2];
+
+    fn position(&self) -> [Vertex; 2] {
+        let [a, b] = self.vertices;
+        [a.min(b), a.max(b)]
+    }
+
+    fn is_solved(&self, original_pos: &[Vertex; 2]) -> bool {
+        self.vertices == *original_pos
+    }
+}
+
+impl OrientedPiece for Edge {
+    const ORIENTATION_COUNT: u8 = 2;
+
+    fn orientation(&self) -> u8 {
+        let [a, b] = self.vertices;
+        (a > b) as u8
+    }
+
+    fn with_orientation(position: [Vertex; 2], orientation: u8) -> Self {
+        let [a, b] = position;
+
+        match orientation % 2 {
+            0 => Self::new(a, b),
+            _ => Self::new(b, a),
+        }
+    }
+}
+
+impl PieceSetDescriptor<6> for Edge {
+    const REFERENCE_POSITIONS: [[Vertex; 2]; 6] = {
+        use Vertex::*;
+
+        [[U, L], [U, R], [U, B], [L, R], [L, B], [R, B]]
+    };
+
+    const SOLVED: [Self; 6] = {
+        use Vertex::*;
+
+        [
+            Self::new(U, L),
+            Self::new(U, R),
+            Self::new(U, B),
+            Self::new(L, R),
+            Self::new(L, B),
+            Self::new(R, B),
+        ]
+    };
+}
+
+impl Movable<PyraminxMove> for Edge {
+    fn apply(&mut self, m: &PyraminxMove) {
+        if !m.tip_only && self.vertices.contains(&m.vertex) {
+            let turns = m.amount.turns();
+            self.vertices = self.vertices.map(|vertex| vertex.turned(m.vertex, turns));
+        }
+    }
+}
+
+impl_movable_array!(Edge, PyraminxMove);
+
+/// The tips of the Pyraminx.
+pub type TipSet = PieceSet<Tip, 4>;
+
+/// The centers of the Pyraminx.
+pub type CenterSet = PieceSet<Center, 4>;
+
+/// The edges of the Pyraminx.
+pub type EdgeSet = PieceSet<Edge, 6>;
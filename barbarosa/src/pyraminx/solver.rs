@@ -0,0 +1,136 @@
+//! Optimal solving and random state scrambles for the Pyraminx.
+
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use rand::Rng;
+
+use crate::generic::{
+    search::{ida::IDASearcher, Searcher, Solver},
+    Alg, Movable, Move,
+};
+
+use super::{
+    moves::{Amount, PyraminxMove},
+    pieces::EdgeSet,
+    Pyraminx,
+};
+
+/// The maximum amount of layer moves needed to solve any Pyraminx.
+const GODS_NUMBER: i32 = 11;
+
+/// The minimum amount of layer moves needed to solve a [`random_state_scramble`], like in the official WCA
+/// scrambles.
+const MIN_SCRAMBLE_LENGTH: usize = 6;
+
+/// The amount of layer moves needed to solve each state of the edges.
+///
+/// There are only 11520 of them (half of the permutations, and half of the flips), so they can all be stored.
+static EDGE_DISTANCES: Lazy<HashMap<EdgeSet, u8>> = Lazy::new(|| {
+    let mut distances = HashMap::from([(EdgeSet::SOLVED, 0)]);
+    let mut frontier = vec![EdgeSet::SOLVED];
+
+    for distance in 1.. {
+        if frontier.is_empty() {
+            break;
+        }
+
+        let mut next = Vec::new();
+
+        for edges in frontier {
+            for m in PyraminxMove::layer_moves() {
+                let moved = edges.clone().moved(&m);
+
+                if !distances.contains_key(&moved) {
+                    distances.insert(moved.clone(), distance);
+                    next.push(moved);
+                }
+            }
+        }
+
+        frontier = next;
+    }
+
+    distances
+});
+
+/// Admissible heuristic for the layer moves: every move twists a single center, and the edges need at least as
+/// many moves as they would by themselves.
+fn heuristic(pyraminx: &Pyraminx) -> f32 {
+    let edges = EDGE_DISTANCES
+        .get(&pyraminx.edges)
+        .copied()
+        .unwrap_or(u8::MAX);
+    let twisted_centers = pyraminx
+        .centers
+        .iter()
+        .filter(|center| center.twist != 0)
+        .count();
+
+    edges.max(twisted_centers as u8) as f32
+}
+
+/// The tip moves that solve the tips of `pyraminx`.
+fn tip_solution(pyraminx: &Pyraminx) -> impl Iterator<Item = PyraminxMove> + '_ {
+    pyraminx.tips.iter().filter_map(|tip| {
+        Amount::from_turns(3 - tip.twist).map(|amount| PyraminxMove::tip(tip.vertex, amount))
+    })
+}
+
+/// Solves the Pyraminx in the least amount of moves.
+///
+/// The tips are independent of the rest of the puzzle, since every layer move turns the tip and the center at
+/// its vertex together. So the solver looks for an optimal solution of the layers with IDA* and then turns each
+/// tip that's still twisted, which is never more than one move per tip. The layer moves come first in the
+/// solution, and then the tips.
+///
+/// States with an odd edge permutation or an odd number of flipped edges are not in the edge table, so they're
+/// rejected with `None` before searching.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OptimalSolver;
+
+impl Solver<Pyraminx, PyraminxMove> for OptimalSolver {
+    fn solve(&self, pyraminx: &Pyraminx) -> Option<Alg<PyraminxMove>> {
+        if !EDGE_DISTANCES.contains_key(&pyraminx.edges) {
+            return None;
+        }
+
+        let searcher = IDASearcher::new(heuristic, Pyraminx::successors, GODS_NUMBER);
+        let (mut solution, solved) = searcher.search(pyraminx, |pyraminx| {
+            pyraminx.centers.is_solved() && pyraminx.edges.is_solved()
+        })?;
+
+        solution.moves.extend(tip_solution(&solved));
+
+        Some(solution)
+    }
+}
+
+/// Generates a random state scramble: the layer moves that reach a random Pyraminx, followed by its tips, which is
+/// the order WCA scrambles use.
+///
+/// The layers are the reverse of an [optimal solution](OptimalSolver), and states that take less than 6 layer
+/// moves get rerolled, since they would be trivial to solve.
+pub fn random_state_scramble(rng: &mut (impl Rng + ?Sized)) -> Alg<PyraminxMove> {
+    loop {
+        let pyraminx: Pyraminx = rng.gen();
+        let solution = OptimalSolver
+            .solve(&pyraminx)
+            .expect("Random states should be solvable");
+
+        let (tips, layers): (Vec<_>, Vec<_>) = solution.moves.into_iter().partition(|m| m.tip_only);
+
+        if layers.len() < MIN_SCRAMBLE_LENGTH {
+            continue;
+        }
+
+        let moves = layers
+            .iter()
+            .rev()
+            .chain(tips.iter())
+            .map(PyraminxMove::inverse)
+            .collect();
+
+        return Alg::new(moves);
+    }
+}
@@ -0,0 +1,143 @@
+#![cfg(test)]
+
+use pretty_assertions::assert_eq;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::generic::{
+    piece::{is_odd_permutation, OrientedPiece},
+    search::Solver,
+    Alg, Cube, Movable, Parsable,
+};
+
+use super::*;
+
+#[test]
+fn tips_are_lowercase_and_turns_are_single() {
+    let scramble = "U' L R' B L' U B' R u l' r";
+    assert_eq!(
+        Alg::<PyraminxMove>::parse(scramble).unwrap().to_string(),
+        scramble
+    );
+
+    assert_eq!(
+        PyraminxMove::parse("b'").unwrap(),
+        PyraminxMove::tip(Vertex::B, Amount::Inverse)
+    );
+
+    // There are no faces, double turns, wide moves or a D vertex
+    for invalid in ["F", "U2", "Uw", "u''", "D"] {
+        assert!(
+            PyraminxMove::parse(invalid).is_err(),
+            "{invalid} should error"
+        );
+    }
+}
+
+#[test]
+fn layers_turn_their_tip_and_center() {
+    for m in PyraminxMove::layer_moves() {
+        let pyraminx = Pyraminx::SOLVED.moved(&m);
+
+        let twisted_tips: Vec<_> = pyraminx.tips.iter().filter(|tip| tip.twist != 0).collect();
+        let twisted_centers: Vec<_> = pyraminx
+            .centers
+            .iter()
+            .filter(|center| center.twist != 0)
+            .collect();
+
+        assert_eq!(twisted_tips.len(), 1);
+        assert_eq!(twisted_tips[0].vertex, m.vertex);
+        assert_eq!(twisted_centers.len(), 1);
+        assert_eq!(twisted_centers[0].vertex, m.vertex);
+        assert!(pyraminx.moved(&m).moved(&m).is_solved());
+    }
+}
+
+#[test]
+fn tips_only_move_themselves() {
+    let alg = Alg::<PyraminxMove>::parse("u l' r b").unwrap();
+    let pyraminx = Pyraminx::SOLVED.moved(&alg);
+
+    assert!(pyraminx.centers.is_solved());
+    assert!(pyraminx.edges.is_solved());
+    assert!(!pyraminx.tips.is_solved());
+}
+
+#[test]
+fn simplifies_algs() {
+    assert_eq!(
+        Alg::<PyraminxMove>::parse("U U u u' R L L")
+            .unwrap()
+            .simplified(),
+        Alg::parse("U' R L'").unwrap()
+    );
+}
+
+#[test]
+fn random_edges_have_even_permutation_and_flips() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..100 {
+        let pyraminx: Pyraminx = rng.gen();
+        let flips: u8 = pyraminx.edges.iter().map(Edge::orientation).sum();
+
+        assert!(!is_odd_permutation(&pyraminx.edges, |_| true));
+        assert_eq!(flips % 2, 0);
+    }
+}
+
+#[test]
+fn rejects_a_single_flipped_edge() {
+    let mut pyraminx = Pyraminx::SOLVED;
+    let edge = pyraminx.edges.iter_mut_unchecked().next().unwrap();
+    *edge = Edge::with_orientation(edge.position(), 1);
+
+    assert_eq!(OptimalSolver.solve(&pyraminx), None);
+}
+
+#[test]
+fn solves_tips_after_the_layers() {
+    let scramble = Alg::<PyraminxMove>::parse("R U' B L' u").unwrap();
+    let pyraminx = Pyraminx::SOLVED.moved(&scramble);
+
+    let solution = OptimalSolver.solve(&pyraminx).unwrap();
+
+    assert_eq!(solution.moves.len(), 5);
+    assert_eq!(
+        solution.moves.last(),
+        Some(&PyraminxMove::tip(Vertex::U, Amount::Inverse))
+    );
+    assert!(pyraminx.moved(&solution).is_solved());
+}
+
+#[test]
+fn solves_random_states_within_gods_number() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..10 {
+        let pyraminx: Pyraminx = rng.gen();
+        let solution = OptimalSolver.solve(&pyraminx).unwrap();
+        let layers = solution.moves.iter().filter(|m| !m.tip_only).count();
+
+        assert!(layers <= 11);
+        assert!(pyraminx.moved(&solution).is_solved());
+    }
+}
+
+#[test]
+fn scrambles_list_the_tips_last() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..5 {
+        let scramble = random_state_scramble(&mut rng);
+        let layers = scramble.moves.iter().filter(|m| !m.tip_only).count();
+        let pyraminx = Pyraminx::SOLVED.moved(&scramble);
+
+        assert!(layers >= 6);
+        assert!(scramble.moves.iter().skip(layers).all(|m| m.tip_only));
+        assert_eq!(
+            OptimalSolver.solve(&pyraminx).unwrap().moves.len(),
+            scramble.moves.len()
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! The Skewb, a cube where every move turns half of the puzzle around one of the corners.
+//!
+//! The Skewb has 8 [corners](Corner), which are the same pieces as the corners of the cubes, and 6
+//! [centers](Center). Moves use WCA notation, where the UFR corner never moves (see [`Pivot`]):
+//!
+//! ```rust
+//! use barbarosa::generic::{search::Solver, Alg, Cube, Movable, Parsable};
+//! use barbarosa::skewb::{OptimalSolver, Skewb, SkewbMove};
+//!
+//! let scramble = Alg::<SkewbMove>::parse("R U' L B' R").unwrap();
+//! let skewb = Skewb::SOLVED.moved(&scramble);
+//! assert_eq!(skewb.distance(), Some(5));
+//!
+//! let solution = OptimalSolver.solve(&skewb).unwrap();
+//! assert!(skewb.moved(&solution).is_solved());
+//! ```
+//!
+//! See [`random_state_scramble`] for WCA scrambles. Skewbs can be printed to the terminal with
+//! [`Display`](std::fmt::Display).
+
+mod moves;
+mod parser;
+mod pieces;
+mod table;
+mod test;
+mod visualization;
+
+use crate::{
+    cube_n::pieces::corner::CornerSet,
+    generic::{self, moves::AsMove, Movable},
+};
+
+pub use crate::cube_n::Corner;
+pub use moves::{Amount, Pivot, SkewbMove};
+pub use pieces::{Center, CenterSet};
+pub use table::{random_state_scramble, OptimalSolver, GODS_NUMBER};
+
+/// A Skewb, stored as where each corner and each center is and how the corners are twisted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Skewb {
+    /// The corners
+    pub corners: CornerSet,
+    /// The centers
+    pub centers: CenterSet,
+}
+
+impl generic::Cube for Skewb {
+    const SOLVED: Self = Self {
+        corners: CornerSet::SOLVED,
+        centers: CenterSet::SOLVED,
+    };
+}
+
+impl AsMove for Skewb {
+    type Move = SkewbMove;
+}
+
+impl Movable<SkewbMove> for Skewb {
+    fn apply(&mut self, m: &SkewbMove) {
+        self.corners.apply(m);
+        self.centers.apply(m);
+    }
+}
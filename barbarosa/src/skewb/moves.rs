@@ -0,0 +1,158 @@
+//! Moves of the Skewb.
+
+use itertools::iproduct;
+use nalgebra::{vector, Vector3};
+use rand_derive2::RandGen;
+use strum::{EnumIter, IntoEnumIterator};
+
+use crate::{
+    cube_n::space::Direction,
+    generic::{self, moves::Mergeable},
+};
+
+/// How much a [`SkewbMove`] turns, either a third of a turn clockwise or counterclockwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, RandGen)]
+#[allow(missing_docs)]
+pub enum Amount {
+    Single,
+    Inverse,
+}
+
+impl Amount {
+    /// The amount of clockwise thirds of a turn.
+    pub const fn turns(&self) -> u8 {
+        match self {
+            Amount::Single => 1,
+            Amount::Inverse => 2,
+        }
+    }
+
+    /// The amount that turns `turns` thirds of a turn clockwise, or `None` if it's a multiple of a full turn.
+    pub const fn from_turns(turns: u8) -> Option<Self> {
+        match turns % 3 {
+            1 => Some(Amount::Single),
+            2 => Some(Amount::Inverse),
+            _ => None,
+        }
+    }
+}
+
+impl std::ops::Add for Amount {
+    type Output = Option<Amount>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Amount::from_turns(self.turns() + rhs.turns())
+    }
+}
+
+impl std::fmt::Display for Amount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Amount::Single => write!(f, ""),
+            Amount::Inverse => write!(f, "'"),
+        }
+    }
+}
+
+/// The corner that a [`SkewbMove`] turns around, in WCA notation.
+///
+/// None of them is next to the UFR corner, so it never moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, RandGen)]
+pub enum Pivot {
+    /// The DRB corner
+    R,
+    /// The UBL corner
+    U,
+    /// The DFL corner
+    L,
+    /// The DBL corner
+    B,
+}
+
+impl Pivot {
+    /// The position of the corner.
+    pub const fn position(&self) -> Vector3<Direction> {
+        use Direction::*;
+
+        match self {
+            Pivot::R => vector![Positive, Negative, Negative],
+            Pivot::U => vector![Negative, Positive, Negative],
+            Pivot::L => vector![Negative, Negative, Positive],
+            Pivot::B => vector![Negative, Negative, Negative],
+        }
+    }
+}
+
+impl std::fmt::Display for Pivot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// A move of the Skewb in WCA notation, which turns half of the puzzle around one of the corners.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, RandGen)]
+pub struct SkewbMove {
+    /// The corner that the move turns around
+    pub pivot: Pivot,
+    /// How much the move turns, looking at the corner
+    pub amount: Amount,
+}
+
+impl SkewbMove {
+    const DISTINCT_MOVES: usize = 4 * 2;
+
+    /// Creates a new [`SkewbMove`]
+    pub const fn new(pivot: Pivot, amount: Amount) -> Self {
+        Self { pivot, amount }
+    }
+
+    /// Returns an array of all moves
+    pub fn all() -> [Self; Self::DISTINCT_MOVES] {
+        iproduct!(Pivot::iter(), Amount::iter())
+            .map(|(pivot, amount)| Self::new(pivot, amount))
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("There are exactly 8 moves")
+    }
+}
+
+impl generic::Move for SkewbMove {
+    fn inverse(&self) -> Self {
+        let amount = match self.amount {
+            Amount::Single => Amount::Inverse,
+            Amount::Inverse => Amount::Single,
+        };
+
+        Self::new(self.pivot, amount)
+    }
+}
+
+impl Mergeable for SkewbMove {
+    type Axis = Pivot;
+
+    fn axis(&self) -> Pivot {
+        self.pivot
+    }
+
+    fn merge(&self, other: &Self) -> Option<Option<Self>> {
+        if self.pivot != other.pivot {
+            return None;
+        }
+
+        Some((self.amount + other.amount).map(|amount| Self::new(self.pivot, amount)))
+    }
+}
+
+impl std::fmt::Display for SkewbMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.pivot, self.amount)
+    }
+}
+
+impl IntoEnumIterator for SkewbMove {
+    type Iterator = core::array::IntoIter<Self, { Self::DISTINCT_MOVES }>;
+
+    fn iter() -> Self::Iterator {
+        Self::all().into_iter()
+    }
+}
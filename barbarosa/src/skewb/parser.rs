@@ -0,0 +1,68 @@
+//! Parsing of WCA Skewb notation.
+
+use pest::iterators::Pair;
+use pest_derive::Parser;
+
+use crate::generic::parse::{FromPest, IntoParseErr, ParseError};
+
+use super::{Amount, Pivot, SkewbMove};
+
+type Result<T> = std::result::Result<T, ParseError<Rule>>;
+
+#[derive(Parser)]
+#[grammar = "grammar/skewb.pest"]
+pub struct SkewbParser;
+
+macro_rules! impl_with_current_rule {
+    ($implementor:ty; $rule:expr; |$arg:ident| $body:expr) => {
+        impl FromPest for $implementor {
+            type Rule = Rule;
+            type Parser = SkewbParser;
+
+            fn rule() -> Self::Rule {
+                $rule
+            }
+
+            fn from_pest($arg: Pair<Self::Rule>) -> Result<Self> {
+                $body
+            }
+        }
+    };
+}
+
+impl_with_current_rule! {
+    Amount;
+    Rule::amount;
+
+    |pair| match pair.as_str() {
+        "" => Ok(Amount::Single),
+        "'" => Ok(Amount::Inverse),
+        other => Err(ParseError::Unreachable(other.to_string())),
+    }
+}
+
+impl_with_current_rule! {
+    Pivot;
+    Rule::pivot;
+
+    |pair| match pair.as_str() {
+        "R" => Ok(Pivot::R),
+        "U" => Ok(Pivot::U),
+        "L" => Ok(Pivot::L),
+        "B" => Ok(Pivot::B),
+        other => Err(ParseError::Unreachable(other.to_string())),
+    }
+}
+
+impl_with_current_rule! {
+    SkewbMove;
+    Rule::skewb_move;
+
+    |pair| {
+        let mut inner = pair.into_inner();
+        let pivot = Pivot::from_pest(inner.next().into_err()?)?;
+        let amount = Amount::from_pest(inner.next().into_err()?)?;
+
+        Ok(SkewbMove::new(pivot, amount))
+    }
+}
@@ -0,0 +1,143 @@
+//! Pieces of the Skewb.
+//!
+//! The corners are the same as the ones of the cubes (see [`Corner`]), but they move differently.
+
+use nalgebra::Vector3;
+use strum::IntoEnumIterator;
+
+use crate::{
+    cube_n::{
+        space::{Axis, Direction, Face},
+        visualization::{Color, Colored},
+        Corner,
+    },
+    generic::{
+        moves::impl_movable_array,
+        piece::{Coordinates, PieceSetDescriptor},
+        Movable, Piece, PieceSet,
+    },
+};
+
+use super::moves::SkewbMove;
+
+/// A center of the Skewb. Unlike the centers of the 3x3x3, they move around, but they don't have any
+/// orientation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Center {
+    /// The face the center is on
+    pub face: Face,
+}
+
+impl Center {
+    /// Creates a new center on `face`.
+    pub const fn new(face: Face) -> Self {
+        Self { face }
+    }
+}
+
+impl Piece for Center {
+    type Position = Face;
+
+    fn position(&self) -> Face {
+        self.face.clone()
+    }
+
+    fn is_solved(&self, original_pos: &Face) -> bool {
+        self.face == *original_pos
+    }
+}
+
+impl PieceSetDescriptor<6> for Center {
+    const REFERENCE_POSITIONS: [Face; 6] = [Face::R, Face::U, Face::F, Face::L, Face::D, Face::B];
+
+    const SOLVED: [Self; 6] = [
+        Self::new(Face::R),
+        Self::new(Face::U),
+        Self::new(Face::F),
+        Self::new(Face::L),
+        Self::new(Face::D),
+        Self::new(Face::B),
+    ];
+}
+
+impl Coordinates for Center {
+    fn coordinates_pos(position: Face) -> Vector3<f32> {
+        position.coords().map(|coord| coord as f32)
+    }
+}
+
+impl Colored for Center {
+    fn colors(&self, original_pos: Face) -> Vec<(Face, Color)> {
+        vec![(self.position(), Color::new(original_pos))]
+    }
+}
+
+/// The product of two directions, as if they were `1` and `-1`.
+fn product(a: Direction, b: Direction) -> Direction {
+    match b {
+        Direction::Positive => a,
+        Direction::Negative => -a,
+    }
+}
+
+/// The axis where a vector on `axis` ends up after turning clockwise around the corner at `pivot`.
+fn turned_axis(pivot: &Vector3<Direction>, axis: Axis) -> Axis {
+    // `Corner::next_axis` goes counterclockwise, so twice is once clockwise
+    Corner::next_axis(pivot, &Corner::next_axis(pivot, &axis))
+}
+
+/// Turns `position` clockwise around the corner at `pivot`, as long as it's on the half of the puzzle that
+/// the pivot moves.
+fn turn(position: &Vector3<Direction>, pivot: &Vector3<Direction>) -> Vector3<Direction> {
+    let mut output = *position;
+
+    for axis in Axis::iter() {
+        let target = turned_axis(pivot, axis);
+        output[target] = product(position[axis], product(pivot[axis], pivot[target]));
+    }
+
+    output
+}
+
+impl Movable<SkewbMove> for Corner {
+    fn apply(&mut self, m: &SkewbMove) {
+        let pivot = m.pivot.position();
+
+        // The corners that are moved are the pivot and the ones next to it
+        let shared_faces = self.position.iter().zip(&pivot).filter(|(a, b)| a == b);
+        if shared_faces.count() < 2 {
+            return;
+        }
+
+        for _ in 0..m.amount.turns() {
+            self.position = turn(&self.position, &pivot);
+            self.orientation_axis = turned_axis(&pivot, self.orientation_axis);
+        }
+    }
+}
+
+impl_movable_array!(Corner, SkewbMove);
+
+impl Movable<SkewbMove> for Center {
+    fn apply(&mut self, m: &SkewbMove) {
+        let pivot = m.pivot.position();
+
+        if pivot[self.face.axis] != self.face.direction {
+            return;
+        }
+
+        for _ in 0..m.amount.turns() {
+            let axis = turned_axis(&pivot, self.face.axis);
+            let direction = product(
+                self.face.direction,
+                product(pivot[self.face.axis], pivot[axis]),
+            );
+            self.face = Face::new(axis, direction);
+        }
+    }
+}
+
+impl_movable_array!(Center, SkewbMove);
+
+/// The 6 centers of the Skewb.
+pub type CenterSet = PieceSet<Center, 6>;
@@ -0,0 +1,181 @@
+//! The distance table of the Skewb, and the optimal solver and scrambler built on top of it.
+//!
+//! The Skewb has only 3,149,280 states, so the amount of moves needed to solve each of them fits in a few
+//! megabytes. The corners and the centers are independent of each other (every combination can be reached),
+//! so each state is indexed by the state of its corners (one of 8748) and the state of its centers (one of 360).
+
+use std::{collections::HashMap, hash::Hash};
+
+use once_cell::sync::Lazy;
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+
+use crate::{
+    cube_n::pieces::corner::CornerSet,
+    generic::{search::Solver, Alg, Cube, Movable},
+};
+
+use super::{CenterSet, Skewb, SkewbMove};
+
+/// The maximum amount of moves needed to solve any Skewb.
+pub const GODS_NUMBER: u8 = 11;
+
+/// The minimum amount of moves needed to solve a [`random_state_scramble`], like in the official WCA
+/// scrambles.
+const MIN_SCRAMBLE_LENGTH: u8 = 7;
+
+/// Every state that a part of the Skewb can reach, along with where each move takes it.
+struct Coordinate<T> {
+    states: Vec<T>,
+    indices: HashMap<T, usize>,
+    moves: Vec<[usize; 8]>,
+}
+
+impl<T: Movable<SkewbMove> + Hash + Eq + Clone> Coordinate<T> {
+    /// Finds every state reachable from `solved`, which gets the index 0.
+    fn new(solved: T) -> Self {
+        let mut states = vec![solved.clone()];
+        let mut indices = HashMap::from([(solved, 0)]);
+        let mut moves = Vec::new();
+
+        // `states` grows while iterating, so it ends up being a breadth first search
+        let mut current = 0;
+        while let Some(state) = states.get(current).cloned() {
+            let successors = SkewbMove::all().map(|m| {
+                let successor = state.clone().moved(&m);
+                let next_index = indices.len();
+
+                *indices.entry(successor.clone()).or_insert_with(|| {
+                    states.push(successor);
+                    next_index
+                })
+            });
+
+            moves.push(successors);
+            current += 1;
+        }
+
+        Self {
+            states,
+            indices,
+            moves,
+        }
+    }
+
+    fn index(&self, state: &T) -> Option<usize> {
+        self.indices.get(state).copied()
+    }
+}
+
+static CORNERS: Lazy<Coordinate<CornerSet>> = Lazy::new(|| Coordinate::new(CornerSet::SOLVED));
+static CENTERS: Lazy<Coordinate<CenterSet>> = Lazy::new(|| Coordinate::new(CenterSet::SOLVED));
+
+/// The index of the skewb in [`DISTANCES`], or `None` if it can't be reached from the solved state.
+fn index(skewb: &Skewb) -> Option<usize> {
+    let corners = CORNERS.index(&skewb.corners)?;
+    let centers = CENTERS.index(&skewb.centers)?;
+
+    Some(corners * CENTERS.states.len() + centers)
+}
+
+/// The amount of moves needed to solve each state of the Skewb, indexed with [`index`].
+pub(super) static DISTANCES: Lazy<Vec<u8>> = Lazy::new(|| {
+    let center_count = CENTERS.states.len();
+    let mut distances = vec![u8::MAX; CORNERS.states.len() * center_count];
+    let mut frontier = vec![0];
+    distances[0] = 0;
+
+    for distance in 1.. {
+        if frontier.is_empty() {
+            break;
+        }
+
+        let mut next = Vec::new();
+
+        for index in frontier {
+            let (corners, centers) = (index / center_count, index % center_count);
+            let successors = CORNERS.moves[corners].iter().zip(&CENTERS.moves[centers]);
+
+            for (corners, centers) in successors {
+                let successor = corners * center_count + centers;
+
+                if distances[successor] == u8::MAX {
+                    distances[successor] = distance;
+                    next.push(successor);
+                }
+            }
+        }
+
+        frontier = next;
+    }
+
+    distances
+});
+
+impl Skewb {
+    /// How many moves an optimal solution takes, looked up in the table of every state. States that no moves reach,
+    /// such as a single twisted corner, have no distance.
+    pub fn distance(&self) -> Option<u8> {
+        index(self).map(|index| DISTANCES[index])
+    }
+}
+
+impl Distribution<Skewb> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Skewb {
+        let corners = rng.gen_range(0..CORNERS.states.len());
+        let centers = rng.gen_range(0..CENTERS.states.len());
+
+        Skewb {
+            corners: CORNERS.states[corners].clone(),
+            centers: CENTERS.states[centers].clone(),
+        }
+    }
+}
+
+/// Solves the Skewb in the least amount of moves, by looking up the [distance](Skewb::distance) of each
+/// state and always doing a move that gets closer to solved. There's no search involved, so it takes at most
+/// [`GODS_NUMBER`] lookups of each move.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OptimalSolver;
+
+impl Solver<Skewb, SkewbMove> for OptimalSolver {
+    fn solve(&self, skewb: &Skewb) -> Option<Alg<SkewbMove>> {
+        let mut skewb = skewb.clone();
+        let mut distance = skewb.distance()?;
+        let mut solution = Alg::empty();
+
+        while distance > 0 {
+            let (m, closer) = SkewbMove::all()
+                .into_iter()
+                .map(|m| (m.clone(), skewb.clone().moved(&m)))
+                .find(|(_, closer)| closer.distance() == Some(distance - 1))
+                .expect("Some move should get closer to solved");
+
+            solution.moves.push(m);
+            skewb = closer;
+            distance -= 1;
+        }
+
+        debug_assert!(skewb.is_solved());
+
+        Some(solution)
+    }
+}
+
+/// Generates a random state scramble, picking a Skewb uniformly among the ones at least 7 moves away from solved
+/// and reversing its [optimal solution](OptimalSolver).
+pub fn random_state_scramble(rng: &mut (impl Rng + ?Sized)) -> Alg<SkewbMove> {
+    loop {
+        let skewb: Skewb = rng.gen();
+
+        if skewb
+            .distance()
+            .is_some_and(|distance| distance >= MIN_SCRAMBLE_LENGTH)
+        {
+            let solution = OptimalSolver
+                .solve(&skewb)
+                .expect("Random states should be solvable");
+
+            return solution.reversed();
+        }
+    }
+}
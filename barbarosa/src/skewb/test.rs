@@ -0,0 +1,99 @@
+#![cfg(test)]
+
+use pretty_assertions::assert_eq;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::generic::{
+    piece::PieceSetDescriptor, search::Solver, Alg, Cube, Movable, Parsable, Piece,
+};
+
+use super::*;
+
+#[test]
+fn notation_only_turns_the_four_free_corners() {
+    let scramble = "R U' L B' R' U L' B";
+    assert_eq!(
+        Alg::<SkewbMove>::parse(scramble).unwrap().to_string(),
+        scramble
+    );
+
+    for invalid in ["F", "R2", "r", "U''", "Rw"] {
+        assert!(SkewbMove::parse(invalid).is_err(), "{invalid} should error");
+    }
+}
+
+#[test]
+fn moves_turn_half_of_the_puzzle() {
+    for m in SkewbMove::all() {
+        let skewb = Skewb::SOLVED.moved(&m);
+
+        let moved_corners = skewb
+            .corners
+            .iter_with_pos()
+            .filter(|(pos, corner)| !corner.is_solved(pos));
+        let moved_centers = skewb
+            .centers
+            .iter_with_pos()
+            .filter(|(pos, center)| !center.is_solved(pos));
+
+        assert_eq!(moved_corners.count(), 4);
+        assert_eq!(moved_centers.count(), 3);
+        assert!(skewb.clone().moved(&m).moved(&m).is_solved());
+
+        // UFR is never moved
+        let ufr = &Corner::REFERENCE_POSITIONS[0];
+        assert!(skewb.corners.piece_originally_at(ufr).is_solved(ufr));
+    }
+}
+
+#[test]
+fn every_state_is_reachable() {
+    let mut counts = [0; GODS_NUMBER as usize + 1];
+
+    for &distance in table::DISTANCES.iter() {
+        counts[distance as usize] += 1;
+    }
+
+    assert_eq!(table::DISTANCES.len(), 3_149_280);
+    assert_eq!(
+        counts,
+        [1, 8, 48, 288, 1728, 10248, 59304, 315198, 1225483, 1455856, 81028, 90]
+    );
+}
+
+#[test]
+fn solutions_are_as_long_as_the_distance() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..20 {
+        let skewb: Skewb = rng.gen();
+        let solution = OptimalSolver.solve(&skewb).unwrap();
+
+        assert_eq!(Some(solution.moves.len() as u8), skewb.distance());
+        assert!(skewb.moved(&solution).is_solved());
+    }
+}
+
+#[test]
+fn scrambles_are_at_least_seven_moves_away() {
+    let mut rng = StdRng::seed_from_u64(69420);
+
+    for _ in 0..20 {
+        let scramble = random_state_scramble(&mut rng);
+        let skewb = Skewb::SOLVED.moved(&scramble);
+
+        assert!(scramble.moves.len() >= 7);
+        assert_eq!(skewb.distance(), Some(scramble.moves.len() as u8));
+    }
+}
+
+#[test]
+fn displays_solved_faces() {
+    let solved = Skewb::SOLVED.to_string();
+    let scrambled = Skewb::SOLVED
+        .moved(&Alg::<SkewbMove>::parse("R U").unwrap())
+        .to_string();
+
+    assert_ne!(solved, scrambled);
+    assert_eq!(solved.lines().count(), scrambled.lines().count());
+}
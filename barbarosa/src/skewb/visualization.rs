@@ -0,0 +1,36 @@
+//! Terminal display of the Skewb.
+
+use core::fmt;
+
+use crate::cube_n::{space::Face, visualization::unfolded::Unfolded};
+
+use super::Skewb;
+
+impl From<&Skewb> for Unfolded<3> {
+    /// Draws the Skewb as if it was a 3x3x3 where the edges have the color of the center, so that each center
+    /// looks like a diamond.
+    fn from(skewb: &Skewb) -> Self {
+        let mut unfolded = Self::default();
+
+        unfolded.populate_with(&skewb.corners);
+        unfolded.populate_with(&skewb.centers);
+
+        for face in Face::iter() {
+            let data = &mut unfolded[&face].data;
+            let center = data[1][1].clone();
+
+            for [x, y] in [[0, 1], [1, 0], [1, 2], [2, 1]] {
+                data[x][y] = center.clone();
+            }
+        }
+
+        unfolded
+    }
+}
+
+impl fmt::Display for Skewb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unfolded: Unfolded<3> = self.into();
+        write!(f, "{unfolded}")
+    }
+}
@@ -0,0 +1,165 @@
+//! The top and bottom layers of the Square-1.
+
+/// A piece of the top or bottom layer, identified by where it is when solved.
+///
+/// The corners are numbered from 0 to 7 and so are the edges, with 0 to 3 being on the top layer when solved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Square1Piece {
+    /// A corner, which takes two slots (60 degrees)
+    Corner(u8),
+    /// An edge, which takes a single slot (30 degrees)
+    Edge(u8),
+}
+
+impl Square1Piece {
+    /// The amount of slots the piece takes.
+    pub const fn size(&self) -> usize {
+        match self {
+            Self::Corner(_) => 2,
+            Self::Edge(_) => 1,
+        }
+    }
+
+    /// A number from 0 to 15 that identifies the piece, corners first.
+    pub(super) const fn index(&self) -> usize {
+        match *self {
+            Self::Corner(i) => i as usize,
+            Self::Edge(i) => 8 + i as usize,
+        }
+    }
+}
+
+/// The top or the bottom layer of the Square-1, divided in 12 slots of 30 degrees.
+///
+/// The slots go clockwise looking at the layer, starting at the front end of the slice. So slots 0 to 5 are
+/// the ones on the half that the slice turns, and corners take two consecutive slots.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Layer {
+    slots: [Square1Piece; 12],
+}
+
+impl Layer {
+    /// The top layer of the solved Square-1.
+    pub const SOLVED_TOP: Self = {
+        use Square1Piece::{Corner as C, Edge as E};
+
+        Self {
+            slots: [
+                C(0),
+                C(0),
+                E(0),
+                C(1),
+                C(1),
+                E(1),
+                C(2),
+                C(2),
+                E(2),
+                C(3),
+                C(3),
+                E(3),
+            ],
+        }
+    };
+
+    /// The bottom layer of the solved Square-1. It's the top layer upside down, so it starts with an edge.
+    pub const SOLVED_BOTTOM: Self = {
+        use Square1Piece::{Corner as C, Edge as E};
+
+        Self {
+            slots: [
+                E(4),
+                C(4),
+                C(4),
+                E(5),
+                C(5),
+                C(5),
+                E(6),
+                C(6),
+                C(6),
+                E(7),
+                C(7),
+                C(7),
+            ],
+        }
+    };
+
+    /// Creates a layer with the given pieces in clockwise order, with the first one starting at slot 0.
+    ///
+    /// Returns `None` if the pieces don't take exactly 12 slots.
+    pub fn new(pieces: &[Square1Piece]) -> Option<Self> {
+        Self::with_shape(0, pieces)
+    }
+
+    /// Like [`Layer::new`], but the first piece starts at slot `start`.
+    pub(super) fn with_shape(start: usize, pieces: &[Square1Piece]) -> Option<Self> {
+        let size: usize = pieces.iter().map(Square1Piece::size).sum();
+        if size != 12 {
+            return None;
+        }
+
+        let mut slots = [Square1Piece::Edge(0); 12];
+        let mut slot = start;
+
+        for piece in pieces {
+            for _ in 0..piece.size() {
+                slots[slot % 12] = *piece;
+                slot += 1;
+            }
+        }
+
+        Some(Self { slots })
+    }
+
+    /// The piece on each slot. Corners show up twice.
+    pub fn slots(&self) -> &[Square1Piece; 12] {
+        &self.slots
+    }
+
+    /// Whether a piece starts at `slot`, that is, `slot` isn't the second half of a corner.
+    fn starts_at(&self, slot: usize) -> bool {
+        let piece = self.slots[slot];
+
+        matches!(piece, Square1Piece::Edge(_)) || self.slots[(slot + 11) % 12] != piece
+    }
+
+    /// The pieces of the layer in clockwise order, starting with the first one that starts at slot 0 or after.
+    pub fn pieces(&self) -> impl Iterator<Item = Square1Piece> + '_ {
+        (0..12)
+            .filter(|&slot| self.starts_at(slot))
+            .map(|slot| self.slots[slot])
+    }
+
+    /// The slots where a piece starts, as a bitmask. This is all that matters to know the shape of the layer.
+    pub(super) fn shape(&self) -> u16 {
+        (0..12)
+            .filter(|&slot| self.starts_at(slot))
+            .fold(0, |mask, slot| mask | 1 << slot)
+    }
+
+    /// Turns the layer `amount` slots clockwise.
+    pub fn turn(&mut self, amount: i8) {
+        let amount = amount.rem_euclid(12) as usize;
+        self.slots.rotate_right(amount);
+    }
+
+    /// Whether the slice can be turned, that is, no corner is on both halves.
+    pub fn is_sliceable(&self) -> bool {
+        self.starts_at(0) && self.starts_at(6)
+    }
+
+    /// Whether the layer is a square, with corners and edges alternating.
+    pub fn is_square(&self) -> bool {
+        let pieces: Vec<_> = self.pieces().collect();
+
+        pieces.len() == 8
+            && pieces
+                .iter()
+                .zip(pieces.iter().cycle().skip(1))
+                .all(|(a, b)| a.size() != b.size())
+    }
+
+    /// Swaps the half of `top` that the slice turns with the one of `bottom`.
+    pub(super) fn slice(top: &mut Layer, bottom: &mut Layer) {
+        top.slots[..6].swap_with_slice(&mut bottom.slots[6..]);
+    }
+}
@@ -0,0 +1,130 @@
+//! The Square-1, a puzzle that changes shape.
+//!
+//! The top and bottom [layers](Layer) have 8 corners and 8 edges between them, and the middle layer is split in
+//! two halves. Unlike in other puzzles, some moves can't be done depending on the shape of the puzzle: the slice
+//! is blocked whenever a corner is in the way. Algs use WCA notation (see [`Square1Move`]), with a grammar of
+//! their own: there are no groups or comments, and `//` is just two slices.
+//!
+//! ```rust
+//! use barbarosa::generic::{Alg, Cube, Movable, Parsable};
+//! use barbarosa::square1::{to_wca_notation, BlockedSliceError, Square1, Square1Move};
+//!
+//! let alg = Alg::<Square1Move>::parse("(1,0)/ (2,0)/ (0,1)").unwrap();
+//! let square1 = Square1::SOLVED.moved(&alg);
+//!
+//! assert!(!square1.is_cube_shape());
+//! assert_eq!(square1.clone().try_apply(&Square1Move::Slice), Err(BlockedSliceError::Bottom));
+//! assert_eq!(to_wca_notation(&alg), "(1,0)/ (2,0)/ (0,1)");
+//!
+//! let blocked = Alg::<Square1Move>::parse("(1,0)/ (2,0)/ (0,1)/").unwrap();
+//! assert_eq!(Square1::SOLVED.try_apply_alg(&blocked), Err((5, BlockedSliceError::Bottom)));
+//! ```
+//!
+//! See [`random_state_scramble`] for WCA scrambles.
+
+mod layer;
+mod moves;
+mod parser;
+mod solver;
+mod test;
+
+use crate::generic::{self, moves::AsMove, Alg, Movable};
+
+pub use layer::{Layer, Square1Piece};
+pub use moves::{to_wca_notation, BlockedSliceError, Square1Move};
+pub use solver::{random_state_scramble, TwoPhaseSolver};
+
+/// The Square-1, as the pieces on each slot of both layers plus the middle layer.
+///
+/// Moves that would be blocked panic when applied with [`Movable`], so check them with
+/// [`Square1::check_move`] or use [`Square1::try_apply`] and [`Square1::try_apply_alg`] instead.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Square1 {
+    /// The top layer
+    pub top: Layer,
+    /// The bottom layer
+    pub bottom: Layer,
+    /// Whether the half of the middle layer that the slice turns is upside down
+    pub flipped_middle: bool,
+}
+
+impl Square1 {
+    /// Whether both layers are squares. The middle layer can still be flipped.
+    pub fn is_cube_shape(&self) -> bool {
+        self.top.is_square() && self.bottom.is_square()
+    }
+
+    /// Checks whether the move can be done, that is, it isn't a slice blocked by a corner.
+    pub fn check_move(&self, m: &Square1Move) -> Result<(), BlockedSliceError> {
+        if *m != Square1Move::Slice {
+            return Ok(());
+        }
+
+        match (self.top.is_sliceable(), self.bottom.is_sliceable()) {
+            (true, true) => Ok(()),
+            (false, true) => Err(BlockedSliceError::Top),
+            (true, false) => Err(BlockedSliceError::Bottom),
+            (false, false) => Err(BlockedSliceError::Both),
+        }
+    }
+
+    /// Applies the move if it can be done, or leaves the puzzle untouched otherwise.
+    pub fn try_apply(&mut self, m: &Square1Move) -> Result<(), BlockedSliceError> {
+        self.check_move(m)?;
+
+        match *m {
+            Square1Move::Turn { top, bottom } => {
+                self.top.turn(top);
+                self.bottom.turn(bottom);
+            }
+            Square1Move::Slice => {
+                Layer::slice(&mut self.top, &mut self.bottom);
+                self.flipped_middle = !self.flipped_middle;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Applies every move of the alg if none of its slices are blocked, or leaves the puzzle untouched
+    /// otherwise. The error has the index of the first blocked slice in the alg.
+    pub fn try_apply_alg(
+        &mut self,
+        alg: &Alg<Square1Move>,
+    ) -> Result<(), (usize, BlockedSliceError)> {
+        let mut moved = self.clone();
+
+        for (i, m) in alg.moves.iter().enumerate() {
+            moved.try_apply(m).map_err(|err| (i, err))?;
+        }
+
+        *self = moved;
+        Ok(())
+    }
+}
+
+impl generic::Cube for Square1 {
+    const SOLVED: Self = Self {
+        top: Layer::SOLVED_TOP,
+        bottom: Layer::SOLVED_BOTTOM,
+        flipped_middle: false,
+    };
+}
+
+impl AsMove for Square1 {
+    type Move = Square1Move;
+}
+
+impl Movable<Square1Move> for Square1 {
+    /// Applies the move.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is blocked. Use [`Square1::try_apply`] to handle it instead, or
+    /// [`Square1::try_apply_alg`] for whole algs.
+    fn apply(&mut self, m: &Square1Move) {
+        if let Err(err) = self.try_apply(m) {
+            panic!("Can't apply {m}: {err}");
+        }
+    }
+}
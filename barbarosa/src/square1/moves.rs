@@ -0,0 +1,124 @@
+//! Moves of the Square-1.
+
+use thiserror::Error;
+
+use crate::generic::{self, moves::Mergeable, Alg};
+
+/// A move of the Square-1.
+///
+/// WCA notation writes a turn of both layers followed by a slice as `(x,y)/`, but here they are two separate
+/// moves. See [`to_wca_notation`] for writing algs the usual way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Square1Move {
+    /// Turns the top layer `top` slots and the bottom layer `bottom` slots clockwise, looking at each layer.
+    /// Written as `(top,bottom)`, where each amount goes from -5 to 6. [`Square1Move::turn`] brings the amounts
+    /// into that range, but any other amounts work too and are written as their equivalent in the range.
+    Turn {
+        /// The slots that the top layer turns
+        top: i8,
+        /// The slots that the bottom layer turns
+        bottom: i8,
+    },
+
+    /// Turns the right half of the puzzle 180 degrees, written as `/`. It's blocked if a corner is in the way
+    /// (see [`BlockedSliceError`]).
+    Slice,
+}
+
+/// Brings an amount of slots into the range used by WCA notation, from -5 to 6.
+const fn normalize(amount: i8) -> i8 {
+    match amount.rem_euclid(12) {
+        amount if amount > 6 => amount - 12,
+        amount => amount,
+    }
+}
+
+impl Square1Move {
+    /// Creates a new turn of both layers, with the amounts brought into the range from -5 to 6.
+    pub const fn turn(top: i8, bottom: i8) -> Self {
+        Self::Turn {
+            top: normalize(top),
+            bottom: normalize(bottom),
+        }
+    }
+}
+
+impl generic::Move for Square1Move {
+    fn inverse(&self) -> Self {
+        match *self {
+            Self::Turn { top, bottom } => Self::turn(-normalize(top), -normalize(bottom)),
+            Self::Slice => Self::Slice,
+        }
+    }
+}
+
+impl Mergeable for Square1Move {
+    /// Turns only commute with turns, and slices with slices.
+    type Axis = std::mem::Discriminant<Self>;
+
+    fn axis(&self) -> Self::Axis {
+        std::mem::discriminant(self)
+    }
+
+    fn merge(&self, other: &Self) -> Option<Option<Self>> {
+        match (*self, *other) {
+            (Self::Turn { top: a, bottom: b }, Self::Turn { top: c, bottom: d }) => {
+                let merged = Self::turn(normalize(a) + normalize(c), normalize(b) + normalize(d));
+
+                match merged {
+                    Self::Turn { top: 0, bottom: 0 } => Some(None),
+                    merged => Some(Some(merged)),
+                }
+            }
+            (Self::Slice, Self::Slice) => Some(None),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Square1Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Turn { top, bottom } => write!(f, "({},{})", normalize(*top), normalize(*bottom)),
+            Self::Slice => write!(f, "/"),
+        }
+    }
+}
+
+/// Error returned when trying to turn the slice while a corner is in the way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[allow(missing_docs)]
+pub enum BlockedSliceError {
+    #[error("A corner of the top layer is blocking the slice")]
+    Top,
+
+    #[error("A corner of the bottom layer is blocking the slice")]
+    Bottom,
+
+    #[error("Corners of both layers are blocking the slice")]
+    Both,
+}
+
+/// Writes an alg in WCA notation, joining each turn with the slice after it: `(1,0)/ (-3,3)/ (0,2)`.
+///
+/// [`Display`](std::fmt::Display) writes every move separately instead, as in `(1,0) / (-3,3) / (0,2)`. Both
+/// can be parsed back with [`Alg::parse`](generic::Parsable::parse).
+pub fn to_wca_notation(alg: &Alg<Square1Move>) -> String {
+    let mut output: Vec<String> = Vec::new();
+    let mut previous_turn = None;
+
+    for m in &alg.moves {
+        match (m, previous_turn.take()) {
+            (Square1Move::Slice, Some(turn)) => output.push(format!("{turn}/")),
+            (Square1Move::Slice, None) => output.push("/".to_string()),
+            (turn, previous) => {
+                output.extend(previous.map(|previous: Square1Move| previous.to_string()));
+                previous_turn = Some(*turn);
+            }
+        }
+    }
+
+    output.extend(previous_turn.map(|turn| turn.to_string()));
+
+    output.join(" ")
+}
@@ -0,0 +1,53 @@
+//! Parsing of Square-1 algs.
+//!
+//! Square-1 moves don't fit in the alg grammar used by the other puzzles, so [`Square1Move`] doesn't implement
+//! [`Parsable`] and algs are parsed with their own grammar instead. This means that they can't have groups,
+//! commutators or comments, which Square-1 algs don't use anyway.
+
+use pest::{iterators::Pair, Parser};
+use pest_derive::Parser;
+
+use crate::generic::{
+    parse::{self, IntoParseErr, Parsable, ParseError},
+    Alg,
+};
+
+use super::Square1Move;
+
+#[derive(Parser)]
+#[grammar = "grammar/square1.pest"]
+pub struct Square1Parser;
+
+/// Parses a [`Rule::square1_move`] pair.
+fn parse_move(pair: Pair<Rule>) -> Result<Square1Move, ParseError<Rule>> {
+    let inner = pair.into_inner().next().into_err()?;
+
+    match inner.as_rule() {
+        Rule::slice => Ok(Square1Move::Slice),
+        Rule::turn => {
+            let mut amounts = inner.into_inner();
+            let top = amounts.next().into_err()?.as_str().parse()?;
+            let bottom = amounts.next().into_err()?.as_str().parse()?;
+
+            Ok(Square1Move::turn(top, bottom))
+        }
+        _ => Err(ParseError::Unreachable(inner.as_str().to_string())),
+    }
+}
+
+impl Parsable for Alg<Square1Move> {
+    type Rule = Rule;
+
+    /// Parses an alg in WCA notation, like `(1,0)/ (-3,3)/`, or with spaces between the moves. Every `/` is a
+    /// slice, so `(1,0)//(0,3)` has two of them.
+    fn parse(s: &str) -> parse::Result<Self> {
+        let alg = Square1Parser::parse(Rule::square1_alg, s)?
+            .next()
+            .into_err()?;
+
+        alg.into_inner()
+            .filter(|pair| pair.as_rule() == Rule::square1_move)
+            .map(parse_move)
+            .collect()
+    }
+}
@@ -0,0 +1,699 @@
+//! Random state scrambles for the Square-1, using a two-phase solver.
+//!
+//! The first phase brings the puzzle to cube shape, and the second one solves it without leaving cube shape.
+//! Turns are free, so both phases only count slices.
+//!
+//! In the second phase the top layer is one slot off (as if it had done `(1,0)` from solved), so that the slice
+//! keeps both layers square. Only turns by multiples of 3 slots are allowed, and all of them are even
+//! permutations of the pieces. So the first phase also has to get the parity of the pieces right.
+
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use rand::{distributions::Standard, prelude::Distribution, seq::SliceRandom, Rng};
+
+use crate::generic::{search::Solver, Alg, Cube, Movable};
+
+use super::{Layer, Square1, Square1Move, Square1Piece};
+
+/// The slots of the half that the slice turns, as a bitmask.
+const HALF: u16 = 0b111111;
+
+/// The maximum amount of slices of the second phase.
+const MAX_PHASE_2_LENGTH: u8 = 30;
+
+/// Whether the permutation is odd.
+fn is_odd(permutation: &[usize]) -> bool {
+    let inversions = permutation
+        .iter()
+        .tuple_combinations()
+        .filter(|(a, b)| a > b)
+        .count();
+
+    inversions % 2 == 1
+}
+
+/// Turns a layer shape (see [`Layer::shape`]) `amount` slots clockwise.
+fn rotate(shape: u16, amount: u32) -> u16 {
+    ((shape << amount) | (shape >> (12 - amount))) & 0xFFF
+}
+
+/// The shape of both layers, and the parity of the pieces read in order (first the top layer and then the
+/// bottom one, see [`Layer::pieces`]). It's all the first phase cares about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Shape {
+    top: u16,
+    bottom: u16,
+    odd: bool,
+}
+
+impl Shape {
+    fn of(square1: &Square1) -> Self {
+        let pieces = square1.top.pieces().chain(square1.bottom.pieces());
+        let permutation: Vec<_> = pieces.map(|piece| piece.index()).collect();
+
+        Self {
+            top: square1.top.shape(),
+            bottom: square1.bottom.shape(),
+            odd: is_odd(&permutation),
+        }
+    }
+
+    fn is_sliceable(&self) -> bool {
+        let cuts = 1 | 1 << 6;
+
+        self.top & cuts == cuts && self.bottom & cuts == cuts
+    }
+
+    fn turned(&self, top: u32, bottom: u32) -> Self {
+        // The pieces that go past the last slot move to the front, which is a cyclic shift of the layer
+        let shift_is_odd = |shape: u16, amount: u32| {
+            let shifted = (shape >> (12 - amount)).count_ones();
+            shifted * (shape.count_ones() - 1) % 2 == 1
+        };
+
+        Self {
+            top: rotate(self.top, top),
+            bottom: rotate(self.bottom, bottom),
+            odd: self.odd ^ shift_is_odd(self.top, top) ^ shift_is_odd(self.bottom, bottom),
+        }
+    }
+
+    fn sliced(&self) -> Self {
+        let a = (self.top & HALF).count_ones();
+        let b = (self.top >> 6).count_ones();
+        let c = (self.bottom & HALF).count_ones();
+        let d = (self.bottom >> 6).count_ones();
+
+        // The pieces go from `a b c d` to `d b c a`
+        let swaps = a * (b + c + d) + d * (b + c);
+
+        Self {
+            top: (self.top & !HALF) | self.bottom >> 6,
+            bottom: (self.bottom & HALF) | (self.top & HALF) << 6,
+            odd: self.odd ^ (swaps % 2 == 1),
+        }
+    }
+
+    /// Every turn that leaves the puzzle ready to slice, along with the shape it leads to.
+    fn sliceable_turns(self) -> impl Iterator<Item = (Square1Move, Shape)> {
+        (0..12)
+            .cartesian_product(0..12)
+            .map(move |(top, bottom)| {
+                let m = Square1Move::turn(top as i8, bottom as i8);
+                (m, self.turned(top, bottom))
+            })
+            .filter(|(_, shape)| shape.is_sliceable())
+    }
+}
+
+/// The state where the second phase ends, one slot away from solved.
+fn phase_2_solved() -> Square1 {
+    Square1::SOLVED.moved(&Square1Move::turn(1, 0))
+}
+
+/// The amount of slices needed to reach the shape of the second phase, for every shape that is ready to slice.
+static SHAPE_DISTANCES: Lazy<HashMap<Shape, u8>> = Lazy::new(|| {
+    let target = Shape::of(&phase_2_solved());
+    let mut distances = HashMap::new();
+    let mut frontier = Vec::new();
+
+    for (_, shape) in target.sliceable_turns() {
+        distances.insert(shape, 0);
+        frontier.push(shape);
+    }
+
+    for distance in 1.. {
+        if frontier.is_empty() {
+            break;
+        }
+
+        let mut next = Vec::new();
+
+        for shape in frontier {
+            let sliced = shape.sliced();
+            if distances.contains_key(&sliced) {
+                continue;
+            }
+
+            // Turns are free, so every turn of the sliced shape is as far away
+            for (_, turned) in sliced.sliceable_turns() {
+                distances.insert(turned, distance);
+                next.push(turned);
+            }
+        }
+
+        frontier = next;
+    }
+
+    distances
+});
+
+/// The slots of the corners and the edges in the second phase.
+const CORNER_SLOTS: [usize; 4] = [1, 4, 7, 10];
+const EDGE_SLOTS: [usize; 4] = [0, 3, 6, 9];
+
+/// The moves of the second phase: `(3,0)`, `(0,3)`, and the two slices that keep the puzzle in cube shape.
+/// The second slice is done with both layers off by two slots, so it moves the corners and the edges
+/// differently.
+const PHASE_2_MOVES: [&[Square1Move]; 4] = [
+    &[Square1Move::turn(3, 0)],
+    &[Square1Move::turn(0, 3)],
+    &[Square1Move::Slice],
+    &[
+        Square1Move::turn(2, 2),
+        Square1Move::Slice,
+        Square1Move::turn(-2, -2),
+    ],
+];
+
+/// The indices of the slices in [`PHASE_2_MOVES`].
+const PHASE_2_SLICES: [usize; 2] = [2, 3];
+
+/// The amount of permutations of 8 pieces.
+const PERMUTATIONS: usize = 40320;
+
+/// The amount of ways to choose which 4 of the 8 positions hold the pieces that go on the top layer.
+const HALVES: usize = 70;
+
+/// The index of a permutation of 8 elements, from 0 to 8! - 1.
+fn permutation_index(permutation: &[u8; 8]) -> usize {
+    (0..8).fold(0, |index, i| {
+        let smaller = permutation[i + 1..]
+            .iter()
+            .filter(|&&other| other < permutation[i])
+            .count();
+
+        index * (8 - i) + smaller
+    })
+}
+
+/// The inverse of [`permutation_index`].
+fn permutation_from_index(mut index: usize) -> [u8; 8] {
+    let mut digits = [0; 8];
+    for i in (0..8).rev() {
+        digits[i] = index % (8 - i);
+        index /= 8 - i;
+    }
+
+    let mut remaining: Vec<u8> = (0..8).collect();
+    digits.map(|digit| remaining.remove(digit))
+}
+
+/// Builds a Square-1 in the shape of the second phase, with the given corners and edges.
+fn from_phase_2_pieces(corners: [u8; 8], edges: [u8; 8]) -> Square1 {
+    let layer = |offset: usize| {
+        let pieces: Vec<_> = (offset..offset + 4)
+            .flat_map(|i| {
+                [
+                    Square1Piece::Edge(edges[i]),
+                    Square1Piece::Corner(corners[i]),
+                ]
+            })
+            .collect();
+
+        Layer::new(&pieces).expect("4 corners and 4 edges take 12 slots")
+    };
+
+    Square1 {
+        top: layer(0),
+        bottom: layer(4),
+        flipped_middle: false,
+    }
+}
+
+/// The corners and edges of a Square-1 in the shape of the second phase.
+fn phase_2_pieces(square1: &Square1) -> ([u8; 8], [u8; 8]) {
+    let read = |slots: [usize; 4]| {
+        let mut output = [0; 8];
+        let positions = slots.map(|slot| (&square1.top, slot));
+        let positions = positions
+            .into_iter()
+            .chain(slots.map(|slot| (&square1.bottom, slot)));
+
+        for (piece, (layer, slot)) in output.iter_mut().zip(positions) {
+            *piece = match layer.slots()[slot] {
+                Square1Piece::Corner(i) | Square1Piece::Edge(i) => i,
+            };
+        }
+
+        output
+    };
+
+    (read(CORNER_SLOTS), read(EDGE_SLOTS))
+}
+
+/// The pieces of [`phase_2_solved`].
+static PHASE_2_SOLVED_PIECES: Lazy<([u8; 8], [u8; 8])> =
+    Lazy::new(|| phase_2_pieces(&phase_2_solved()));
+
+/// Numbers the pieces by where they are in [`phase_2_solved`], so that it's the identity permutation.
+fn relabel(pieces: [u8; 8], solved: &[u8; 8]) -> [u8; 8] {
+    pieces.map(|piece| {
+        solved
+            .iter()
+            .position(|&other| other == piece)
+            .expect("Every piece is somewhere") as u8
+    })
+}
+
+/// The permutations of the corners and the edges of a Square-1 in the shape of the second phase, relative to
+/// [`phase_2_solved`].
+fn phase_2_permutations(square1: &Square1) -> (usize, usize) {
+    let (corners, edges) = phase_2_pieces(square1);
+    let (solved_corners, solved_edges) = &*PHASE_2_SOLVED_PIECES;
+
+    (
+        permutation_index(&relabel(corners, solved_corners)),
+        permutation_index(&relabel(edges, solved_edges)),
+    )
+}
+
+/// Every turn of the second phase, as the amount of quarter turns of the top and bottom layers.
+fn phase_2_turns() -> impl Iterator<Item = (u8, u8)> {
+    (0..4).cartesian_product(0..4)
+}
+
+/// Every step of the second phase: a turn followed by one of the [`PHASE_2_SLICES`].
+fn phase_2_steps() -> impl Iterator<Item = ((u8, u8), usize)> {
+    phase_2_turns().cartesian_product(PHASE_2_SLICES)
+}
+
+/// Where every turn and every step of the second phase take each value of a coordinate, in the order of
+/// [`phase_2_turns`] and [`phase_2_steps`].
+struct MoveTable {
+    turns: Vec<[u16; 16]>,
+    steps: Vec<[u16; 32]>,
+}
+
+impl MoveTable {
+    /// Builds the table, given where each of the [`PHASE_2_MOVES`] takes every value.
+    fn new(moves: &[[u16; 4]]) -> Self {
+        let turn = |mut value: u16, (top, bottom): (u8, u8)| {
+            (0..top).for_each(|_| value = moves[value as usize][0]);
+            (0..bottom).for_each(|_| value = moves[value as usize][1]);
+            value
+        };
+
+        let turns = (0..moves.len() as u16)
+            .map(|value| {
+                let turns: Vec<_> = phase_2_turns().map(|t| turn(value, t)).collect();
+                turns.try_into().expect("There are 16 turns")
+            })
+            .collect();
+
+        let steps = (0..moves.len() as u16)
+            .map(|value| {
+                let steps: Vec<_> = phase_2_steps()
+                    .map(|(t, slice)| moves[turn(value, t) as usize][slice])
+                    .collect();
+                steps.try_into().expect("There are 32 steps")
+            })
+            .collect();
+
+        Self { turns, steps }
+    }
+}
+
+/// The move tables of the corners or the edges in the second phase.
+struct PieceTables {
+    permutations: MoveTable,
+    /// Which positions hold the first 4 pieces (the ones that go on top), for every permutation
+    halves: Vec<u16>,
+    half_moves: MoveTable,
+}
+
+impl PieceTables {
+    /// Builds the tables, given a way to build a Square-1 with a permutation of the pieces and to read it back.
+    fn new(build: impl Fn([u8; 8]) -> Square1, read: impl Fn(&Square1) -> usize) -> Self {
+        let moves: Vec<_> = (0..PERMUTATIONS)
+            .map(|index| {
+                let square1 = build(permutation_from_index(index));
+                PHASE_2_MOVES.map(|m| read(&square1.clone().moved(m)) as u16)
+            })
+            .collect();
+
+        let masks: Vec<u8> = (0..=u8::MAX)
+            .filter(|mask| mask.count_ones() == 4)
+            .collect();
+        let halves: Vec<_> = (0..PERMUTATIONS)
+            .map(|index| {
+                let mask = permutation_from_index(index)
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &piece)| piece < 4)
+                    .fold(0, |mask, (position, _)| mask | 1 << position);
+
+                masks
+                    .binary_search(&mask)
+                    .expect("Half of the pieces go on top") as u16
+            })
+            .collect();
+
+        let mut half_moves = vec![[0; 4]; HALVES];
+        for (index, successors) in moves.iter().enumerate() {
+            half_moves[halves[index] as usize] =
+                successors.map(|successor| halves[successor as usize]);
+        }
+
+        Self {
+            permutations: MoveTable::new(&moves),
+            halves,
+            half_moves: MoveTable::new(&half_moves),
+        }
+    }
+}
+
+/// The amount of slices needed to solve the permutation of one kind of piece, along with the halves of the other
+/// kind and the middle layer. The permutation moves with `primary`, and the halves with `secondary`.
+fn phase_2_distances(primary: &PieceTables, secondary: &PieceTables) -> Vec<u8> {
+    let mut distances = vec![u8::MAX; PERMUTATIONS * HALVES * 2];
+    let solved_halves = secondary.halves[0] as usize;
+
+    for (permutation, halves) in primary.permutations.turns[0]
+        .iter()
+        .zip(secondary.half_moves.turns[solved_halves])
+    {
+        distances[phase_2_index(*permutation, halves, false)] = 0;
+    }
+
+    // Going through the whole table at each distance is faster than keeping the states in a queue
+    for distance in 1.. {
+        let mut found_any = false;
+
+        for index in 0..distances.len() {
+            if distances[index] != distance - 1 {
+                continue;
+            }
+
+            let permutation = index / (HALVES * 2);
+            let halves = index / 2 % HALVES;
+            let flipped_middle = index % 2 == 1;
+
+            let permutations = &primary.permutations.steps[permutation];
+            let halves = &secondary.half_moves.steps[halves];
+
+            for (&permutation, &halves) in permutations.iter().zip(halves) {
+                let successor = phase_2_index(permutation, halves, !flipped_middle);
+
+                if distances[successor] == u8::MAX {
+                    distances[successor] = distance;
+                    found_any = true;
+                }
+            }
+        }
+
+        if !found_any {
+            break;
+        }
+    }
+
+    distances
+}
+
+/// The index in the tables of [`phase_2_distances`].
+fn phase_2_index(permutation: u16, halves: u16, flipped_middle: bool) -> usize {
+    (permutation as usize * HALVES + halves as usize) * 2 + flipped_middle as usize
+}
+
+/// Every table of the second phase.
+struct Phase2Tables {
+    corners: PieceTables,
+    edges: PieceTables,
+    corner_distances: Vec<u8>,
+    edge_distances: Vec<u8>,
+}
+
+static PHASE_2_TABLES: Lazy<Phase2Tables> = Lazy::new(|| {
+    let (solved_corners, solved_edges) = *PHASE_2_SOLVED_PIECES;
+
+    let corners = PieceTables::new(
+        |permutation| {
+            from_phase_2_pieces(
+                permutation.map(|i| solved_corners[i as usize]),
+                solved_edges,
+            )
+        },
+        |square1| phase_2_permutations(square1).0,
+    );
+    let edges = PieceTables::new(
+        |permutation| {
+            from_phase_2_pieces(
+                solved_corners,
+                permutation.map(|i| solved_edges[i as usize]),
+            )
+        },
+        |square1| phase_2_permutations(square1).1,
+    );
+
+    Phase2Tables {
+        corner_distances: phase_2_distances(&corners, &edges),
+        edge_distances: phase_2_distances(&edges, &corners),
+        corners,
+        edges,
+    }
+});
+
+/// A state of the second phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Phase2 {
+    corners: u16,
+    edges: u16,
+    flipped_middle: bool,
+}
+
+impl Phase2 {
+    /// A lower bound of the amount of slices needed to solve the state.
+    fn distance(&self, tables: &Phase2Tables) -> u8 {
+        let corners = phase_2_index(
+            self.corners,
+            tables.edges.halves[self.edges as usize],
+            self.flipped_middle,
+        );
+        let edges = phase_2_index(
+            self.edges,
+            tables.corners.halves[self.corners as usize],
+            self.flipped_middle,
+        );
+
+        tables.corner_distances[corners].max(tables.edge_distances[edges])
+    }
+
+    /// Searches for a solution with exactly `slices` slices, and pushes it in reverse to `solution`: each turn
+    /// with the index of the slice after it, except the last one.
+    fn search(
+        &self,
+        tables: &Phase2Tables,
+        slices: u8,
+        previous_slice: Option<usize>,
+        solution: &mut Vec<((u8, u8), Option<usize>)>,
+    ) -> bool {
+        let corners = self.corners as usize;
+        let edges = self.edges as usize;
+
+        if self.distance(tables) > slices {
+            return false;
+        }
+
+        if slices == 0 {
+            let solving_turn = phase_2_turns().enumerate().find(|&(i, _)| {
+                tables.corners.permutations.turns[corners][i] == 0
+                    && tables.edges.permutations.turns[edges][i] == 0
+                    && !self.flipped_middle
+            });
+            solution.extend(solving_turn.map(|(_, turn)| (turn, None)));
+
+            return solving_turn.is_some();
+        }
+
+        for (i, (turn, slice)) in phase_2_steps().enumerate() {
+            // Doing the same slice twice in a row does nothing
+            if turn == (0, 0) && previous_slice == Some(slice) {
+                continue;
+            }
+
+            let next = Self {
+                corners: tables.corners.permutations.steps[corners][i],
+                edges: tables.edges.permutations.steps[edges][i],
+                flipped_middle: !self.flipped_middle,
+            };
+
+            if next.search(tables, slices - 1, Some(slice), solution) {
+                solution.push((turn, Some(slice)));
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+/// The amount of slices needed to reach the shape of the second phase, or `None` if the pieces are in a shape
+/// and parity that no sequence of moves leads to.
+pub(super) fn phase_1_distance(square1: &Square1) -> Option<u8> {
+    Shape::of(square1)
+        .sliceable_turns()
+        .find_map(|(_, shape)| SHAPE_DISTANCES.get(&shape))
+        .copied()
+}
+
+/// Solves the Square-1 in two phases. The first one brings it to cube shape with the fewest slices, only
+/// looking at the shape and the parity of the pieces. The second one keeps the cube shape and solves the pieces
+/// and the middle layer with an iterative deepening search over `(3,0)`, `(0,3)` and slices.
+///
+/// The solutions aren't optimal: the first phase takes the shortest way to cube shape, without looking at how
+/// long the second phase will be.
+/// [`solve`](Solver::solve) only returns `None` for states that can't be reached from solved, which can only be
+/// built by hand with [`Layer::new`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TwoPhaseSolver;
+
+impl Solver<Square1, Square1Move> for TwoPhaseSolver {
+    fn solve(&self, square1: &Square1) -> Option<Alg<Square1Move>> {
+        let mut square1 = square1.clone();
+        let mut solution = Vec::new();
+
+        let mut apply = |square1: &mut Square1, m: Square1Move| {
+            square1.apply(&m);
+            solution.push(m);
+        };
+
+        // First phase
+        let mut distance = phase_1_distance(&square1)?;
+
+        while distance > 0 {
+            let (m, _) = Shape::of(&square1)
+                .sliceable_turns()
+                .find(|(_, shape)| SHAPE_DISTANCES.get(&shape.sliced()) == Some(&(distance - 1)))
+                .expect("Some slice should get closer to the second phase");
+
+            apply(&mut square1, m);
+            apply(&mut square1, Square1Move::Slice);
+            distance -= 1;
+        }
+
+        let target = Shape::of(&phase_2_solved());
+        let (m, _) = Shape::of(&square1)
+            .sliceable_turns()
+            .find(|(_, shape)| *shape == target)
+            .expect("The first phase should end in the shape of the second one");
+
+        apply(&mut square1, m);
+
+        // Second phase
+        let (corners, edges) = phase_2_permutations(&square1);
+        let phase_2 = Phase2 {
+            corners: corners as u16,
+            edges: edges as u16,
+            flipped_middle: square1.flipped_middle,
+        };
+
+        let tables = &*PHASE_2_TABLES;
+        let mut steps = Vec::new();
+        (0..=MAX_PHASE_2_LENGTH)
+            .find(|&slices| phase_2.search(tables, slices, None, &mut steps))?;
+
+        for ((top, bottom), slice) in steps.into_iter().rev() {
+            apply(
+                &mut square1,
+                Square1Move::turn(3 * top as i8, 3 * bottom as i8),
+            );
+
+            for m in slice.map_or(&[][..], |slice| PHASE_2_MOVES[slice]) {
+                apply(&mut square1, *m);
+            }
+        }
+
+        apply(&mut square1, Square1Move::turn(-1, 0));
+
+        debug_assert!(square1.is_solved());
+
+        Some(Alg::new(solution).simplified())
+    }
+}
+
+/// Every shape that the Square-1 can be in, with both layers and the parity of the pieces.
+static SHAPES: Lazy<Vec<Shape>> = Lazy::new(|| {
+    let shapes: HashSet<_> = SHAPE_DISTANCES
+        .keys()
+        .flat_map(|shape| {
+            (0..12)
+                .cartesian_product(0..12)
+                .map(|(top, bottom)| shape.turned(top, bottom))
+        })
+        .collect();
+
+    shapes
+        .into_iter()
+        .sorted_by_key(|shape| (shape.top, shape.bottom, shape.odd))
+        .collect()
+});
+
+/// Fills the layers of `shape` with the pieces, popping them from the end. The parity of the result depends
+/// on the order of the pieces, not on the parity of `shape`.
+fn with_pieces(
+    shape: Shape,
+    mut corners: Vec<Square1Piece>,
+    mut edges: Vec<Square1Piece>,
+    flipped_middle: bool,
+) -> Square1 {
+    let mut layer = |shape: u16| {
+        let starts: Vec<_> = (0..12).filter(|slot| shape & 1 << slot != 0).collect();
+        let pieces: Vec<_> = starts
+            .iter()
+            .zip(starts.iter().cycle().skip(1))
+            .map(|(start, next)| match (next + 12 - start) % 12 {
+                2 => corners.pop(),
+                _ => edges.pop(),
+            })
+            .collect::<Option<_>>()
+            .expect("Reachable shapes have 8 corners and 8 edges");
+
+        Layer::with_shape(starts[0], &pieces).expect("Reachable shapes take 12 slots")
+    };
+
+    Square1 {
+        top: layer(shape.top),
+        bottom: layer(shape.bottom),
+        flipped_middle,
+    }
+}
+
+impl Distribution<Square1> for Standard {
+    /// Samples a reachable shape and parity, and fills it with random pieces of that parity. The middle layer
+    /// doesn't depend on either.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Square1 {
+        let shape = *SHAPES.choose(rng).expect("There are shapes");
+        let flipped_middle = rng.gen();
+
+        let mut corners: Vec<_> = (0..8).map(Square1Piece::Corner).collect();
+        let mut edges: Vec<_> = (0..8).map(Square1Piece::Edge).collect();
+        corners.shuffle(rng);
+        edges.shuffle(rng);
+
+        let square1 = with_pieces(shape, corners.clone(), edges.clone(), flipped_middle);
+
+        match Shape::of(&square1) == shape {
+            true => square1,
+            false => {
+                // Swapping two edges keeps the shape and fixes the parity
+                edges.swap(0, 1);
+                with_pieces(shape, corners, edges, flipped_middle)
+            }
+        }
+    }
+}
+
+/// Generates a random state scramble by reversing the [two-phase solution](TwoPhaseSolver) of a random
+/// Square-1. Random states are always reachable, so this never fails.
+///
+/// Use [`to_wca_notation`](super::to_wca_notation) to write it in the usual `(x,y)/` format.
+pub fn random_state_scramble(rng: &mut (impl Rng + ?Sized)) -> Alg<Square1Move> {
+    let square1: Square1 = rng.gen();
+
+    TwoPhaseSolver
+        .solve(&square1)
+        .expect("Random states should be solvable")
+        .reversed()
+}
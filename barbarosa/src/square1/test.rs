@@ -0,0 +1,206 @@
+#![cfg(test)]
+
+use pretty_assertions::assert_eq;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::generic::{search::Solver, Alg, Cube, Movable, Move, Parsable};
+
+use super::{solver::phase_1_distance, *};
+
+#[test]
+fn wca_notation_joins_turns_with_the_next_slice() {
+    let scramble = "(1,0)/ (-3,3)/ / (0,2)";
+    let parsed = Alg::<Square1Move>::parse(scramble).unwrap();
+
+    assert_eq!(to_wca_notation(&parsed), scramble);
+    assert_eq!(parsed.to_string(), "(1,0) / (-3,3) / / (0,2)");
+    assert_eq!(
+        Alg::<Square1Move>::parse(&parsed.to_string()).unwrap(),
+        parsed
+    );
+}
+
+#[test]
+fn turn_amounts_are_normalized() {
+    assert_eq!(
+        Alg::<Square1Move>::parse("( 7, -6 )/").unwrap().moves,
+        vec![Square1Move::turn(-5, 6), Square1Move::Slice]
+    );
+}
+
+#[test]
+fn turns_built_by_hand_are_written_in_wca_range() {
+    let turn = Square1Move::Turn {
+        top: 7,
+        bottom: i8::MIN,
+    };
+    assert_eq!(turn.to_string(), "(-5,4)");
+    assert_eq!(turn.inverse(), Square1Move::turn(5, -4));
+
+    let merged = Alg::<Square1Move>::new(vec![turn, turn]).simplified();
+    assert_eq!(merged.to_string(), "(2,-4)");
+}
+
+#[test]
+fn double_slashes_are_two_slices() {
+    assert_eq!(
+        Alg::<Square1Move>::parse("(1,0)//(0,3)").unwrap().moves,
+        vec![
+            Square1Move::turn(1, 0),
+            Square1Move::Slice,
+            Square1Move::Slice,
+            Square1Move::turn(0, 3),
+        ]
+    );
+}
+
+#[test]
+fn rejects_the_syntax_of_other_puzzles() {
+    for invalid in [
+        "(1)",
+        "(a,0)",
+        "\\",
+        "(1,0,0)",
+        "R",
+        "[(1,0), /]",
+        "((1,0)/)2",
+        "/* setup */ /",
+    ] {
+        assert!(
+            Alg::<Square1Move>::parse(invalid).is_err(),
+            "{invalid} should error"
+        );
+    }
+}
+
+#[test]
+fn slices_can_be_blocked() {
+    let solved = Square1::SOLVED;
+    assert_eq!(solved.check_move(&Square1Move::Slice), Ok(()));
+
+    let cases = [
+        ((1, 0), Ok(())),
+        ((0, -1), Ok(())),
+        ((-1, 0), Err(BlockedSliceError::Top)),
+        ((0, 1), Err(BlockedSliceError::Bottom)),
+        ((-1, 1), Err(BlockedSliceError::Both)),
+    ];
+
+    for ((top, bottom), expected) in cases {
+        let mut square1 = solved.clone().moved(&Square1Move::turn(top, bottom));
+        let before = square1.clone();
+
+        assert_eq!(
+            square1.try_apply(&Square1Move::Slice),
+            expected,
+            "({top},{bottom})"
+        );
+
+        if expected.is_err() {
+            assert_eq!(square1, before);
+        }
+    }
+}
+
+#[test]
+fn blocked_algs_report_the_first_blocked_slice() {
+    let mut square1 = Square1::SOLVED;
+    let blocked = Alg::<Square1Move>::parse("(1,0)/ (-1,0) (-1,1)/ (1,0)/").unwrap();
+
+    assert_eq!(
+        square1.try_apply_alg(&blocked),
+        Err((4, BlockedSliceError::Both))
+    );
+    assert!(square1.is_solved());
+
+    let alg = Alg::<Square1Move>::parse("(1,0)/ (-1,0)").unwrap();
+    assert_eq!(square1.try_apply_alg(&alg), Ok(()));
+    assert_eq!(square1, Square1::SOLVED.moved(&alg));
+}
+
+#[test]
+#[should_panic]
+fn applying_blocked_slices_panics() {
+    Square1::SOLVED.moved(&Alg::<Square1Move>::parse("(0,1)/").unwrap());
+}
+
+#[test]
+fn cube_shape_ignores_the_middle_layer() {
+    let shaped = |alg: &str| Square1::SOLVED.moved(&Alg::<Square1Move>::parse(alg).unwrap());
+
+    assert!(Square1::SOLVED.is_cube_shape());
+    assert!(shaped("(1,0)/").is_cube_shape());
+    assert!(!shaped("/").is_cube_shape());
+    assert!(!shaped("(1,0)/ (2,0)/").is_cube_shape());
+
+    let flipped = shaped("/ (6,0)/ (6,0)/");
+    assert!(flipped.flipped_middle);
+    assert!(flipped.is_cube_shape());
+    assert!(!flipped.is_solved());
+}
+
+#[test]
+fn the_middle_layer_flips_back_with_another_slice() {
+    let scramble = Alg::<Square1Move>::parse("(1,0)/ (3,3)/ (0,3)/ (-3,0)/ (2,-1)").unwrap();
+    let square1 = Square1::SOLVED.moved(&scramble);
+
+    assert!(!square1.is_solved());
+    assert!(square1.moved(&scramble.reversed()).is_solved());
+
+    let square1 =
+        Square1::SOLVED.moved(&Alg::<Square1Move>::parse("(1,0)/ (6,6)/ (6,6)/").unwrap());
+    assert!(square1.flipped_middle);
+    assert!(square1
+        .moved(&Alg::<Square1Move>::parse("/ (6,6)/ (6,6)/ (-1,0)").unwrap())
+        .is_solved());
+}
+
+#[test]
+fn solves_short_scrambles() {
+    let scrambles = [
+        "/",
+        "(1,0)/ (-1,0)",
+        "/ (6,0)/ (6,0)/",
+        "(1,0)/ (3,3)/ (0,3)/ (-3,0)/ (2,-1)",
+        "(0,-1)/ (4,1)/ (-3,0)/ (-1,-4)/ /",
+    ];
+
+    for scramble in scrambles {
+        let square1 = Square1::SOLVED.moved(&Alg::<Square1Move>::parse(scramble).unwrap());
+        let solution = TwoPhaseSolver.solve(&square1).unwrap();
+
+        assert!(square1.moved(&solution).is_solved(), "{scramble}");
+    }
+
+    assert_eq!(
+        TwoPhaseSolver.solve(&Square1::SOLVED),
+        Some(Alg::new(vec![]))
+    );
+}
+
+#[test]
+fn random_states_can_reach_cube_shape() {
+    let mut rng = StdRng::seed_from_u64(49);
+
+    for _ in 0..1000 {
+        let square1: Square1 = rng.gen();
+        assert!(phase_1_distance(&square1).is_some(), "{square1:?}");
+    }
+}
+
+#[test]
+#[ignore = "Solving random states takes over 10 seconds each without optimizations"]
+fn random_state_scrambles() {
+    let mut rng = StdRng::seed_from_u64(49);
+
+    for _ in 0..5 {
+        let square1: Square1 = rng.gen();
+        let solution = TwoPhaseSolver.solve(&square1).unwrap();
+        assert!(square1.moved(&solution).is_solved());
+
+        let scramble = random_state_scramble(&mut rng);
+        let parsed = Alg::<Square1Move>::parse(&to_wca_notation(&scramble)).unwrap();
+        assert_eq!(parsed, scramble);
+        assert!(!Square1::SOLVED.moved(&scramble).is_solved());
+    }
+}